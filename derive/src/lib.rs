@@ -0,0 +1,213 @@
+// ladata-derive::lib
+//
+//! Derive macros for [`ladata`](https://docs.rs/ladata).
+//
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident};
+
+/// Derives [`DataUnit`] for a custom enum, along with its sibling *data type*.
+///
+/// It generates:
+/// - a fieldless enum named `{Name}Type`, with one variant per unit variant,
+///   implementing [`DataType`].
+/// - the [`DataUnit`] implementation for the enum, with `{Name}Type` as its
+///   associated *data type*.
+/// - the [`DataUnitCopy`] and [`DataTypeCopy`] implementations, if the enum
+///   is marked with `#[data_unit(copy)]`.
+/// - a `data_type()` method returning the *data type* of each unit.
+///
+/// The size and alignment of each variant's *data type* are those of the tuple
+/// of its fields.
+///
+/// # Attributes
+/// - `#[data_unit(copy)]`: implements the `Copy` marker traits. The enum must
+///   also implement `Copy`, since derive macros can't see the other derives.
+/// - `#[data_unit(type = Name)]`: renames the generated *data type* enum.
+///
+/// # Examples
+/// ```
+/// use ladata::all::{DataType, DataUnit, DataUnit64bitCopyWith};
+///
+/// #[derive(Clone, Copy, Debug, DataUnit)]
+/// #[data_unit(copy)]
+/// enum MyCell {
+///     A(i64),
+///     B(f32, u8),
+///     C,
+/// }
+///
+/// let cell = MyCell::B(1.5, 2);
+/// assert_eq![MyCellType::B, cell.data_type()];
+/// assert_eq![8, cell.data_type().data_size()];
+/// assert![cell.is_copy()];
+///
+/// // it can be embedded in the `With` variant of the `Copy` units
+/// let _unit = DataUnit64bitCopyWith::With(cell);
+/// ```
+///
+/// Only enums without generics are supported:
+/// ```compile_fail
+/// #[derive(ladata::all::DataUnit)]
+/// struct NotAnEnum(u8);
+/// ```
+/// ```compile_fail
+/// #[derive(ladata::all::DataUnit)]
+/// union NotAnEnum { a: u8 }
+/// ```
+/// ```compile_fail
+/// #[derive(Debug, ladata::all::DataUnit)]
+/// enum Generic<T> { A(T) }
+/// ```
+///
+/// Marking a non-`Copy` enum as `copy` fails to compile:
+/// ```compile_fail
+/// #[derive(Debug, ladata::all::DataUnit)]
+/// #[data_unit(copy)]
+/// enum NotCopy { A(String) }
+/// ```
+///
+/// [`DataType`]: https://docs.rs/ladata/latest/ladata/unit/traits/trait.DataType.html
+/// [`DataUnit`]: https://docs.rs/ladata/latest/ladata/unit/traits/trait.DataUnit.html
+/// [`DataTypeCopy`]: https://docs.rs/ladata/latest/ladata/unit/traits/trait.DataTypeCopy.html
+/// [`DataUnitCopy`]: https://docs.rs/ladata/latest/ladata/unit/traits/trait.DataUnitCopy.html
+#[proc_macro_derive(DataUnit, attributes(data_unit))]
+pub fn derive_data_unit(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    data_unit(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The options given through the `data_unit` attribute.
+struct Options {
+    copy: bool,
+    type_name: Ident,
+}
+
+impl Options {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut copy = false;
+        let mut type_name = format_ident!("{}Type", input.ident);
+        for attr in &input.attrs {
+            if attr.path().is_ident("data_unit") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("copy") {
+                        copy = true;
+                        Ok(())
+                    } else if meta.path.is_ident("type") {
+                        type_name = meta.value()?.parse()?;
+                        Ok(())
+                    } else {
+                        Err(meta.error("expected `copy` or `type = Name`"))
+                    }
+                })?;
+            }
+        }
+        Ok(Self { copy, type_name })
+    }
+}
+
+fn data_unit(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`DataUnit` can only be derived for enums",
+        ));
+    };
+    if !input.generics.params.is_empty() || input.generics.where_clause.is_some() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`DataUnit` can't be derived for generic enums",
+        ));
+    }
+    let Options { copy, type_name } = Options::parse(&input)?;
+    let (vis, name) = (&input.vis, &input.ident);
+
+    let variants: Vec<_> = data.variants.iter().map(|v| &v.ident).collect();
+    let docs = data.variants.iter().map(|v| {
+        let docs = v.attrs.iter().filter(|a| a.path().is_ident("doc"));
+        quote! { #(#docs)* }
+    });
+    let patterns: Vec<_> = data
+        .variants
+        .iter()
+        .map(|v| {
+            let ident = &v.ident;
+            match v.fields {
+                Fields::Named(_) => quote! { Self::#ident { .. } },
+                Fields::Unnamed(_) => quote! { Self::#ident(..) },
+                Fields::Unit => quote! { Self::#ident },
+            }
+        })
+        .collect();
+    let tuples: Vec<_> = data
+        .variants
+        .iter()
+        .map(|v| {
+            let types = v.fields.iter().map(|f| &f.ty);
+            quote! { (#(#types,)*) }
+        })
+        .collect();
+
+    let type_doc = format!("The *data type* of [`{name}`].");
+    let copy_impls = copy.then(|| {
+        quote! {
+            #[automatically_derived]
+            impl ::ladata::unit::DataTypeCopy for #type_name {}
+            #[automatically_derived]
+            impl ::ladata::unit::DataUnitCopy for #name {}
+        }
+    });
+
+    Ok(quote! {
+        #[doc = #type_doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #vis enum #type_name {
+            #( #docs #variants, )*
+        }
+
+        #[automatically_derived]
+        impl ::ladata::unit::DataType for #type_name {
+            fn data_align(&self) -> usize {
+                match *self {
+                    #( Self::#variants => ::core::mem::align_of::<#tuples>(), )*
+                }
+            }
+            fn data_size(&self) -> usize {
+                match *self {
+                    #( Self::#variants => ::core::mem::size_of::<#tuples>(), )*
+                }
+            }
+            fn is_copy(&self) -> bool {
+                #copy
+            }
+        }
+
+        #[automatically_derived]
+        impl ::ladata::unit::DataUnit for #name {
+            type Type = #type_name;
+
+            fn is_copy(&self) -> bool {
+                #copy
+            }
+            fn unit_type(&self) -> Self::Type {
+                self.data_type()
+            }
+        }
+
+        #copy_impls
+
+        #[automatically_derived]
+        impl #name {
+            /// Returns the *data type* of the unit.
+            pub fn data_type(&self) -> #type_name {
+                match *self {
+                    #( #patterns => #type_name::#variants, )*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,78 @@
+// ladata-derive::tests::derive
+
+#![allow(dead_code)]
+
+use core::mem::{align_of, size_of};
+use ladata::all::{
+    DataType, DataType32bitWith, DataTypeCopy, DataUnit, DataUnit32bitWith, DataUnitCopy,
+};
+
+#[derive(Clone, Copy, Debug, DataUnit)]
+#[data_unit(copy)]
+enum Cell {
+    /// An integer.
+    Int(i32),
+    Pair(u8, u16),
+    Named {
+        a: u64,
+    },
+    Empty,
+}
+
+#[derive(Debug, DataUnit)]
+#[data_unit(type = BoxedKind)]
+pub enum Boxed {
+    Text(String),
+    Byte(u8),
+}
+
+#[derive(Clone, Debug, DataUnit)]
+#[data_unit(copy)]
+enum Marked {
+    A(char),
+}
+impl Copy for Marked {}
+
+fn assert_copy<T: DataTypeCopy, U: DataUnitCopy>() {}
+
+#[test]
+fn copy() {
+    let c = Cell::Pair(1, 2);
+    assert![c.is_copy()];
+    assert![c.data_type().is_copy()];
+    assert_copy::<CellType, Cell>();
+    assert_copy::<MarkedType, Marked>();
+
+    let b = Boxed::Text("hi".into());
+    assert![!b.is_copy()];
+    assert![!BoxedKind::Byte.is_copy()];
+}
+
+#[test]
+fn data_type() {
+    assert_eq![CellType::Int, Cell::Int(3).data_type()];
+    assert_eq![CellType::Pair, Cell::Pair(1, 2).data_type()];
+    assert_eq![CellType::Named, Cell::Named { a: 0 }.data_type()];
+    assert_eq![CellType::Empty, Cell::Empty.data_type()];
+    assert_eq![BoxedKind::Byte, Boxed::Byte(1).data_type()];
+    assert_eq![BoxedKind::Text, Boxed::Text("".into()).unit_type()];
+}
+
+#[test]
+fn sizes() {
+    assert_eq![size_of::<i32>(), CellType::Int.data_size()];
+    assert_eq![size_of::<(u8, u16)>(), CellType::Pair.data_size()];
+    assert_eq![align_of::<u64>(), CellType::Named.data_align()];
+    assert_eq![
+        (0, 1),
+        (CellType::Empty.data_size(), CellType::Empty.data_align())
+    ];
+    assert_eq![size_of::<String>(), BoxedKind::Text.data_size()];
+}
+
+#[test]
+fn embedded() {
+    let unit = DataUnit32bitWith::With(Boxed::Byte(3));
+    assert![matches![unit, DataUnit32bitWith::With(Boxed::Byte(3))]];
+    assert_eq![DataType32bitWith::With(BoxedKind::Byte), unit.data_type()];
+}
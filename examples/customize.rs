@@ -5,17 +5,45 @@
 use core::mem::size_of;
 use ladata::all::*;
 
-///  custom data.
+/// A custom data unit, holding either an integer or a float.
 #[derive(Clone, Copy, Debug)]
 enum MyCell {
     A(i64),
     B(f64),
 }
 
+/// The custom data type of a [`MyCell`], without its data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MyCellType {
+    A,
+    B,
+}
+
+impl DataType for MyCellType {
+    fn data_align(&self) -> usize {
+        8
+    }
+    fn data_size(&self) -> usize {
+        8
+    }
+    fn is_copy(&self) -> bool {
+        true
+    }
+}
+impl DataTypeCopy for MyCellType {}
+
 impl DataUnit for MyCell {
+    type Type = MyCellType;
+
     fn is_copy(&self) -> bool {
         true
     }
+    fn unit_type(&self) -> MyCellType {
+        match self {
+            MyCell::A(_) => MyCellType::A,
+            MyCell::B(_) => MyCellType::B,
+        }
+    }
 }
 impl DataUnitCopy for MyCell {}
 
@@ -49,6 +77,13 @@ fn main() {
         MyCellInside::Char('®'),
     ];
 
+    // the data type of the embedded data comes from `DataUnit::unit_type`
+    assert_eq![
+        DataType64bitCopyWith::With(MyCellType::B),
+        a2[2].data_type()
+    ];
+    assert_eq![DataType64bitCopyWith::I8, a2[3].data_type()];
+
     println!("\nIterate an array of `MyCellInside`:");
     for c in a2 {
         match c {
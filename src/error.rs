@@ -25,6 +25,9 @@ pub enum LadataError {
     /// The key already exists.
     KeyAlreadyExists,
 
+    /// The key was not found.
+    KeyNotFound,
+
     /// The given index is out of bounds.
     // /// The given index in row or column major order was out of bounds.
     IndexOutOfBounds(usize),
@@ -42,11 +45,23 @@ pub enum LadataError {
     ///
     Underflow,
 
+    /// The divisor of the operation is zero.
+    DivisionByZero,
+
     /// The dimensions given did not match the elements provided
     DimensionMismatch,
 
     /// The node is empty.
     EmptyNode,
+
+    /// The data type doesn't match the expected one.
+    TypeMismatch,
+
+    /// The data can't be encoded, or the encoded bytes are invalid.
+    InvalidEncoding,
+
+    /// The text can't be parsed as the expected data type.
+    InvalidText,
 }
 
 #[cfg(feature = "std")]
@@ -70,6 +85,7 @@ impl fmt::Display for LadataError {
                 }
             }
             LadataError::KeyAlreadyExists => write!(f, "The key already exists."),
+            LadataError::KeyNotFound => write!(f, "The key was not found."),
             LadataError::IndexOutOfBounds(i) => write!(f, "Index {i} is out of bounds."),
             LadataError::Indices2dOutOfBounds(i, j) => {
                 write!(f, "Indices 2d: {i}, {j} are out of bounds.")
@@ -80,9 +96,13 @@ impl fmt::Display for LadataError {
             ),
             LadataError::Overflow => write!(f, "Overflow."),
             LadataError::Underflow => write!(f, "Underflow."),
+            LadataError::DivisionByZero => write!(f, "Division by zero."),
 
             LadataError::DimensionMismatch => write!(f, "Dimension Mismatch."),
             LadataError::EmptyNode => write!(f, "The node is empty."),
+            LadataError::TypeMismatch => write!(f, "Type Mismatch."),
+            LadataError::InvalidEncoding => write!(f, "Invalid encoding."),
+            LadataError::InvalidText => write!(f, "Invalid text."),
         }
     }
 }
@@ -38,11 +38,21 @@ pub mod list;
 // pub mod key;
 pub mod mem;
 pub mod misc;
+pub mod schema;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod table;
 // pub mod tree;
 pub mod unit;
 
 /// All items are reexported here.
 pub mod all {
     #[doc(inline)]
-    pub use super::{error::*, grid::*, list::all::*, mem::all::*, misc::*, unit::all::*};
+    pub use super::{
+        error::*, grid::*, list::all::*, mem::all::*, misc::*, schema::all::*, unit::all::*,
+    };
+
+    #[doc(inline)]
+    #[cfg(feature = "alloc")]
+    pub use super::table::all::*;
 }
@@ -7,7 +7,10 @@ use crate::{
     list::{Array, BitArray},
     mem::Storage,
 };
-use core::fmt;
+use core::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
 
 // Default
 impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> Default
@@ -45,6 +48,96 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> Copy for BitArray<S,
 {
 }
 
+// Not
+impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> Not for BitArray<S, BITLEN, BYTECAP> {
+    type Output = Self;
+
+    /// Inverts every bit, leaving the unused bits beyond `BITLEN` set to `0`.
+    #[inline]
+    fn not(mut self) -> Self {
+        self.map_words(|a| !a);
+        self
+    }
+}
+impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> Not for &BitArray<S, BITLEN, BYTECAP>
+where
+    S::Stored<[u8; BYTECAP]>: Clone,
+{
+    type Output = BitArray<S, BITLEN, BYTECAP>;
+
+    /// Returns a copy with every bit inverted,
+    /// leaving the unused bits beyond `BITLEN` set to `0`.
+    #[inline]
+    fn not(self) -> Self::Output {
+        !self.clone()
+    }
+}
+
+/// Implements a bitwise binary operator and its assignment form between bit
+/// arrays of the same length, across different storages.
+macro_rules! impl_bitwise_op {
+    ($( $Op:ident::$op:ident, $OpAssign:ident::$op_assign:ident, |$a:ident, $b:ident| $expr:expr );+ $(;)?) => { $(
+        impl<S: Storage, S2: Storage, const BITLEN: usize, const BYTECAP: usize>
+            $OpAssign<&BitArray<S2, BITLEN, BYTECAP>> for BitArray<S, BITLEN, BYTECAP>
+        {
+            #[inline]
+            fn $op_assign(&mut self, rhs: &BitArray<S2, BITLEN, BYTECAP>) {
+                self.zip_words(rhs, |$a, $b| $expr);
+            }
+        }
+        impl<S: Storage, S2: Storage, const BITLEN: usize, const BYTECAP: usize>
+            $OpAssign<BitArray<S2, BITLEN, BYTECAP>> for BitArray<S, BITLEN, BYTECAP>
+        {
+            #[inline]
+            fn $op_assign(&mut self, rhs: BitArray<S2, BITLEN, BYTECAP>) {
+                self.zip_words(&rhs, |$a, $b| $expr);
+            }
+        }
+
+        impl<S: Storage, S2: Storage, const BITLEN: usize, const BYTECAP: usize>
+            $Op<&BitArray<S2, BITLEN, BYTECAP>> for BitArray<S, BITLEN, BYTECAP>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $op(mut self, rhs: &BitArray<S2, BITLEN, BYTECAP>) -> Self {
+                self.zip_words(rhs, |$a, $b| $expr);
+                self
+            }
+        }
+        impl<S: Storage, S2: Storage, const BITLEN: usize, const BYTECAP: usize>
+            $Op<BitArray<S2, BITLEN, BYTECAP>> for BitArray<S, BITLEN, BYTECAP>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $op(mut self, rhs: BitArray<S2, BITLEN, BYTECAP>) -> Self {
+                self.zip_words(&rhs, |$a, $b| $expr);
+                self
+            }
+        }
+        impl<S: Storage, S2: Storage, const BITLEN: usize, const BYTECAP: usize>
+            $Op<&BitArray<S2, BITLEN, BYTECAP>> for &BitArray<S, BITLEN, BYTECAP>
+        where
+            S::Stored<[u8; BYTECAP]>: Clone,
+        {
+            type Output = BitArray<S, BITLEN, BYTECAP>;
+
+            #[inline]
+            fn $op(self, rhs: &BitArray<S2, BITLEN, BYTECAP>) -> Self::Output {
+                let mut ba = self.clone();
+                ba.zip_words(rhs, |$a, $b| $expr);
+                ba
+            }
+        }
+    )+ };
+}
+impl_bitwise_op![
+    BitAnd::bitand, BitAndAssign::bitand_assign, |a, b| a & b;
+    BitOr::bitor, BitOrAssign::bitor_assign, |a, b| a | b;
+    BitXor::bitxor, BitXorAssign::bitxor_assign, |a, b| a ^ b;
+];
+
 // Debug
 impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> fmt::Debug
     for BitArray<S, BITLEN, BYTECAP>
@@ -179,3 +272,33 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> fmt::Binary
         Ok(())
     }
 }
+
+// Serialize
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "serde")))]
+impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> serde::Serialize
+    for BitArray<S, BITLEN, BYTECAP>
+{
+    /// Serializes the inner bytes as a tuple of `BYTECAP` bytes.
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        crate::unit::serde_field::serialize_bytes(&self.array, serializer)
+    }
+}
+
+// Deserialize
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "serde")))]
+impl<'de, S: Storage, const BITLEN: usize, const BYTECAP: usize> serde::Deserialize<'de>
+    for BitArray<S, BITLEN, BYTECAP>
+{
+    /// Deserializes the inner bytes from a tuple of `BYTECAP` bytes.
+    ///
+    /// The bits beyond `BITLEN` are set to `0`.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut ba = Self {
+            array: Array::new(crate::unit::serde_field::deserialize_bytes(deserializer)?),
+        };
+        ba.clear_unused_bits();
+        Ok(ba)
+    }
+}
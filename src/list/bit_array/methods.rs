@@ -15,16 +15,18 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN,
     /// Returns a `BitArray` having `BITLEN` bits set to the given `value`,
     /// with a capacity of `BYTECAP` bytes.
     ///
-    /// More precisely every bit of `BYTECAP` will be set to `value`.
+    /// The unused bits of `BYTECAP` beyond `BITLEN` will be set to `0`.
     ///
     /// # Errors
     /// Returns an error if `BITLEN > BYTECAP * 8`.
     #[inline]
     pub fn new(value: bool) -> Result<Self> {
         if BITLEN <= BYTECAP * 8 {
-            Ok(Self {
+            let mut ba = Self {
                 array: Array::new([value as u8 * u8::MAX; BYTECAP]),
-            })
+            };
+            ba.clear_unused_bits();
+            Ok(ba)
         } else {
             Err(Error::DimensionMismatch)
         }
@@ -32,7 +34,7 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN,
     /// Returns a `BitArray` having `BITLEN` bits set to the given `value`,
     /// with a capacity of `BYTECAP` bytes, unchecked version.
     ///
-    /// More precisely every bit of `BYTECAP` will be set to `value`.
+    /// The unused bits of `BYTECAP` beyond `BITLEN` will be set to `0`.
     ///
     /// # Panics
     /// Panics in debug if `BITLEN > BYTECAP * 8`.
@@ -41,9 +43,11 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN,
         #[cfg(debug_assertions)]
         devela::iif![BITLEN > BYTECAP * 8; panic!("BITLEN > BYTECAP * 8")];
 
-        Ok(Self {
-            array: Array::new([value as u8; BYTECAP]),
-        })
+        let mut ba = Self {
+            array: Array::new([value as u8 * u8::MAX; BYTECAP]),
+        };
+        ba.clear_unused_bits();
+        Ok(ba)
     }
 
     /// Returns a `BitArray` having `BITLEN` bits set to `0`, and a capacity of
@@ -113,7 +117,7 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN,
     /// Returns a `BitArray` having `BITLEN` bits set to `1`, and a capacity of
     /// `BYTECAP` bytes.
     ///
-    /// More precisely every bit of `BYTECAP` will be set to `1`.
+    /// The unused bits of `BYTECAP` beyond `BITLEN` will be set to `0`.
     ///
     /// # Errors
     /// Returns an error if `BITLEN > BYTECAP * 8`.
@@ -125,15 +129,18 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN,
     ///
     /// let a = BitArray::<(), 6, 1>::new_oned()?;
     /// assert![a.is_oned()];
+    /// assert_eq![a.as_byte_slice(), &[0b0011_1111]];
     ///
     /// # Ok(()) }
     /// ```
     #[inline]
     pub fn new_oned() -> Result<Self> {
         if BITLEN <= BYTECAP * 8 {
-            Ok(Self {
+            let mut ba = Self {
                 array: Array::new([u8::MAX; BYTECAP]),
-            })
+            };
+            ba.clear_unused_bits();
+            Ok(ba)
         } else {
             Err(Error::DimensionMismatch)
         }
@@ -142,7 +149,7 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN,
     /// Returns a `BitArray` having `BITLEN` bits set to `1`, and a capacity of
     /// `BYTECAP` bytes, unchecked version.
     ///
-    /// More precisely every bit of `BYTECAP` will be set to `1`.
+    /// The unused bits of `BYTECAP` beyond `BITLEN` will be set to `0`.
     ///
     /// # Panics
     /// Panics in debug if `BITLEN > BYTECAP * 8`.
@@ -159,22 +166,27 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN,
         #[cfg(debug_assertions)]
         devela::iif![BITLEN > BYTECAP * 8; panic!("BITLEN > BYTECAP * 8")];
 
-        Self {
+        let mut ba = Self {
             array: Array::new([u8::MAX; BYTECAP]),
-        }
+        };
+        ba.clear_unused_bits();
+        ba
     }
 
     /* reconstructors */
 
     /// Returns itself with a new length, which must fit the same byte capacity.
     ///
-    /// The inner byte array is moved, not copied.
+    /// The inner byte array is moved, not copied. When shrinking, the bits
+    /// beyond `NEW_BITLEN` are set to `0`.
     ///
     /// # Errors
     /// Returns an error if `NEW_BITLEN > BYTECAP * 8`.
     pub fn into_resized<const NEW_BITLEN: usize>(self) -> Result<BitArray<S, NEW_BITLEN, BYTECAP>> {
-        if NEW_BITLEN >= BYTECAP * 8 {
-            Ok(BitArray::<S, NEW_BITLEN, BYTECAP> { array: self.array })
+        if NEW_BITLEN <= BYTECAP * 8 {
+            let mut ba = BitArray::<S, NEW_BITLEN, BYTECAP> { array: self.array };
+            ba.clear_unused_bits();
+            Ok(ba)
         } else {
             Err(Error::DimensionMismatch)
         }
@@ -196,6 +208,9 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN,
 
     /// Returns an exclusive byte slice containing the entire bit array,
     /// including its unused capacity.
+    ///
+    /// The unused bits beyond `BITLEN` must be left as `0`, since the set
+    /// operations rely on it.
     pub fn as_mut_byte_slice(&mut self) -> &mut [u8] {
         self.array.as_mut_slice()
     }
@@ -207,13 +222,17 @@ where
 {
     /// Returns a clone of itself with a new length, which must fit the same byte capacity.
     ///
+    /// When shrinking, the bits beyond `NEW_BITLEN` are set to `0`.
+    ///
     /// # Errors
     /// Returns an error if `NEW_BITLEN > BYTECAP * 8`.
     pub fn resized<const NEW_BITLEN: usize>(&self) -> Result<BitArray<S, NEW_BITLEN, BYTECAP>> {
-        if NEW_BITLEN >= BYTECAP * 8 {
-            Ok(BitArray::<S, NEW_BITLEN, BYTECAP> {
+        if NEW_BITLEN <= BYTECAP * 8 {
+            let mut ba = BitArray::<S, NEW_BITLEN, BYTECAP> {
                 array: self.array.clone(),
-            })
+            };
+            ba.clear_unused_bits();
+            Ok(ba)
         } else {
             Err(Error::DimensionMismatch)
         }
@@ -581,10 +600,271 @@ impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN,
         true
     }
 
-    // /// Computes the intersection of this bit array with another.
-    // pub fn intersect<S2: Storage>(&mut self, other: &BitArray<S2, BITLEN, BYTECAP>) {
-    //     for i in 0..BYTECAP {
-    //         self.array[i] &= other.array[i];
-    //     }
-    // }
+    /// Returns the number of bits set to `1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::all::BitArray;
+    /// # fn main() -> ladata::all::LadataResult<()> {
+    ///
+    /// let mut a = BitArray::<(), 13, 2>::new_zeroed()?;
+    /// a.set_one(0)?;
+    /// a.set_one(12)?;
+    /// assert_eq![2, a.count_ones()];
+    /// assert_eq![11, (!a).count_ones()];
+    /// # Ok(()) }
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        let mut words = self.array.chunks_exact(8);
+        let mut count = 0;
+        for word in &mut words {
+            count += u64::from_ne_bytes(word.try_into().unwrap()).count_ones() as usize;
+        }
+        for byte in words.remainder() {
+            count += byte.count_ones() as usize;
+        }
+        count
+    }
+
+    /// Returns the number of bits with `index < BITLEN` set to `0`.
+    pub fn count_zeros(&self) -> usize {
+        BITLEN - self.count_ones()
+    }
+
+    /// Returns `true` if every bit set in `self` is also set in `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::all::BitArray;
+    /// # fn main() -> ladata::all::LadataResult<()> {
+    ///
+    /// let mut a = BitArray::<(), 10, 2>::new_zeroed()?;
+    /// let mut b = BitArray::<(), 10, 2>::new_zeroed()?;
+    /// a.set_one(9)?;
+    /// b.set_one(9)?;
+    /// b.set_one(0)?;
+    /// assert![a.is_subset(&b)];
+    /// assert![!b.is_subset(&a)];
+    /// assert![b.is_superset(&a)];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    pub fn is_subset<S2: Storage>(&self, other: &BitArray<S2, BITLEN, BYTECAP>) -> bool {
+        self.all_words(other, |a, b| a & !b == 0)
+    }
+
+    /// Returns `true` if every bit set in `other` is also set in `self`.
+    #[inline]
+    pub fn is_superset<S2: Storage>(&self, other: &BitArray<S2, BITLEN, BYTECAP>) -> bool {
+        self.all_words(other, |a, b| !a & b == 0)
+    }
+
+    /// Returns `true` if `self` and `other` have no set bits in common.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::all::BitArray;
+    /// # fn main() -> ladata::all::LadataResult<()> {
+    ///
+    /// let mut a = BitArray::<(), 70, 9>::new_zeroed()?;
+    /// let mut b = BitArray::<(), 70, 9>::new_zeroed()?;
+    /// a.set_one(3)?;
+    /// b.set_one(69)?;
+    /// assert![a.is_disjoint(&b)];
+    ///
+    /// b.set_one(3)?;
+    /// assert![!a.is_disjoint(&b)];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    pub fn is_disjoint<S2: Storage>(&self, other: &BitArray<S2, BITLEN, BYTECAP>) -> bool {
+        self.all_words(other, |a, b| a & b == 0)
+    }
+}
+
+/// # set operations
+impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN, BYTECAP>
+where
+    S::Stored<[u8; BYTECAP]>: Clone,
+{
+    /// Returns a new bit array with the bits set in either `self` or `other`.
+    ///
+    /// This is the same as `self.clone() | other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::all::{BitArray, Boxed};
+    /// # fn main() -> ladata::all::LadataResult<()> {
+    ///
+    /// let mut a = BitArray::<(), 12, 2>::new_zeroed()?;
+    /// let mut b = BitArray::<Boxed, 12, 2>::new_zeroed()?;
+    /// a.set_one(1)?;
+    /// b.set_one(11)?;
+    /// assert_eq![format!["{:b}", a.union(&b)], "100000000010"];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn union<S2: Storage>(&self, other: &BitArray<S2, BITLEN, BYTECAP>) -> Self {
+        let mut ba = self.clone();
+        ba.zip_words(other, |a, b| a | b);
+        ba
+    }
+
+    /// Returns a new bit array with the bits set in both `self` and `other`.
+    ///
+    /// This is the same as `self.clone() & other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::all::{BitArray, Boxed};
+    /// # fn main() -> ladata::all::LadataResult<()> {
+    ///
+    /// let mut a = BitArray::<(), 77, 10>::new_zeroed()?;
+    /// let mut b = BitArray::<Boxed, 77, 10>::new_oned()?;
+    /// a.set_one(0)?;
+    /// a.set_one(76)?;
+    /// b.set_zero(0)?;
+    /// assert![a.intersection(&b).get_bit(76)?];
+    /// assert_eq![a.intersection(&b).as_byte_slice(), (a.clone() & &b).as_byte_slice()];
+    ///
+    /// // the unused bits beyond `BITLEN` stay zeroed
+    /// assert_eq![(!&a).as_byte_slice()[9], 0b0000_1111];
+    /// assert_eq![(!a ^ b).as_byte_slice()[9], 0b0001_0000];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn intersection<S2: Storage>(&self, other: &BitArray<S2, BITLEN, BYTECAP>) -> Self {
+        let mut ba = self.clone();
+        ba.zip_words(other, |a, b| a & b);
+        ba
+    }
+
+    /// Returns a new bit array with the bits set in `self` but not in `other`.
+    ///
+    /// This is the same as `self.clone() & !other.clone()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::all::BitArray;
+    /// # fn main() -> ladata::all::LadataResult<()> {
+    ///
+    /// let mut a = BitArray::<(), 4, 1>::new_oned()?;
+    /// let mut b = BitArray::<(), 4, 1>::new_zeroed()?;
+    /// b.set_one(0)?;
+    /// b.set_one(2)?;
+    /// assert_eq![format!["{:b}", a.difference(&b)], "1010"];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn difference<S2: Storage>(&self, other: &BitArray<S2, BITLEN, BYTECAP>) -> Self {
+        let mut ba = self.clone();
+        ba.zip_words(other, |a, b| a & !b);
+        ba
+    }
+
+    /// Returns a new bit array with the bits set in either `self` or `other`,
+    /// but not in both.
+    ///
+    /// This is the same as `self.clone() ^ other`.
+    #[inline]
+    #[must_use]
+    pub fn symmetric_difference<S2: Storage>(&self, other: &BitArray<S2, BITLEN, BYTECAP>) -> Self {
+        let mut ba = self.clone();
+        ba.zip_words(other, |a, b| a ^ b);
+        ba
+    }
+}
+
+// Private
+//
+// The bytes are processed a word of 8 bytes at a time, and the remaining
+// bytes one by one. The unused bits beyond `BITLEN` are always kept zeroed.
+impl<S: Storage, const BITLEN: usize, const BYTECAP: usize> BitArray<S, BITLEN, BYTECAP> {
+    /// Sets every bit beyond `BITLEN` to `0`.
+    ///
+    /// Does nothing if `BITLEN >= BYTECAP * 8`, since there are no unused bits.
+    #[inline]
+    pub(crate) fn clear_unused_bits(&mut self) {
+        if BITLEN >= BYTECAP * 8 {
+            return;
+        }
+        let full_bytes = BITLEN / 8;
+        let remaining_bits = BITLEN % 8;
+
+        if remaining_bits != 0 {
+            self.array[full_bytes] &= (1 << remaining_bits) - 1;
+            self.array[full_bytes + 1..].fill(0);
+        } else {
+            self.array[full_bytes..].fill(0);
+        }
+    }
+
+    /// Replaces each word with the result of `op`.
+    #[inline]
+    pub(super) fn map_words(&mut self, op: impl Fn(u64) -> u64) {
+        let mut words = self.array.chunks_exact_mut(8);
+        for word in &mut words {
+            let w = op(u64::from_ne_bytes(word.try_into().unwrap()));
+            word.copy_from_slice(&w.to_ne_bytes());
+        }
+        for byte in words.into_remainder() {
+            *byte = op(*byte as u64) as u8;
+        }
+        self.clear_unused_bits();
+    }
+
+    /// Replaces each word with the result of `op` applied to it and to the
+    /// word at the same position in `other`.
+    #[inline]
+    pub(super) fn zip_words<S2: Storage>(
+        &mut self,
+        other: &BitArray<S2, BITLEN, BYTECAP>,
+        op: impl Fn(u64, u64) -> u64,
+    ) {
+        let mut words = self.array.chunks_exact_mut(8);
+        let mut other_words = other.array.chunks_exact(8);
+        for (word, other_word) in (&mut words).zip(&mut other_words) {
+            let w = op(
+                u64::from_ne_bytes(word.try_into().unwrap()),
+                u64::from_ne_bytes(other_word.try_into().unwrap()),
+            );
+            word.copy_from_slice(&w.to_ne_bytes());
+        }
+        for (byte, other_byte) in words
+            .into_remainder()
+            .iter_mut()
+            .zip(other_words.remainder())
+        {
+            *byte = op(*byte as u64, *other_byte as u64) as u8;
+        }
+        self.clear_unused_bits();
+    }
+
+    /// Returns `true` if `pred` holds for every pair of words at the same
+    /// position in `self` and `other`.
+    #[inline]
+    fn all_words<S2: Storage>(
+        &self,
+        other: &BitArray<S2, BITLEN, BYTECAP>,
+        pred: impl Fn(u64, u64) -> bool,
+    ) -> bool {
+        let mut words = self.array.chunks_exact(8);
+        let mut other_words = other.array.chunks_exact(8);
+        for (word, other_word) in (&mut words).zip(&mut other_words) {
+            if !pred(
+                u64::from_ne_bytes(word.try_into().unwrap()),
+                u64::from_ne_bytes(other_word.try_into().unwrap()),
+            ) {
+                return false;
+            }
+        }
+        words
+            .remainder()
+            .iter()
+            .zip(other_words.remainder())
+            .all(|(byte, other_byte)| pred(*byte as u64, *other_byte as u64))
+    }
 }
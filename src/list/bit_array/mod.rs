@@ -12,6 +12,8 @@ use crate::{list::Array, mem::Storage, misc::DataCollection};
 
 mod impls;
 mod methods;
+#[cfg(test)]
+mod tests;
 
 /// An array of bits, backed by an [`Array`] of bytes.
 pub struct BitArray<S: Storage, const BITLEN: usize, const BYTECAP: usize> {
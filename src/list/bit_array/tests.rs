@@ -0,0 +1,134 @@
+// ladata::list::bit_array::tests
+//!
+//
+
+use super::*;
+
+/// Returns a bit array with the bits at `indices` set to `1`.
+fn with_ones<S: Storage, const BITLEN: usize, const BYTECAP: usize>(
+    indices: &[usize],
+) -> BitArray<S, BITLEN, BYTECAP> {
+    let mut ba = BitArray::new_zeroed().unwrap();
+    for &i in indices {
+        ba.set_one(i).unwrap();
+    }
+    ba
+}
+
+/// Returns the indices of the bits set to `1`.
+fn ones<S: Storage, const BITLEN: usize, const BYTECAP: usize>(
+    ba: &BitArray<S, BITLEN, BYTECAP>,
+) -> impl Iterator<Item = usize> + '_ {
+    (0..BITLEN).filter(|&i| ba.get_bit(i).unwrap())
+}
+
+#[test]
+fn bit_array_unused_bits_zeroed() {
+    // the unused bits are never set by the constructors
+    let a = BitArray::<(), 13, 2>::new_oned().unwrap();
+    assert_eq![a.as_byte_slice(), &[0xFF, 0b1_1111]];
+    assert_eq![13, a.count_ones()];
+    assert_eq![0, a.count_zeros()];
+    assert_eq![13, BitArray::<(), 13, 2>::new(true).unwrap().count_ones()];
+    assert_eq![
+        13,
+        BitArray::<(), 13, 2>::new_unchecked(true)
+            .unwrap()
+            .count_ones()
+    ];
+    assert_eq![13, BitArray::<(), 13, 3>::new_oned_unchecked().count_ones()];
+
+    // nor left behind when shrinking
+    let b = a.resized::<4>().unwrap();
+    assert_eq![b.as_byte_slice(), &[0b1111, 0]];
+    assert![a.into_resized::<20>().is_err()];
+
+    // a length above the capacity leaves no unused bits to clear
+    let mut c = BitArray::<(), 20, 2> {
+        array: Array::new([0xFF; 2]),
+    };
+    c.clear_unused_bits();
+    assert_eq![c.as_byte_slice(), &[0xFF, 0xFF]];
+}
+
+#[test]
+fn bit_array_not() {
+    let a: BitArray<(), 13, 2> = with_ones(&[0, 5, 12]);
+    let n = !&a;
+    assert_eq![10, n.count_ones()];
+    assert_eq![3, n.count_zeros()];
+    assert_eq![n.as_byte_slice()[1] & !0b1_1111, 0];
+    assert![ones(&n).eq((1..12).filter(|&i| i != 5))];
+    assert_eq![(!n).as_byte_slice(), a.as_byte_slice()];
+
+    // across whole words and the remaining bytes
+    let z = BitArray::<(), 77, 10>::new_zeroed().unwrap();
+    let o = !z;
+    assert_eq![77, o.count_ones()];
+    assert_eq![&o.as_byte_slice()[..9], &[0xFF; 9]];
+    assert_eq![o.as_byte_slice()[9], 0b1_1111];
+    assert![o.is_oned()];
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn bit_array_binary_ops() {
+    let a: BitArray<(), 13, 2> = with_ones(&[0, 5, 12]);
+    let b: BitArray<Boxed, 13, 2> = with_ones(&[5, 6]);
+
+    assert![ones(&(a & &b)).eq([5])];
+    assert![ones(&(a | &b)).eq([0, 5, 6, 12])];
+    assert![ones(&(a ^ &b)).eq([0, 6, 12])];
+    assert![ones(&(&a & &b)).eq([5])];
+    assert![ones(&(b.clone() | a)).eq([0, 5, 6, 12])];
+
+    let mut c = a;
+    c &= &b;
+    assert![ones(&c).eq([5])];
+    c |= b.clone();
+    assert![ones(&c).eq([5, 6])];
+    c ^= &a;
+    assert![ones(&c).eq([0, 6, 12])];
+
+    // the set methods match the operators
+    assert![ones(&a.union(&b)).eq([0, 5, 6, 12])];
+    assert![ones(&a.intersection(&b)).eq([5])];
+    assert![ones(&a.difference(&b)).eq([0, 12])];
+    assert![ones(&b.difference(&a)).eq([6])];
+    assert![ones(&a.symmetric_difference(&b)).eq([0, 6, 12])];
+    assert_eq![
+        a.difference(&b).as_byte_slice(),
+        (a & !b.clone()).as_byte_slice()
+    ];
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn bit_array_set_relations() {
+    let a: BitArray<(), 77, 10> = with_ones(&[3, 64, 76]);
+    let b: BitArray<Boxed, 77, 10> = with_ones(&[3, 8, 64, 76]);
+    let c: BitArray<(), 77, 10> = with_ones(&[8, 70]);
+
+    assert![a.is_subset(&b)];
+    assert![!b.is_subset(&a)];
+    assert![b.is_superset(&a)];
+    assert![!a.is_superset(&b)];
+    assert![a.is_subset(&a) && a.is_superset(&a)];
+    assert![a.is_disjoint(&c)];
+    assert![!b.is_disjoint(&c)];
+
+    // the complement is disjoint, and everything is a subset of it plus itself
+    let n = !a;
+    assert![a.is_disjoint(&n)];
+    assert_eq![77, (a | &n).count_ones()];
+    assert![b.is_subset(&(a | &n))];
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn bit_array_deserialize_clears_unused_bits() {
+    let a: BitArray<(), 13, 2> = serde_json::from_str("[255,255]").unwrap();
+    assert_eq![a.as_byte_slice(), &[0xFF, 0b1_1111]];
+    assert_eq![13, a.count_ones()];
+    assert_eq![0, (!a).count_ones()];
+}
@@ -5,10 +5,10 @@
 
 use core::fmt;
 
-use super::{Array, Deque, Storage};
+use super::{Array, Deque, DequeIter, DequeIterMut, Storage};
 
 #[cfg(feature = "alloc")]
-use crate::mem::Boxed;
+use {crate::mem::Boxed, alloc::vec};
 
 // T:Clone
 impl<T: Clone, S: Storage, const CAP: usize> Clone for Deque<T, S, CAP>
@@ -120,41 +120,132 @@ impl<T: Default, const CAP: usize> Default for Deque<T, Boxed, CAP> {
     }
 }
 
-impl<T: Default, I, const CAP: usize> From<I> for Deque<T, (), CAP>
-where
-    I: IntoIterator<Item = T>,
-{
-    /// Returns a queue filled with an iterator, in the stack.
+impl<T: Default, const CAP: usize, const N: usize> From<[T; N]> for Deque<T, (), CAP> {
+    /// Returns a deque filled with the elements of an array, in the stack.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
     ///
     /// # Examples
     /// ```
-    /// use ladata::all::DirectDeque;
+    /// use ladata::list::DirectDeque;
     ///
     /// let s: DirectDeque<_, 3> = [1, 2, 3].into();
     /// ```
-    fn from(iterator: I) -> Deque<T, (), CAP> {
-        let mut s = Deque::<T, (), CAP>::default();
-        let _ = s.extend_back(iterator);
-        s
+    fn from(array: [T; N]) -> Deque<T, (), CAP> {
+        array.into_iter().collect()
     }
 }
 
 #[cfg(feature = "alloc")]
-impl<T: Default, I, const CAP: usize> From<I> for Deque<T, Boxed, CAP>
-where
-    I: IntoIterator<Item = T>,
-{
-    /// Returns a queue filled with an iterator, in the heap.
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize, const N: usize> From<[T; N]> for Deque<T, Boxed, CAP> {
+    /// Returns a deque filled with the elements of an array, in the heap.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
     ///
     /// # Examples
     /// ```
-    /// use ladata::all::BoxedDeque;
+    /// use ladata::list::BoxedDeque;
     ///
     /// let s: BoxedDeque<_, 3> = [1, 2, 3].into();
     /// ```
-    fn from(iterator: I) -> Deque<T, Boxed, CAP> {
-        let mut s = Deque::<T, Boxed, CAP>::default();
+    fn from(array: [T; N]) -> Deque<T, Boxed, CAP> {
+        array.into_iter().collect()
+    }
+}
+
+/* FromIterator */
+
+impl<T: Default, const CAP: usize> FromIterator<T> for Deque<T, (), CAP> {
+    /// Returns a deque filled with an iterator, in the stack.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    ///
+    /// let s: DirectDeque<_, 3> = (1..=5).collect();
+    /// assert_eq![3, s.len()];
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut s = Self::default();
         let _ = s.extend_back(iterator);
         s
     }
 }
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize> FromIterator<T> for Deque<T, Boxed, CAP> {
+    /// Returns a deque filled with an iterator, in the heap.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut s = Self::default();
+        let _ = s.extend_back(iterator);
+        s
+    }
+}
+
+/* IntoIterator */
+
+impl<T, const CAP: usize> IntoIterator for Deque<T, (), CAP> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, CAP>;
+
+    /// Returns an owning iterator, from the front to the back.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    ///
+    /// let q = DirectDeque::<_, 4>::from([1, 2, 3]);
+    /// let mut sum = 0;
+    /// for n in q {
+    ///     sum += n;
+    /// }
+    /// assert_eq![6, sum];
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_front()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T, const CAP: usize> IntoIterator for Deque<T, Boxed, CAP> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    /// Returns an owning iterator, from the front to the back.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedDeque;
+    ///
+    /// let q = BoxedDeque::<_, 4>::from([1, 2, 3]);
+    /// assert_eq![vec![1, 2, 3], q.into_iter().collect::<Vec<_>>()];
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_front()
+    }
+}
+
+impl<'s, T, S: Storage, const CAP: usize> IntoIterator for &'s Deque<T, S, CAP> {
+    type Item = &'s T;
+    type IntoIter = DequeIter<'s, T, S, CAP>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'s, T, S: Storage, const CAP: usize> IntoIterator for &'s mut Deque<T, S, CAP> {
+    type Item = &'s mut T;
+    type IntoIter = DequeIterMut<'s, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
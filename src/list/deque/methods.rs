@@ -9,17 +9,20 @@ use core::{
     ptr,
 };
 
-use super::{Array, Deque, DequeIter};
+use core::{cmp::Ordering, ops::RangeBounds};
+
+use super::{Array, Deque, DequeDrain, DequeIter, DequeIterMut, DequeRange, DequeRangeMut};
 
 use crate::{
     error::{LadataError as Error, LadataResult as Result},
+    list::range_bounds,
     mem::Storage,
 };
 
 #[cfg(feature = "alloc")]
 use {
     crate::mem::Boxed,
-    alloc::{vec, vec::Vec},
+    alloc::{boxed::Box, vec, vec::Vec},
 };
 
 // `S:() + T:Clone`
@@ -66,6 +69,60 @@ impl<T: Clone, const CAP: usize> Deque<T, Boxed, CAP> {
     }
 }
 
+// `S:()`
+impl<T, const CAP: usize> Deque<T, (), CAP> {
+    /// Returns an owning double-ended iterator, starting at the front.
+    ///
+    /// It doesn't depend on `T: Clone`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 4>::from([1, 2, 3]);
+    /// q.push_front(0)?;
+    /// assert![q.into_iter_front().rev().eq([3, 2, 1, 0])];
+    /// # Ok(()) }
+    /// ```
+    pub fn into_iter_front(mut self) -> core::array::IntoIter<T, CAP> {
+        self.array.rotate_left(self.front);
+        let mut iter = self.array.into_array().into_iter();
+        if self.len < CAP {
+            // drops the unused elements
+            let _ = iter.nth_back(CAP - self.len - 1);
+        }
+        iter
+    }
+}
+
+// `S:Boxed`
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T, const CAP: usize> Deque<T, Boxed, CAP> {
+    /// Returns an owning double-ended iterator, starting at the front.
+    ///
+    /// It doesn't depend on `T: Clone`, and reuses the heap allocation.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = BoxedDeque::<_, 4>::from([1, 2, 3]);
+    /// q.push_front(0)?;
+    /// assert![q.into_iter_front().eq([0, 1, 2, 3])];
+    /// # Ok(()) }
+    /// ```
+    pub fn into_iter_front(mut self) -> vec::IntoIter<T> {
+        self.array.rotate_left(self.front);
+        let boxed: Box<[T]> = self.array.into_array();
+        let mut vec = boxed.into_vec();
+        vec.truncate(self.len);
+        vec.into_iter()
+    }
+}
+
 // ``
 impl<T, S: Storage, const CAP: usize> Deque<T, S, CAP> {
     // Returns the `nth` element's index counting from the back.
@@ -163,10 +220,38 @@ impl<T, S: Storage, const CAP: usize> Deque<T, S, CAP> {
 
     /* iter */
 
+    /// Returns an iterator over shared references, from the front to the back.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    ///
+    /// let q = DirectDeque::<_, 4>::from([1, 2, 3]);
+    /// assert![q.iter().eq(&[1, 2, 3])];
+    /// assert![q.iter().rev().eq(&[3, 2, 1])];
+    /// ```
     pub fn iter(&self) -> DequeIter<'_, T, S, CAP> {
         DequeIter {
             deque: self,
             idx: 0,
+            end: self.len,
+        }
+    }
+
+    /// Returns an iterator over exclusive references, from the front to the back.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    ///
+    /// let mut q = DirectDeque::<_, 4>::from([1, 2, 3]);
+    /// q.iter_mut().for_each(|e| *e *= 10);
+    /// assert_eq![q.to_array(), Some([10, 20, 30])];
+    /// ```
+    pub fn iter_mut(&mut self) -> DequeIterMut<'_, T> {
+        let (a, b) = self.as_mut_slices();
+        DequeIterMut {
+            iter: a.iter_mut().chain(b.iter_mut()),
         }
     }
 
@@ -177,7 +262,8 @@ impl<T, S: Storage, const CAP: usize> Deque<T, S, CAP> {
     /// `( 1 2 -- 1 2 3 4 5 6)` for `[3 4 5 6]`
     ///
     /// # Errors
-    /// Errors if the deque becomes full before the iterator finishes.
+    /// Errors if the deque becomes full while the iterator still has elements,
+    /// in which case the first element that doesn't fit is dropped.
     ///
     /// # Examples
     /// ```
@@ -191,15 +277,16 @@ impl<T, S: Storage, const CAP: usize> Deque<T, S, CAP> {
     where
         I: IntoIterator<Item = T>,
     {
-        let mut iter = iterator.into_iter();
-        while !self.is_full() {
+        let mut iter = iterator.into_iter().peekable();
+        while iter.peek().is_some() {
+            if self.is_full() {
+                return Err(Error::NotEnoughSpace(None));
+            }
             if let Some(e) = iter.next() {
                 self.push_back_unchecked(e);
-            } else {
-                return Ok(());
             }
         }
-        Err(Error::NotEnoughSpace(None))
+        Ok(())
     }
 
     /// Extends the front of the queue from an iterator.
@@ -207,7 +294,8 @@ impl<T, S: Storage, const CAP: usize> Deque<T, S, CAP> {
     /// `( 1 2 -- 6 5 4 3 1 2 )` for `[3 4 5 6]`
     ///
     /// # Errors
-    /// Errors if the queue becomes full before the iterator finishes.
+    /// Errors if the deque becomes full while the iterator still has elements,
+    /// in which case the first element that doesn't fit is dropped.
     ///
     /// # Examples
     /// ```
@@ -221,15 +309,16 @@ impl<T, S: Storage, const CAP: usize> Deque<T, S, CAP> {
     where
         I: IntoIterator<Item = T>,
     {
-        let mut iter = iterator.into_iter();
-        while !self.is_full() {
+        let mut iter = iterator.into_iter().peekable();
+        while iter.peek().is_some() {
+            if self.is_full() {
+                return Err(Error::NotEnoughSpace(None));
+            }
             if let Some(e) = iter.next() {
                 self.push_front_unchecked(e);
-            } else {
-                return Ok(());
             }
         }
-        Err(Error::NotEnoughSpace(None))
+        Ok(())
     }
 
     /* push */
@@ -325,6 +414,65 @@ impl<T, S: Storage, const CAP: usize> Deque<T, S, CAP> {
         self.len += 1;
     }
 
+    /// Pushes a new element to the front of the queue,
+    /// overwriting the back element if the queue is full.
+    ///
+    /// Returns the overwritten element, if any.
+    ///
+    /// `( 1 2 3 -- 4 1 2 )` for `CAP == 3`
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    ///
+    /// let mut q = DirectDeque::<u8, 3>::from([1, 2]);
+    /// assert_eq![None, q.push_front_overwrite(3)];
+    /// assert_eq![Some(2), q.push_front_overwrite(4)];
+    /// assert_eq![q.to_array(), Some([4, 3, 1])];
+    /// ```
+    pub fn push_front_overwrite(&mut self, element: T) -> Option<T> {
+        if CAP == 0 {
+            Some(element)
+        } else if self.is_full() {
+            self.front = (self.front + CAP - 1) % CAP;
+            self.back = self.front;
+            Some(core::mem::replace(&mut self.array[self.front], element))
+        } else {
+            self.push_front_unchecked(element);
+            None
+        }
+    }
+
+    /// Pushes a new element to the back of the queue,
+    /// overwriting the front element if the queue is full.
+    ///
+    /// Returns the overwritten element, if any.
+    ///
+    /// `( 1 2 3 -- 2 3 4 )` for `CAP == 3`
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    ///
+    /// let mut q = DirectDeque::<u8, 3>::from([1, 2]);
+    /// assert_eq![None, q.push_back_overwrite(3)];
+    /// assert_eq![Some(1), q.push_back_overwrite(4)];
+    /// assert_eq![q.to_array(), Some([2, 3, 4])];
+    /// ```
+    pub fn push_back_overwrite(&mut self, element: T) -> Option<T> {
+        if CAP == 0 {
+            Some(element)
+        } else if self.is_full() {
+            let evicted = core::mem::replace(&mut self.array[self.back], element);
+            self.back = (self.back + 1) % CAP;
+            self.front = self.back;
+            Some(evicted)
+        } else {
+            self.push_back_unchecked(element);
+            None
+        }
+    }
+
     /* peek */
 
     /// Returns a shared reference to the front element.
@@ -1611,6 +1759,508 @@ impl<T: Clone, S: Storage, const CAP: usize> Deque<T, S, CAP> {
     }
 }
 
+// ``
+impl<T, S: Storage, const CAP: usize> Deque<T, S, CAP> {
+    /* slices */
+
+    /// Returns the two contiguous parts of the deque,
+    /// from the front to the back.
+    ///
+    /// The second slice is empty if the elements are already contiguous.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 4>::from([1, 2, 3]);
+    /// q.push_front(0)?;
+    /// assert_eq![(&[0][..], &[1, 2, 3][..]), q.as_slices()];
+    /// # Ok(()) }
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let end = self.front + self.len;
+        if end <= CAP {
+            (&self.array[self.front..end], &[])
+        } else {
+            let (wrapped, front) = self.array.split_at(self.front);
+            (front, &wrapped[..end - CAP])
+        }
+    }
+
+    /// Returns the two contiguous parts of the deque as exclusive slices,
+    /// from the front to the back.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let end = self.front + self.len;
+        if end <= CAP {
+            (&mut self.array[self.front..end], &mut [])
+        } else {
+            let (wrapped, front) = self.array.split_at_mut(self.front);
+            (front, &mut wrapped[..end - CAP])
+        }
+    }
+
+    /* random access */
+
+    /// Returns a shared reference to the element at `index`,
+    /// counting from the front.
+    ///
+    /// # Errors
+    /// Errors if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let q = DirectDeque::<_, 4>::from([1, 2, 3]);
+    /// assert_eq![&2, q.get(1)?];
+    /// assert![q.get(3).is_err()];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Result<&T> {
+        if index >= self.len {
+            Err(Error::IndexOutOfBounds(index))
+        } else {
+            Ok(&self.array[self.idx_front(index)])
+        }
+    }
+
+    /// Returns an exclusive reference to the element at `index`,
+    /// counting from the front.
+    ///
+    /// # Errors
+    /// Errors if `index >= len`.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T> {
+        if index >= self.len {
+            Err(Error::IndexOutOfBounds(index))
+        } else {
+            let i = self.idx_front(index);
+            Ok(&mut self.array[i])
+        }
+    }
+
+    /// Swaps the elements at indices `a` and `b`, counting from the front.
+    ///
+    /// # Errors
+    /// Errors if either index is `>= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 4>::from([1, 2, 3]);
+    /// q.swap(0, 2)?;
+    /// assert_eq![q.to_array(), Some([3, 2, 1])];
+    /// # Ok(()) }
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) -> Result<()> {
+        if a >= self.len {
+            Err(Error::IndexOutOfBounds(a))
+        } else if b >= self.len {
+            Err(Error::IndexOutOfBounds(b))
+        } else {
+            self.swap_unchecked(a, b);
+            Ok(())
+        }
+    }
+    // Swaps the elements at indices `a` and `b`, counting from the front.
+    #[inline]
+    fn swap_unchecked(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.idx_front(a), self.idx_front(b));
+        self.array.swap(a, b);
+    }
+
+    /// Inserts an element at `index`, counting from the front.
+    ///
+    /// Moves either the elements before or after `index`,
+    /// whichever are fewer.
+    ///
+    /// # Errors
+    /// Errors if the deque is full, or if `index > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 5>::from([1, 2, 3, 4]);
+    /// q.insert(1, 9)?;
+    /// assert_eq![q.to_array(), Some([1, 9, 2, 3, 4])];
+    /// # Ok(()) }
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) -> Result<()> {
+        if index > self.len {
+            Err(Error::IndexOutOfBounds(index))
+        } else if self.is_full() {
+            Err(Error::NotEnoughSpace(Some(1)))
+        } else {
+            if index <= self.len / 2 {
+                self.push_front_unchecked(element);
+                for i in 0..index {
+                    self.swap_unchecked(i, i + 1);
+                }
+            } else {
+                self.push_back_unchecked(element);
+                for i in (index..self.len - 1).rev() {
+                    self.swap_unchecked(i, i + 1);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Returns an iterator over the elements in `range`, counting from the front.
+    ///
+    /// # Errors
+    /// Errors if the range is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 5>::from([2, 3, 4, 5]);
+    /// q.push_front(1)?;
+    /// assert![q.range(..3)?.eq(&[1, 2, 3])];
+    /// assert![q.range(1..=3)?.rev().eq(&[4, 3, 2])];
+    /// # Ok(()) }
+    /// ```
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Result<DequeRange<'_, T>> {
+        let (start, end) = range_bounds(&range, self.len)?;
+        let (a, b) = self.as_slices();
+        let (a, b) = if end <= a.len() {
+            (&a[start..end], &b[..0])
+        } else if start >= a.len() {
+            (&b[start - a.len()..end - a.len()], &a[..0])
+        } else {
+            (&a[start..], &b[..end - a.len()])
+        };
+        Ok(DequeRange {
+            iter: a.iter().chain(b.iter()),
+        })
+    }
+
+    /// Returns an iterator over exclusive references to the elements in `range`,
+    /// counting from the front.
+    ///
+    /// # Errors
+    /// Errors if the range is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 4>::from([1, 2, 3, 4]);
+    /// q.range_mut(2..)?.for_each(|e| *e *= 10);
+    /// assert_eq![q.to_array(), Some([1, 2, 30, 40])];
+    /// # Ok(()) }
+    /// ```
+    pub fn range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Result<DequeRangeMut<'_, T>> {
+        let (start, end) = range_bounds(&range, self.len)?;
+        let (a, b) = self.as_mut_slices();
+        let alen = a.len();
+        let (a, b) = if end <= alen {
+            (&mut a[start..end], &mut b[..0])
+        } else if start >= alen {
+            (&mut b[start - alen..end - alen], &mut a[..0])
+        } else {
+            (&mut a[start..], &mut b[..end - alen])
+        };
+        Ok(DequeRangeMut {
+            iter: a.iter_mut().chain(b.iter_mut()),
+        })
+    }
+
+    /// Retains only the elements for which the predicate returns `true`,
+    /// preserving their order.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    ///
+    /// let mut q = DirectDeque::<_, 6>::from([1, 2, 3, 4, 5, 6]);
+    /// q.retain(|e| e % 3 != 0);
+    /// assert_eq![q.to_array(), Some([1, 2, 4, 5])];
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut kept = 0;
+        for i in 0..self.len {
+            if f(&self.array[self.idx_front(i)]) {
+                self.swap_unchecked(kept, i);
+                kept += 1;
+            }
+        }
+        self.truncate_back(kept);
+    }
+
+    /// Rotates the deque so that the element at `index` becomes the front,
+    /// preserving the circular order of the elements.
+    ///
+    /// A full deque only updates its indices, otherwise it moves the elements
+    /// before or after `index` to the other end, whichever are fewer.
+    ///
+    /// # Errors
+    /// Errors if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 8>::from([1, 2, 3, 4, 5]);
+    /// q.rotate_to(3)?;
+    /// assert_eq![q.to_array(), Some([4, 5, 1, 2, 3])];
+    /// # Ok(()) }
+    /// ```
+    pub fn rotate_to(&mut self, index: usize) -> Result<()> {
+        if index >= self.len {
+            Err(Error::IndexOutOfBounds(index))
+        } else {
+            if self.is_full() {
+                self.front = self.idx_front(index);
+                self.back = self.front;
+            } else if index <= self.len / 2 {
+                // move the first `index` elements from the front to the back
+                for _ in 0..index {
+                    self.array.swap(self.front, self.back);
+                    self.front = (self.front + 1) % CAP;
+                    self.back = (self.back + 1) % CAP;
+                }
+            } else {
+                // move the last `len - index` elements from the back to the front
+                for _ in index..self.len {
+                    self.front = (self.front + CAP - 1) % CAP;
+                    self.back = (self.back + CAP - 1) % CAP;
+                    self.array.swap(self.front, self.back);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Shortens the deque from the front, keeping the last `len` elements.
+    ///
+    /// Does nothing if `len` is greater than the current length.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    ///
+    /// let mut q = DirectDeque::<_, 4>::from([1, 2, 3, 4]);
+    /// q.truncate_front(3);
+    /// assert_eq![q.to_array(), Some([2, 3, 4])];
+    /// ```
+    pub fn truncate_front(&mut self, len: usize) {
+        if len < self.len {
+            self.front = self.idx_front(self.len - len);
+            self.len = len;
+        }
+    }
+
+    /// Shortens the deque from the back, keeping the first `len` elements.
+    ///
+    /// Does nothing if `len` is greater than the current length.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    ///
+    /// let mut q = DirectDeque::<_, 4>::from([1, 2, 3, 4]);
+    /// q.truncate_back(1);
+    /// assert_eq![q.to_array(), Some([1])];
+    /// ```
+    pub fn truncate_back(&mut self, len: usize) {
+        if len < self.len {
+            self.back = self.idx_front(len);
+            self.len = len;
+        }
+    }
+
+    /// Binary searches the sorted deque with a comparator function,
+    /// taking into account that its elements can wrap around the array.
+    ///
+    /// Returns the index of a matching element, or the index where
+    /// it could be inserted while maintaining the sorted order,
+    /// like [`slice::binary_search_by`].
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 6>::from([3, 5, 8]);
+    /// q.push_front(2)?;
+    /// q.push_front(1)?;
+    /// assert_eq![Ok(3), q.binary_search_by(|e| e.cmp(&5))];
+    /// assert_eq![Err(4), q.binary_search_by(|e| e.cmp(&6))];
+    /// assert_eq![Err(0), q.binary_search_by(|e| e.cmp(&0))];
+    /// # Ok(()) }
+    /// ```
+    pub fn binary_search_by<F>(&self, mut f: F) -> core::result::Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let (a, b) = self.as_slices();
+        match b.first().map(&mut f) {
+            Some(Ordering::Equal) => Ok(a.len()),
+            Some(Ordering::Less) => b
+                .binary_search_by(f)
+                .map(|i| i + a.len())
+                .map_err(|i| i + a.len()),
+            _ => a.binary_search_by(f),
+        }
+    }
+}
+
+// `T:Clone`
+impl<T: Clone, S: Storage, const CAP: usize> Deque<T, S, CAP> {
+    /// Removes and returns the element at `index`, counting from the front.
+    ///
+    /// Moves either the elements before or after `index`,
+    /// whichever are fewer.
+    ///
+    /// # Errors
+    /// Errors if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 5>::from([1, 2, 3, 4, 5]);
+    /// assert_eq![4, q.remove(3)?];
+    /// assert_eq![q.to_array(), Some([1, 2, 3, 5])];
+    /// # Ok(()) }
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Result<T> {
+        if index >= self.len {
+            Err(Error::IndexOutOfBounds(index))
+        } else if index < self.len / 2 {
+            for i in (0..index).rev() {
+                self.swap_unchecked(i, i + 1);
+            }
+            self.pop_front()
+        } else {
+            for i in index..self.len - 1 {
+                self.swap_unchecked(i, i + 1);
+            }
+            self.pop_back()
+        }
+    }
+
+    /// Removes and returns the element at `index`, replacing it with
+    /// the front element.
+    ///
+    /// # Errors
+    /// Errors if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 4>::from([1, 2, 3, 4]);
+    /// assert_eq![3, q.swap_remove_front(2)?];
+    /// assert_eq![q.to_array(), Some([2, 1, 4])];
+    /// # Ok(()) }
+    /// ```
+    pub fn swap_remove_front(&mut self, index: usize) -> Result<T> {
+        if index >= self.len {
+            Err(Error::IndexOutOfBounds(index))
+        } else {
+            self.swap_unchecked(index, 0);
+            self.pop_front()
+        }
+    }
+
+    /// Removes and returns the element at `index`, replacing it with
+    /// the back element.
+    ///
+    /// # Errors
+    /// Errors if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 4>::from([1, 2, 3, 4]);
+    /// assert_eq![2, q.swap_remove_back(1)?];
+    /// assert_eq![q.to_array(), Some([1, 4, 3])];
+    /// # Ok(()) }
+    /// ```
+    pub fn swap_remove_back(&mut self, index: usize) -> Result<T> {
+        if index >= self.len {
+            Err(Error::IndexOutOfBounds(index))
+        } else {
+            self.swap_unchecked(index, self.len - 1);
+            self.pop_back()
+        }
+    }
+}
+
+// `T:Default`
+impl<T: Default, S: Storage, const CAP: usize> Deque<T, S, CAP> {
+    /// Removes the elements in `range` from the deque, returning them
+    /// in an iterator.
+    ///
+    /// The drained elements are moved out one by one as the iterator advances,
+    /// leaving their default value behind. When the iterator is dropped, the
+    /// ones that were not consumed are dropped too, and the gap is closed by
+    /// moving either the elements before or after the range, whichever are
+    /// fewer, one slot each.
+    ///
+    /// # Errors
+    /// Errors if the range is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectDeque;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectDeque::<_, 6>::from([1, 2, 3, 4, 5, 6]);
+    /// assert![q.drain(1..3)?.eq([2, 3])];
+    /// assert_eq![q.to_array(), Some([1, 4, 5, 6])];
+    /// assert![q.drain(2..)?.eq([5, 6])];
+    /// assert_eq![q.to_array(), Some([1, 4])];
+    /// # Ok(()) }
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Result<DequeDrain<'_, T, S, CAP>> {
+        let (start, end) = range_bounds(&range, self.len)?;
+        Ok(DequeDrain {
+            idx: self.idx_front(start),
+            remaining: end - start,
+            start,
+            count: end - start,
+            deque: self,
+        })
+    }
+
+    /// Closes the gap left by `count` taken elements at `start`, moving
+    /// either the elements before or after it, whichever are fewer.
+    pub(super) fn close_gap(&mut self, start: usize, count: usize) {
+        if start <= self.len - start - count {
+            for i in (0..start).rev() {
+                let (from, to) = (self.idx_front(i), self.idx_front(i + count));
+                self.array[to] = core::mem::take(&mut self.array[from]);
+            }
+            self.front = self.idx_front(count);
+        } else {
+            for i in start + count..self.len {
+                let (from, to) = (self.idx_front(i), self.idx_front(i - count));
+                self.array[to] = core::mem::take(&mut self.array[from]);
+            }
+            self.back = self.idx_front(self.len - count);
+        }
+        self.len -= count;
+    }
+}
+
 // `T: PartialEq`
 impl<T: PartialEq, S: Storage, const CAP: usize> Deque<T, S, CAP> {
     /// Returns true if the deque contains `element`.
@@ -1658,4 +2308,159 @@ mod tests {
         // loops over CAP
         assert_eq![2, q.idx_back(5)];
     }
+
+    // returns a full deque of 1..=6 whose front is at array index `offset`
+    fn wrapped(offset: usize) -> Deque<i32, (), 6> {
+        let mut q = Deque::<_, (), 6>::default();
+        for _ in 0..offset {
+            q.push_back(0).unwrap();
+            q.drop_front().unwrap();
+        }
+        for e in 1..=6 {
+            q.push_back(e).unwrap();
+        }
+        assert_eq![offset, q.front];
+        q
+    }
+
+    // test the middle operations for every wraparound layout
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn middle_wrapped() {
+        use alloc::vec::Vec;
+
+        for offset in 0..6 {
+            for i in 0..6 {
+                let mut q = wrapped(offset);
+                let mut v: Vec<_> = (1..=6).collect();
+                assert_eq![Ok(v.remove(i)), q.remove(i)];
+                assert_eq![v, q.to_vec()];
+                q.insert(i, 9).unwrap();
+                v.insert(i, 9);
+                assert_eq![v, q.to_vec()];
+
+                let mut q = wrapped(offset);
+                q.truncate_back(5);
+                q.rotate_to(i.min(4)).unwrap();
+                let mut v: Vec<_> = (1..=5).collect();
+                v.rotate_left(i.min(4));
+                assert_eq![v, q.to_vec()];
+                assert![q.range(..).unwrap().eq(v.iter())];
+
+                for end in i..=6 {
+                    let mut q = wrapped(offset);
+                    let mut v: Vec<_> = (1..=6).collect();
+                    assert![q.drain(i..end).unwrap().eq(v.drain(i..end))];
+                    assert_eq![v, q.to_vec()];
+                    assert_eq![(q.back + 6 - q.front) % 6, q.len % 6];
+                }
+            }
+            let q = wrapped(offset);
+            for (i, e) in (1..=6).enumerate() {
+                assert_eq![Ok(i), q.binary_search_by(|x| x.cmp(&e))];
+            }
+            assert_eq![Err(6), q.binary_search_by(|x| x.cmp(&7))];
+            assert![q.range(2..5).unwrap().eq(&[3, 4, 5])];
+        }
+    }
+
+    // test the iterators for every wraparound layout and length
+    #[test]
+    fn iter_wrapped() {
+        for offset in 0..6 {
+            for len in 0..=6 {
+                let mut q = wrapped(offset);
+                q.truncate_back(len);
+                let v = [1, 2, 3, 4, 5, 6];
+                let v = &v[..len];
+
+                assert![q.iter().eq(v)];
+                assert![q.iter().rev().eq(v.iter().rev())];
+                assert_eq![len, q.iter().len()];
+                let mut i = q.iter();
+                for n in 0..len {
+                    let e = if n % 2 == 0 { i.next() } else { i.next_back() };
+                    assert_eq![len - n - 1, i.len()];
+                    assert![e.is_some()];
+                }
+                assert_eq![None, i.next()];
+                assert_eq![None, i.next_back()];
+
+                q.iter_mut().for_each(|e| *e *= 10);
+                assert![q.iter().copied().eq(v.iter().map(|e| e * 10))];
+
+                assert![q.clone().into_iter_front().eq(q.iter().copied())];
+                let w = q.clone().into_iter_front().rev();
+                assert![w.eq(q.iter().copied().rev())];
+                let w = q.drain(..).unwrap().rev();
+                assert![w.eq(v.iter().rev().map(|e| e * 10))];
+                assert![q.is_empty()];
+
+                // a partially consumed drain drops the rest of its range
+                let mut q = wrapped(offset);
+                q.truncate_back(len);
+                let mut d = q.drain(..len / 2).unwrap();
+                assert_eq![(len / 2 > 0).then_some(1), d.next()];
+                drop(d);
+                assert![q.iter().eq(&v[len / 2..])];
+            }
+        }
+    }
+
+    #[test]
+    fn drain_ranges() {
+        let v = [1, 2, 3, 4, 5, 6];
+        for offset in 0..6 {
+            for start in 0..=6 {
+                for end in start..=6 {
+                    let rest = v[..start].iter().chain(&v[end..]);
+
+                    let mut q = wrapped(offset);
+                    assert![q
+                        .drain(start..end)
+                        .unwrap()
+                        .eq(v[start..end].iter().copied())];
+                    assert![q.iter().eq(rest.clone())];
+                    assert_eq![6 - (end - start), q.len()];
+
+                    // partially consumed from both ends
+                    let mut q = wrapped(offset);
+                    let mut d = q.drain(start..end).unwrap();
+                    if end - start >= 2 {
+                        assert_eq![Some(v[start]), d.next()];
+                        assert_eq![Some(v[end - 1]), d.next_back()];
+                    }
+                    drop(d);
+                    assert![q.iter().eq(rest.clone())];
+
+                    // the deque stays usable
+                    if end - start >= 2 {
+                        q.push_front(0).unwrap();
+                        q.push_back(7).unwrap();
+                        assert_eq![Some(&0), q.peek_front().ok()];
+                        assert_eq![Some(&7), q.peek_back().ok()];
+                        assert_eq![6 - (end - start) + 2, q.len()];
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extend_exact() {
+        for offset in 0..6 {
+            let mut q = wrapped(offset);
+            q.truncate_back(2);
+            assert![q.extend_back([3, 4, 5, 6]).is_ok()];
+            assert![q.iter().eq(&[1, 2, 3, 4, 5, 6])];
+            assert![q.extend_back([7]).is_err()];
+            assert![q.extend_front([]).is_ok()];
+
+            q.truncate_back(2);
+            assert![q.extend_front([0, -1, -2, -3]).is_ok()];
+            assert![q.iter().eq(&[-3, -2, -1, 0, 1, 2])];
+            assert![q.extend_front([-4]).is_err()];
+            assert![q.iter().eq(&[-3, -2, -1, 0, 1, 2])];
+        }
+    }
 }
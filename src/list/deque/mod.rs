@@ -4,6 +4,11 @@
 //! either end.
 //
 
+use core::{
+    iter::{Chain, FusedIterator},
+    slice,
+};
+
 use crate::{list::Array, mem::Storage};
 
 #[cfg(feature = "alloc")]
@@ -39,10 +44,11 @@ pub type BoxedDeque<T, const CAP: usize> = Deque<T, Boxed, CAP>;
 
 /* iterators */
 
-/// A deque iterator.
+/// A deque iterator, from the front to the back.
 pub struct DequeIter<'s, T, S: Storage, const CAP: usize> {
     deque: &'s Deque<T, S, CAP>,
     idx: usize,
+    end: usize,
 }
 
 impl<'s, T, S: Storage, const CAP: usize> Iterator for DequeIter<'s, T, S, CAP> {
@@ -66,21 +72,22 @@ impl<'s, T, S: Storage, const CAP: usize> Iterator for DequeIter<'s, T, S, CAP>
     ///
     /// ```
     fn next(&mut self) -> Option<Self::Item> {
-        let item = if self.idx == self.deque.len() {
+        if self.idx == self.end {
             None
         } else {
-            Some(&self.deque.array[self.deque.idx_front(self.idx)])
-        };
-        self.idx += 1;
-        item
+            self.idx += 1;
+            Some(&self.deque.array[self.deque.idx_front(self.idx - 1)])
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.deque.len(), Some(self.deque.len()))
+        let len = self.end - self.idx;
+        (len, Some(len))
     }
 }
 
 impl<'s, T, S: Storage, const CAP: usize> ExactSizeIterator for DequeIter<'s, T, S, CAP> {}
+impl<'s, T, S: Storage, const CAP: usize> FusedIterator for DequeIter<'s, T, S, CAP> {}
 
 impl<'s, T, S: Storage, const CAP: usize> DoubleEndedIterator for DequeIter<'s, T, S, CAP> {
     /// Iterates over shared references.
@@ -104,14 +111,157 @@ impl<'s, T, S: Storage, const CAP: usize> DoubleEndedIterator for DequeIter<'s,
     /// assert_eq![Some(&4), dqi.next_back()];
     /// assert_eq![Some(&3), dqi.next_back()];
     /// assert_eq![None, dqi.next_back()];
+    ///
+    /// let mut dqi = dq.iter();
+    /// assert_eq![Some(&4), dqi.next_back()];
+    /// assert_eq![Some(&3), dqi.next()];
+    /// assert_eq![None, dqi.next_back()];
     /// ```
     fn next_back(&mut self) -> Option<Self::Item> {
-        let item = if self.idx == self.deque.len() {
+        if self.idx == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(&self.deque.array[self.deque.idx_front(self.end)])
+        }
+    }
+}
+
+/// A deque iterator over exclusive references, from the front to the back.
+///
+/// Created by [`Deque::iter_mut`].
+pub struct DequeIterMut<'s, T> {
+    iter: Chain<slice::IterMut<'s, T>, slice::IterMut<'s, T>>,
+}
+
+impl<'s, T> Iterator for DequeIterMut<'s, T> {
+    type Item = &'s mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<'s, T> DoubleEndedIterator for DequeIterMut<'s, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+impl<'s, T> ExactSizeIterator for DequeIterMut<'s, T> {}
+impl<'s, T> FusedIterator for DequeIterMut<'s, T> {}
+
+/// An iterator over a range of a deque.
+///
+/// Created by [`Deque::range`].
+pub struct DequeRange<'s, T> {
+    iter: Chain<slice::Iter<'s, T>, slice::Iter<'s, T>>,
+}
+
+impl<'s, T> Iterator for DequeRange<'s, T> {
+    type Item = &'s T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<'s, T> DoubleEndedIterator for DequeRange<'s, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+impl<'s, T> ExactSizeIterator for DequeRange<'s, T> {}
+impl<'s, T> FusedIterator for DequeRange<'s, T> {}
+
+/// An iterator over exclusive references to a range of a deque.
+///
+/// Created by [`Deque::range_mut`].
+pub struct DequeRangeMut<'s, T> {
+    iter: Chain<slice::IterMut<'s, T>, slice::IterMut<'s, T>>,
+}
+
+impl<'s, T> Iterator for DequeRangeMut<'s, T> {
+    type Item = &'s mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<'s, T> DoubleEndedIterator for DequeRangeMut<'s, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+impl<'s, T> ExactSizeIterator for DequeRangeMut<'s, T> {}
+impl<'s, T> FusedIterator for DequeRangeMut<'s, T> {}
+
+/// A draining deque iterator.
+///
+/// Created by [`Deque::drain`].
+pub struct DequeDrain<'s, T: Default, S: Storage, const CAP: usize> {
+    deque: &'s mut Deque<T, S, CAP>,
+    // the array index of the next drained element
+    idx: usize,
+    remaining: usize,
+    // the position and length of the drained range
+    start: usize,
+    count: usize,
+}
+
+impl<'s, T: Default, S: Storage, const CAP: usize> Iterator for DequeDrain<'s, T, S, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
             None
         } else {
-            Some(&self.deque.array[self.deque.idx_back(self.idx)])
-        };
-        self.idx += 1;
-        item
+            let e = core::mem::take(&mut self.deque.array[self.idx]);
+            self.idx = (self.idx + 1) % CAP;
+            self.remaining -= 1;
+            Some(e)
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<'s, T: Default, S: Storage, const CAP: usize> DoubleEndedIterator
+    for DequeDrain<'s, T, S, CAP>
+{
+    fn next_back(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            None
+        } else {
+            self.remaining -= 1;
+            Some(core::mem::take(
+                &mut self.deque.array[(self.idx + self.remaining) % CAP],
+            ))
+        }
+    }
+}
+impl<'s, T: Default, S: Storage, const CAP: usize> ExactSizeIterator for DequeDrain<'s, T, S, CAP> {}
+impl<'s, T: Default, S: Storage, const CAP: usize> FusedIterator for DequeDrain<'s, T, S, CAP> {}
+impl<'s, T: Default, S: Storage, const CAP: usize> Drop for DequeDrain<'s, T, S, CAP> {
+    /// Drops the drained elements that were not consumed,
+    /// and closes the gap they leave in the deque.
+    fn drop(&mut self) {
+        self.for_each(drop);
+        self.deque.close_gap(self.start, self.count);
     }
 }
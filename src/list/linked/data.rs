@@ -1,77 +1,108 @@
 // ladata::list::link::adt
 //
-//!
+//! `DataSinglyLinkedList` & `DataDoublyLinkedList` abstract data types.
 //
+// The nodes are addressed by lightweight handles (e.g. an index into an
+// arena of nodes), since the nodes themselves are owned by the list.
+
+use core::fmt;
 
 use crate::{error::LadataResult as Result, misc::DataCollection};
 
-/// An abstract Singly Linked List.
+/// An abstract Singly Linked List, with nodes addressed by handles.
+///
+/// A handle stays valid while its node is in the list. Passing the handle of a
+/// removed node makes the methods returning a `Result` return an error, and
+/// [`linked_next`][Self::linked_next] return `None`, unless its slot has been
+/// reused by a newer node, in which case it refers to that different element.
+///
+/// # Examples
+/// ```
+/// use ladata::list::{DataSinglyLinkedList, DirectSinglyLinkedList8};
+///
+/// // Returns the sum of all the elements, walking the list forward.
+/// fn sum<L: DataSinglyLinkedList<Element = i32>>(list: &L) -> i32 {
+///     let mut sum = 0;
+///     let mut node = list.linked_first();
+///     while let Some(n) = node {
+///         sum += list.linked_data(n).unwrap();
+///         node = list.linked_next(n);
+///     }
+///     sum
+/// }
+///
+/// let l = DirectSinglyLinkedList8::<i32, 4>::from([1, 2, 3]);
+/// assert_eq![6, sum(&l)];
+/// ```
 pub trait DataSinglyLinkedList: DataCollection {
-    type Node: DataSinglyLinkedListNode<Data = Self::Element>;
+    /// The handle of a node.
+    type Node: Copy + PartialEq + fmt::Debug;
+
+    /// Returns the handle of the first node, or `None` if the list is empty.
+    fn linked_first(&self) -> Option<Self::Node>;
+    /// Returns the handle of the node after `node`,
+    /// or `None` if it's the last or it's not in the list.
+    fn linked_next(&self, node: Self::Node) -> Option<Self::Node>;
 
-    ///
-    fn linked_first(&mut self) -> Result<Self::Node>;
+    /// Returns a shared reference to the element of the `node`.
+    fn linked_data(&self, node: Self::Node) -> Result<&Self::Element>;
+    /// Returns an exclusive reference to the element of the `node`.
+    fn linked_data_mut(&mut self, node: Self::Node) -> Result<&mut Self::Element>;
 
-    ///
+    /// Inserts an `element` as the first node, and returns its handle.
+    fn linked_push_first(&mut self, element: Self::Element) -> Result<Self::Node>;
+    /// Inserts an `element` after the `node`, and returns its handle.
     fn linked_insert_after(
         &mut self,
-        node: &mut Self::Node,
+        node: Self::Node,
         element: Self::Element,
     ) -> Result<Self::Node>;
-    ///
-    fn linked_remove_after(&mut self, node: &mut Self::Node) -> Result<Option<Self::Node>>;
-}
-
-/// An abstract Singly Linked List Node.
-pub trait DataSinglyLinkedListNode: Sized {
-    type Data;
-    ///
-    fn linked_data(&self) -> &Self::Data;
-    ///
-    fn linked_data_mut(&mut self) -> &mut Self::Data;
 
-    /// Returns a shared reference to the next node.
-    fn linked_next(&self) -> Option<&Self>;
-    /// Returns an exclusive reference to the next node.
-    fn linked_next_mut(&mut self) -> Option<&mut Self>;
+    /// Removes the first node and returns its element.
+    fn linked_remove_first(&mut self) -> Result<Self::Element>;
+    /// Removes the node after the `node` and returns its element.
+    fn linked_remove_after(&mut self, node: Self::Node) -> Result<Self::Element>;
 }
 
 /* doubly */
 
-/// An abstract Doubly Linked List.
+/// An abstract Doubly Linked List, with nodes addressed by handles.
+///
+/// A handle stays valid while its node is in the list. Passing the handle of a
+/// removed node makes the methods returning a `Result` return an error, and
+/// [`linked_next`][DataSinglyLinkedList::linked_next] and
+/// [`linked_prev`][Self::linked_prev] return `None`, unless its slot has been
+/// reused by a newer node, in which case it refers to that different element.
 pub trait DataDoublyLinkedList: DataSinglyLinkedList {
-    ///
-    fn linked_last(&mut self) -> Result<Option<Self::Node>>;
+    /// Returns the handle of the last node, or `None` if the list is empty.
+    fn linked_last(&self) -> Option<Self::Node>;
+    /// Returns the handle of the node before `node`,
+    /// or `None` if it's the first or it's not in the list.
+    fn linked_prev(&self, node: Self::Node) -> Option<Self::Node>;
 
-    ///
+    /// Inserts an `element` as the last node, and returns its handle.
+    fn linked_push_last(&mut self, element: Self::Element) -> Result<Self::Node>;
+    /// Inserts an `element` before the `node`, and returns its handle.
     fn linked_insert_before(
         &mut self,
-        node: &mut Self::Node,
+        node: Self::Node,
         element: Self::Element,
     ) -> Result<Self::Node>;
-    ///
-    fn linked_remove_before(&mut self, node: &mut Self::Node) -> Result<Option<Self::Node>>;
-}
 
-/// An abstract Doubly Linked List Node.
-pub trait DataDoublyLinkedListNode: DataSinglyLinkedListNode {
-    /// Returns a shared reference to the previous node.
-    fn linked_prev(&self) -> Option<&Self>;
-    /// Returns an exclusive reference to the previous node.
-    fn linked_prev_mut(&mut self) -> Option<&mut Self>;
+    /// Removes the last node and returns its element.
+    fn linked_remove_last(&mut self) -> Result<Self::Element>;
+    /// Removes the node before the `node` and returns its element.
+    fn linked_remove_before(&mut self, node: Self::Node) -> Result<Self::Element>;
+    /// Removes the `node` and returns its element.
+    fn linked_remove(&mut self, node: Self::Node) -> Result<Self::Element>;
 }
 
 /* thirdly */
 
 // /// An abstract Thirdly Linked List.
 // pub trait DataThirdlyLinkedList: DataDoublyLinkedList {
-//     fn linked_parent(&self) -> Result<Self::Node>;
-// }
-//
-// /// An abstract Thirdly Linked List Node.
-// pub trait DataThirdlyLinkedListNode: DataDoublyLinkedListNode {
-//     /// Returns a shared reference to the parent node.
-//     fn linked_parent(&self) -> Option<&Self>;
-//     /// Returns an excusive reference to the parent node.
-//     fn linked_set_parent(&mut self, parent: Option<Self>);
+//     /// Returns the handle of the parent of `node`.
+//     fn linked_parent(&self, node: Self::Node) -> Option<Self::Node>;
+//     /// Sets the parent of `node`.
+//     fn linked_set_parent(&mut self, node: Self::Node, parent: Option<Self::Node>);
 // }
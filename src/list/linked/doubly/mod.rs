@@ -0,0 +1,1264 @@
+// ladata::list::link::doubly
+//
+//! A macro builder for linked lists backed by a const-sized array.
+//
+// ```diagram
+//
+// Concrete list data representation:
+// count:3  front:2  back:0  free:_  nodes:↓
+//    i0       i1       i2       i3
+// [__3rd_] [__2nd_] [__1st_] [______]
+//    p1 n_    p2 n0    p_ n1    p_ n_
+//
+// Abstract list representation:
+// [i2] ⇄ [i1] ⇄ [i0]
+// front         back
+//
+// each node has a successor (next), pointing towards the back,
+// and a predecessor (prev), pointing towards the front.
+// ```
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    mem::size_of,
+};
+
+use crate::{
+    error::{LadataError as Error, LadataResult as Result},
+    list::{Array, DataDoublyLinkedList, DataSinglyLinkedList},
+    mem::Storage,
+    misc::*,
+};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::mem::Boxed,
+    alloc::{format, string::String, vec, vec::Vec},
+};
+
+mod cursor;
+mod node;
+mod ops;
+
+/// Generates a doubly linked list backed by an array, with custom index size.
+#[rustfmt::skip]
+macro_rules! linked_list_array {
+    // $name : name prefix. E.g.: DoublyLinked
+    // $B : byte size
+    // $b : bit size
+    // $t : inner index type
+    // $nmt: devela::NonSpecific inner index type
+    ( $name:ident, $B:literal, $b:literal, $t:ty, $nmt:ty) => { devela::paste! {
+        node::linked_list_array_node![$name, $b];
+        cursor::linked_list_array_cursor![$name, $b];
+        ops::linked_list_array_ops![$name, $b];
+
+        #[doc = "A doubly linked list, backed by an [`Array`], using " $b "-bit indices."]
+        ///
+        #[doc = "It has a maximum length of [`" $t "::MAX`]` -1` elements."]
+        ///
+        /// The list remembers the indices of the front and back elements,
+        /// the index of the first free slot and the number of elements.
+        ///
+        /// Each node remembers the indices of the previous and next elements.
+        ///
+        /// Since each list owns its own array of nodes, splitting and splicing
+        /// lists with a cursor can't relink the nodes from one array into the other.
+        /// The elements are moved instead, one at a time, which requires `T: Default`
+        /// to take them out of their slots without `unsafe` code.
+        pub struct [<$name$b>]<T, S: Storage, const CAP: usize> {
+            /// The index of the current element at the front.
+            front: [<NonMaxIndex$b>],
+            /// The index of the current element at the back.
+            back: [<NonMaxIndex$b>],
+            /// The index of the first free element.
+            free: [<NonMaxIndex$b>],
+            /// The current counted number of nodes.
+            count: [<Counter$b>],
+            /// The array of nodes, stored in the generic container.
+            nodes: Array<[<$name$b Node>]<T>, S, CAP>,
+        }
+
+        /// impl Clone, Copy, Debug, Default…
+        mod [<impls_$b>] {
+            use super::*;
+
+            // T:Clone
+            impl<T: Clone, S: Storage, const CAP: usize> Clone for [<$name$b>]<T, S, CAP>
+                where S::Stored<[[<$name$b Node>]<T>; CAP]>: Clone {
+                fn clone(&self) -> Self {
+                    Self {
+                        front: self.front.clone(),
+                        back: self.back.clone(),
+                        free: self.free.clone(),
+                        count: self.count.clone(),
+                        nodes: self.nodes.clone(),
+                    }
+                }
+            }
+
+            /// `T:Copy`
+            impl<T: Copy, S: Storage, const CAP: usize> Copy for [<$name$b>]<T, S, CAP>
+                where S::Stored<[[<$name$b Node>]<T>; CAP]>: Copy {}
+
+            /// `T:Debug`
+            impl<T: Debug, S: Storage, const CAP: usize> Debug for [<$name$b>]<T, S, CAP>
+                where S::Stored<[[<$name$b Node>]<T>; CAP]>: Debug {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write![f, "{} {{ cap:{} len:{} front:{} back:{} free:{} sizeof:{} }}",
+                        stringify!([<$name$b>]),
+                        CAP, self.len(), self.front, self.back, self.free, size_of::<Self>()]?;
+
+                    if self.len() > 0 {
+
+                        // IMPROVE: save ordered list of indexes, from iteration.
+                        // IMPROVE: limit the number of depicted elements.
+
+                        /* show the list diagram */
+                        write![f, "\n## array of nodes:"]?;
+
+                        //    i0       i1       i2       i3
+                        write![f, "\n"]?;
+                        for (idx, _) in self.nodes.iter().enumerate() {
+                            write![f, "   i{}    ", idx]?;
+                        }
+                        // [__3rd_] [__2nd_] [__1st_] [______]
+                        write![f, "\n"]?;
+
+                        #[cfg(feature = "alloc")]
+                        {
+                            use unicode_segmentation::UnicodeSegmentation;
+
+                            for node in self.nodes.iter() {
+                                // if node.is_in_the_list() { // IMPROVE
+                                    let graphemes: String = format!["{:?}",
+                                        node.data].graphemes(true).take(6).collect();
+                                    write![f, "[{:_^6}] ", graphemes]?;
+                                // } else {
+                                //     write![f, "[______] "]?;
+                                // }
+                            }
+                            //    p1 n_    p2 n0    p_ n1    p_ n_
+                            write![f, "\n"]?;
+                        }
+
+                        for node in self.nodes.iter() {
+                            write![f, "   p{} n{} ", node.prev, node.next]?;
+                        }
+
+                        /* show the list of nodes */
+                        write![f, "\n## node list from the front:"]?;
+
+                        let mut current_idx = self.front;
+                        while current_idx.is_some() {
+                            let node = &self.nodes[current_idx.as_usize()];
+                            write![f, "\ni{current_idx}: {node:?}"]?;
+                            current_idx = node.next();
+                        }
+                    }
+                    Ok(())
+                }
+            }
+
+            /// `S=(); T:Default`
+            impl<T: Default, const CAP: usize> Default for [<$name$b>]<T, (), CAP>
+                where [<$name$b Node>]<T>: Default
+            {
+                /// Returns an empty, non-circular, doubly linked list,
+                /// allocated in the stack, and filled with unlinked `CAP`
+                /// elements set to their default value.
+                ///
+                /// # Panics
+                #[doc = "Panics if `CAP` is > [`" $t "::MAX`]."]
+                ///
+                /// # Examples
+                /// ```
+                /// use ladata::list::DoublyLinkedList8;
+                /// let l = DoublyLinkedList8::<u8, (), 100>::default();
+                /// ```
+                fn default() -> Self {
+                    assert![CAP < $t::MAX as usize];
+                    Self {
+                        front: None.into(),
+                        back: None.into(),
+                        free: None.into(),
+                        count: [<Counter$b>]::default(),
+                        nodes: Array::default(),
+                    }
+                }
+            }
+
+            /// `S=Boxed; T:Default`
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+            impl<T: Default, const CAP: usize> Default for [<$name$b>]<T, Boxed, CAP>
+                where [<$name$b Node>]<T>: Default
+            {
+                /// Returns an empty, non-circular, doubly linked list,
+                /// allocated in the stack, and filled with unlinked `CAP`
+                /// elements set to their default value.
+                ///
+                /// # Examples
+                /// ```
+                /// use ladata::all::{Boxed, DoublyLinkedList8};
+                /// let l = DoublyLinkedList8::<u8, Boxed, 10>::default();
+                /// ```
+                ///
+                /// # Panics
+                #[doc = "Panics if `CAP` is > [`" $t "::MAX`]."]
+                fn default() -> Self {
+                    assert![CAP < $t::MAX as usize];
+                    Self {
+                        front: None.into(),
+                        back: None.into(),
+                        free: None.into(),
+                        count: [<Counter$b>]::default(),
+                        nodes: Array::default(),
+                    }
+                }
+            }
+        }
+
+        /// `S=(); T:Clone`
+        impl<T: Clone, const CAP: usize> [<$name$b>]<T, (), CAP> {
+            /// Returns a doubly linked list, allocated in the stack,
+            /// filled with `CAP` unlinked elements set to `value`.
+            ///
+            /// # Errors
+            #[doc = "If `CAP` is >= [`" $t "::MAX`]."]
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let l = DirectDoublyLinkedList8::<u8, 100>::new(0)?;
+            /// # Ok(()) }
+            /// ```
+            pub fn new(value: T) -> Result<Self> {
+                if CAP < $t::MAX as usize {
+                    Ok(Self {
+                        front: None.into(),
+                        back: None.into(),
+                        free: None.into(),
+                        count: [<Counter$b>]::new(),
+                        nodes: Array::<[<$name$b Node>]<T>, (), CAP>::
+                            with([<$name$b Node>]::new_unlinked(value)),
+                    })
+                } else {
+                    Err(Error::IndexOutOfBounds(CAP))
+                }
+            }
+        }
+
+        /// `S:Boxed + T:Clone`
+        #[cfg(feature = "alloc")]
+        #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+        impl<T: Clone, const CAP: usize> [<$name$b>]<T, Boxed, CAP> {
+            /// Returns a doubly linked list, allocated in the heap,
+            /// filled with `CAP` unlinked elements set to `value`.
+            ///
+            /// # Errors
+            #[doc = "If `CAP` is >= [`" $t "::MAX`]."]
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::{BoxedDoublyLinkedList8};
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let l = BoxedDoublyLinkedList8::<u8, 100>::new(0)?;
+            /// # Ok(()) }
+            /// ```
+            pub fn new(value: T) -> Result<Self> {
+                if CAP < $t::MAX as usize {
+                    Ok(Self {
+                        front: None.into(),
+                        back: None.into(),
+                        free: None.into(),
+                        count: [<Counter$b>]::new(),
+                        nodes: Array::<[<$name$b Node>]<T>, Boxed, CAP>::
+                            with([<$name$b Node>]::new_unlinked(value)),
+                    })
+                } else {
+                    Err(Error::IndexOutOfBounds(CAP))
+                }
+            }
+        }
+
+        /// `*`
+        impl<T, S: Storage, const CAP: usize> [<$name$b>]<T, S, CAP> {
+            /// Returns the number of elements.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 3>::default();
+            /// s.push_front(1)?;
+            /// assert_eq![1, s.len()];
+            /// # Ok(()) }
+            /// ```
+            pub const fn len(&self) -> usize {
+                self.count.as_usize()
+            }
+
+            /// Checks if the list is empty.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            ///
+            /// let s = DirectDoublyLinkedList8::<i32, 5>::default();
+            /// assert![s.is_empty()];
+            /// ```
+            pub const fn is_empty(&self) -> bool {
+                // eprintln!("IS_EMPTY? len:{}, f:{} b:{}", self.len(), self.front, self.back);
+                self.len() == 0
+            }
+
+            /// Checks if the list is full.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 2>::default();
+            /// s.push_front(1)?;
+            /// s.push_front(2)?;
+            /// assert![s.is_full()];
+            /// # Ok(()) }
+            /// ```
+            pub const fn is_full(&self) -> bool {
+                self.len() == CAP
+            }
+
+            /// Returns the maximum number of elements.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            ///
+            /// let s = DirectDoublyLinkedList8::<i32, 3>::default();
+            /// assert_eq![3, s.capacity()];
+            /// ```
+            pub const fn capacity(&self) -> usize {
+                CAP
+            }
+
+            /// Returns the available free elements.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 3>::default();
+            /// s.push_front(1)?;
+            /// assert_eq![2, s.remaining_capacity()];
+            /// # Ok(()) }
+            /// ```
+            pub const fn remaining_capacity(&self) -> usize {
+                CAP - self.len()
+            }
+
+            /// Clears the list, unlinking all values.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 2>::default();
+            /// s.push_front(1)?;
+            /// s.push_front(2)?;
+            /// s.clear();
+            /// assert![s.is_empty()];
+            /// # Ok(()) }
+            /// ```
+            pub fn clear(&mut self) {
+                self.count = [<Counter$b>]::new();
+                self.front = None.into();
+                self.back = None.into();
+                self.free = None.into();
+                self.unlink_all_nodes();
+            }
+
+            /* front & back */
+
+            // MAYBE
+            // /// Returns the index of the front element.
+            // ///
+            // /// # Errors
+            // /// If the list is empty.
+            // ///
+            // /// # Examples
+            // /// ```
+            // /// use ladata::list::DirectDoublyLinkedList8;
+            // /// # fn main() -> ladata::error::LadataResult<()> {
+            // ///
+            // /// let mut s = DirectDoublyLinkedList8::<i32, 3>::default();
+            // /// s.push_front(1)?;
+            // /// assert_eq![0, s.front_index()?];
+            // /// # Ok(()) }
+            // /// ```
+            // pub const fn front_index(&self) -> Result<$t> {
+            //     if let Some(i) = self.front.get() {
+            //         Ok(i)
+            //     } else {
+            //         Err(Error::NotEnoughElements(1))
+            //     }
+            // }
+
+            /// Returns a shared reference to the front element.
+            ///
+            /// # Errors
+            /// If the list is empty.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 3>::default();
+            /// s.push_front(1)?;
+            /// assert_eq![&1, s.front()?];
+            /// # Ok(()) }
+            /// ```
+            pub fn front(&self) -> Result<&T> {
+                if self.front.is_some() {
+                    Ok(&self.nodes[self.front.as_usize()].data)
+                } else {
+                    Err(Error::NotEnoughElements(1))
+                }
+            }
+
+            /// Returns an exclusive reference to the front element.
+            ///
+            /// # Errors
+            /// If the list is empty.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 3>::default();
+            /// s.push_front(1)?;
+            /// assert_eq![&mut 1, s.front_mut()?];
+            /// # Ok(()) }
+            /// ```
+            pub fn front_mut(&mut self) -> Result<&mut T> {
+                if self.front.is_some() {
+                    Ok(&mut self.nodes[self.front.as_usize()].data)
+                } else {
+                    Err(Error::NotEnoughElements(1))
+                }
+            }
+
+
+            /// Returns a shared reference to the back element.
+            ///
+            /// # Errors
+            /// If the list is empty.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 3>::default();
+            /// s.push_front(1)?;
+            /// assert_eq![&1, s.back()?];
+            /// # Ok(()) }
+            /// ```
+            pub fn back(&self) -> Result<&T> {
+                if self.back.is_some() {
+                    Ok(&self.nodes[self.back.as_usize()].data)
+                } else {
+                    Err(Error::NotEnoughElements(1))
+                }
+            }
+
+            /// Returns an exclusive reference to the back element.
+            ///
+            /// # Errors
+            /// If the list is empty.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 3>::default();
+            /// s.push_front(1)?;
+            /// assert_eq![&mut 1, s.back_mut()?];
+            /// # Ok(()) }
+            /// ```
+            pub fn back_mut(&mut self) -> Result<&mut T> {
+                if self.back.is_some() {
+                    Ok(&mut self.nodes[self.back.as_usize()].data)
+                } else {
+                    Err(Error::NotEnoughElements(1))
+                }
+            }
+
+            /// Pushes an element to the front of the list,
+            /// and returns its index.
+            ///
+            /// # Errors
+            /// If the list is full.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 3>::default();
+            /// s.push_front(1)?;
+            /// assert_eq![1, s.len()];
+            /// # Ok(()) }
+            /// ```
+            //
+            // # Diagram
+            // ```_
+            // count:0  front:_  back:_  free:_
+            //    i0       i1       i2       i3
+            // [______] [______] [______] [______]
+            //    p_ n_    p_ n_    p_ n_    p_ n_
+            //
+            // count:1  front:0  back:0  free:_                     push_front()
+            //    i0       i1       i2       i3
+            // [__1st_] [______] [______] [______]
+            //    p_ n_    p_ n_    p_ n_    p_ n_
+            //
+            // count:2  front:1  back:0  free:_                     push_front()
+            //    i0       i1       i2       i3
+            // [__2nd_] [__1st_] [______] [______]
+            //    p1 n_    p_ n0    p_ n_    p_ n_
+            // ```
+            pub fn push_front(&mut self, value: T) -> Result<[<NonMaxIndex$b>]> {
+                if self.is_full() {
+                    Err(Error::NotEnoughSpace(Some(1)))
+                } else {
+                    self.link_before(self.front, value)
+                }
+            }
+
+            /// Pushes an element to the back of the list,
+            /// and returns its index.
+            ///
+            /// # Errors
+            /// If the list is full.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 3>::default();
+            /// s.push_back(1)?;
+            /// assert_eq![1, s.len()];
+            /// # Ok(()) }
+            /// ```
+            //
+            // # Diagram
+            // ```_
+            // count:0  front:_  back:_  free:_
+            //    i0       i1       i2       i3
+            // [______] [______] [______] [______]
+            //    p_ n_    p_ n_    p_ n_    p_ n_
+            //
+            // count:1  front:0  back:0  free:_                      push_back()
+            //    i0       i1       i2       i3
+            // [__1st_] [______] [______] [______]
+            //    p_ n_    p_ n_    p_ n_    p_ n_
+            //
+            // count:2  front:0  back:1  free:_                      push_back()
+            //    i0       i1       i2       i3
+            // [__1st_] [__2nd_] [______] [______]
+            //    p_ n1    p0 n_    p_ n_    p_ n_
+            // ```
+            pub fn push_back(&mut self, value: T) -> Result<[<NonMaxIndex$b>]> {
+                if self.is_full() {
+                    Err(Error::NotEnoughSpace(Some(1)))
+                } else {
+                    self.link_after(self.back, value)
+                }
+            }
+
+            /// Provides a double-ended iterator, starting at the front.
+            ///
+            /// # Example
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let l = DirectDoublyLinkedList8::<i32, 4>::from([1, 2, 3]);
+            ///
+            /// let mut li = l.iter_front();
+            /// assert_eq![Some(&1), li.next()];
+            /// assert_eq![Some(&3), li.next_back()];
+            /// assert_eq![Some(&2), li.next()];
+            /// assert_eq![None, li.next()];
+            /// assert_eq![None, li.next_back()];
+            /// # Ok(()) }
+            /// ```
+            pub fn iter_front(&self) -> [<$name$b Iter>]<'_, T, S, CAP> {
+                [<$name$b Iter>] {
+                    list: self,
+                    front: self.front,
+                    back: self.back,
+                    remaining: self.len(),
+                }
+            }
+
+            /// Provides an owning double-ended iterator, starting at the front.
+            ///
+            /// # Example
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let l = DirectDoublyLinkedList8::<i32, 4>::from([1, 2]);
+            ///
+            /// let mut li = l.into_iter_front();
+            /// assert_eq![Some(1), li.next()];
+            /// assert_eq![Some(2), li.next()];
+            /// assert_eq![None, li.next()];
+            /// # Ok(()) }
+            /// ```
+            pub fn into_iter_front(self) -> [<$name$b IntoIter>]<T, S, CAP> {
+                [<$name$b IntoIter>] {
+                    list: self,
+                }
+            }
+
+            /// Extends the front of the list from an iterator.
+            ///
+            /// # Errors
+            /// Errors if the list becomes full before the iterator finishes.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            ///
+            /// let mut l = DirectDoublyLinkedList8::<i32, 5>::default();
+            /// assert![l.extend_front([1, 2, 3]).is_ok()];
+            /// assert_eq![l.iter_front().collect::<Vec<&i32>>(), &[&3, &2, &1]];
+            ///
+            /// assert![l.extend_front([4, 5, 6, 7, 8]).is_err()];
+            /// assert_eq![l.into_iter_front().collect::<Vec<i32>>(), &[5, 4, 3, 2, 1]];
+            /// ```
+            pub fn extend_front<I>(&mut self, iterator: I) -> Result<()>
+            where
+                I: IntoIterator<Item = T>,
+            {
+                let mut iter = iterator.into_iter();
+                while !self.is_full() {
+                    if let Some(e) = iter.next() {
+                        let _ = self.push_front(e);
+                    } else {
+                        return Ok(());
+                    }
+                }
+                Err(Error::NotEnoughSpace(None))
+            }
+
+            /// Extends the back of the list from an iterator.
+            ///
+            /// # Errors
+            /// Errors if the list becomes full before the iterator finishes.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            ///
+            /// let mut l = DirectDoublyLinkedList8::<i32, 5>::default();
+            /// assert![l.extend_back([1, 2, 3]).is_ok()];
+            /// assert_eq![l.iter_front().collect::<Vec<&i32>>(), &[&1, &2, &3]];
+            ///
+            /// assert![l.extend_back([4, 5, 6, 7, 8]).is_err()];
+            /// assert_eq![l.into_iter_front().collect::<Vec<i32>>(), &[1, 2, 3, 4, 5]];
+            /// ```
+            pub fn extend_back<I>(&mut self, iterator: I) -> Result<()>
+            where
+                I: IntoIterator<Item = T>,
+            {
+                let mut iter = iterator.into_iter();
+                while !self.is_full() {
+                    if let Some(e) = iter.next() {
+                        let _ = self.push_back(e);
+                    } else {
+                        return Ok(());
+                    }
+                }
+                Err(Error::NotEnoughSpace(None))
+            }
+        }
+
+        /// `T:Clone`
+        impl<T: Clone, S: Storage, const CAP: usize> [<$name$b>]<T, S, CAP> {
+            /// Removes the element at the front of the array and returns it.
+            ///
+            /// # Errors
+            /// If the list is empty.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 3>::from([1, 2]);
+            /// assert_eq![Ok(1), s.pop_front()];
+            /// assert_eq![Ok(2), s.pop_front()];
+            /// assert![s.pop_front().is_err()];
+            /// # Ok(()) }
+            /// ```
+            //
+            // # Diagram
+            // ```_
+            // count:2  front:1  back:0  free:_
+            //    i0       i1       i2       i3
+            // [__2nd_] [__1st_] [______] [______]
+            //    p1 n_    p_ n0    p_ n_    p_ n_
+            //
+            // count:1  front:0  back:0  free:1                      pop_front()
+            //    i0       i1       i2       i3
+            // [__1st_] [______] [______] [______]
+            //    p_ n_    p_ n_    p_ n_    p_ n_
+            //
+            // count:0  front:_  back:_  free:0                      pop_front()
+            //    i0       i1       i2       i3
+            // [______] [______] [______] [______]
+            //    p_ n1    p_ n_    p_ n_    p_ n_
+            // ```
+            // #[cfg(not(feature = "unsafe"))] // IMPROVE: unsafe version not depending on Clone
+            pub fn pop_front(&mut self) -> Result<T> {
+                if self.is_empty() {
+                    Err(Error::NotEnoughElements(1))
+                } else {
+                    // Unlink the front node, and free its slot.
+                    let front_index = self.front;
+                    self.unlink(front_index);
+                    let front_data = self.nodes[front_index.as_usize()].data.clone();
+                    self.free_node(front_index)?;
+
+                    Ok(front_data)
+                }
+            }
+
+            /// Removes the element at the back of the array and returns it.
+            ///
+            /// # Errors
+            /// If the list is empty.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut s = DirectDoublyLinkedList8::<i32, 3>::from([1, 2]);
+            /// assert_eq![Ok(2), s.pop_back()];
+            /// assert_eq![Ok(1), s.pop_back()];
+            /// assert![s.pop_back().is_err()];
+            /// # Ok(()) }
+            /// ```
+            pub fn pop_back(&mut self) -> Result<T> {
+                if self.is_empty() {
+                    Err(Error::NotEnoughElements(1))
+                } else {
+                    // Unlink the back node, and free its slot.
+                    let back_index = self.back;
+                    self.unlink(back_index);
+                    let back_data = self.nodes[back_index.as_usize()].data.clone();
+                    self.free_node(back_index)?;
+
+                    Ok(back_data)
+                }
+            }
+
+            /// Returns the list elements as a vector.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectDoublyLinkedList8;
+            /// # fn main() -> ladata::all::LadataResult<()> {
+            ///
+            /// let mut l = DirectDoublyLinkedList8::<_, 5>::from([3, 4]);
+            /// l.push_front(2)?;
+            /// l.push_back(5)?;
+            /// l.push_front(1)?;
+            /// assert_eq![l.to_vec(), vec![1, 2, 3, 4, 5]];
+            /// # Ok(()) }
+            /// ```
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+            pub fn to_vec(&self) -> Vec<T> {
+                if self.is_empty() {
+                    vec![]
+                } else {
+                    self.iter_front().cloned().collect::<Vec<T>>()
+                }
+            }
+
+            /// Resets the list, unlinking all elements and setting them to `value`.
+            pub fn reset(&mut self, value: T) {
+                self.front = None.into();
+                self.back = None.into();
+                self.free = None.into();
+                self.count = [<Counter$b>]::new();
+                self.reset_all_nodes(value);
+            }
+        }
+
+        // Iter ----------------------------------------------------------------
+
+        #[doc ="A `" [<$name$b>] "` iterator."]
+        pub struct [<$name$b Iter>]<'s, T, S: Storage, const CAP: usize> {
+            list: &'s [<$name$b>]<T, S, CAP>,
+            /// The next node index from the front.
+            front: [<NonMaxIndex$b>],
+            /// The next node index from the back.
+            back: [<NonMaxIndex$b>],
+            /// The number of elements not yet iterated.
+            remaining: usize,
+        }
+
+        impl<'a, T, S: Storage, const CAP: usize> Iterator for [<$name$b Iter>]<'a, T, S, CAP> {
+            type Item = &'a T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.remaining == 0 {
+                    None
+                } else {
+                    let node = &self.list.nodes[self.front.as_usize()];
+                    self.front = node.next();
+                    self.remaining -= 1;
+                    Some(&node.data)
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.remaining, Some(self.remaining))
+            }
+        }
+
+        impl<'a, T, S: Storage, const CAP: usize>
+            DoubleEndedIterator for [<$name$b Iter>]<'a, T, S, CAP> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.remaining == 0 {
+                    None
+                } else {
+                    let node = &self.list.nodes[self.back.as_usize()];
+                    self.back = node.prev();
+                    self.remaining -= 1;
+                    Some(&node.data)
+                }
+            }
+        }
+
+        impl<'a, T, S: Storage, const CAP: usize>
+            ExactSizeIterator for [<$name$b Iter>]<'a, T, S, CAP> {}
+
+        impl<'a, T, S: Storage, const CAP: usize>
+            FusedIterator for [<$name$b Iter>]<'a, T, S, CAP> {}
+
+        // IntoIter ------------------------------------------------------------
+
+        #[doc ="A `" [<$name$b>] "` owning iterator."]
+        pub struct [<$name$b IntoIter>]<T, S: Storage, const CAP: usize> {
+            list: [<$name$b>]<T, S, CAP>,
+        }
+
+        impl<T: Clone, S: Storage, const CAP: usize> Iterator for [<$name$b IntoIter>]<T, S, CAP> {
+            type Item = T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.list.is_empty() {
+                    None
+                } else {
+                    self.list.pop_front().ok()
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.list.len(), Some(self.list.len()))
+            }
+        }
+
+        impl<T: Clone, S: Storage, const CAP: usize>
+            DoubleEndedIterator for [<$name$b IntoIter>]<T, S, CAP> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.list.is_empty() {
+                    None
+                } else {
+                    self.list.pop_back().ok()
+                }
+            }
+        }
+
+        impl<T: Clone, S: Storage, const CAP: usize>
+            ExactSizeIterator for [<$name$b IntoIter>]<T, S, CAP> {}
+
+        impl<T: Clone, S: Storage, const CAP: usize>
+            FusedIterator for [<$name$b IntoIter>]<T, S, CAP> {}
+
+        // From ----------------------------------------------------------------
+
+        impl<T: Default, I, const CAP: usize> From<I> for [<$name$b>]<T, (), CAP>
+        where
+            I: IntoIterator<Item = T>,
+        {
+            /// Returns a list filled with an iterator, in the stack.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::all::DirectDoublyLinkedList8;
+            ///
+            /// let s: DirectDoublyLinkedList8<_, 3> = [1, 2, 3].into();
+            /// ```
+            fn from(iterator: I) -> [<$name$b>]<T, (), CAP> {
+                let mut s = [<$name$b>]::<T, (), CAP>::default();
+                let _ = s.extend_back(iterator);
+                s
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<T: Default, I, const CAP: usize> From<I> for [<$name$b>]<T, Boxed, CAP>
+        where
+            I: IntoIterator<Item = T>,
+        {
+            /// Returns a queue filled with an iterator, in the heap.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::all::BoxedDoublyLinkedList8;
+            ///
+            /// let s: BoxedDoublyLinkedList8<_, 3> = [1, 2, 3].into();
+            /// ```
+            fn from(iterator: I) -> [<$name$b>]<T, Boxed, CAP> {
+                let mut s = [<$name$b>]::<T, Boxed, CAP>::default();
+                let _ = s.extend_back(iterator);
+                s
+            }
+        }
+
+        // Data ----------------------------------------------------------------
+
+        impl<T, S: Storage, const CAP: usize> DataCollection for [<$name$b>]<T, S, CAP> {
+            type Element = T;
+
+            fn collection_is_empty(&self) -> Option<bool> {
+                Some(self.is_empty())
+            }
+            fn collection_is_full(&self) -> Option<bool> {
+                Some(self.is_full())
+            }
+            fn collection_capacity(&self) -> usize {
+                CAP
+            }
+            fn collection_len(&self) -> usize {
+                self.len()
+            }
+        }
+
+        /// `T:Clone`
+        impl<T: Clone, S: Storage, const CAP: usize> DataSinglyLinkedList
+            for [<$name$b>]<T, S, CAP> {
+            type Node = [<NonMaxIndex$b>];
+
+            fn linked_first(&self) -> Option<Self::Node> {
+                if self.front.is_some() { Some(self.front) } else { None }
+            }
+            fn linked_next(&self, node: Self::Node) -> Option<Self::Node> {
+                let next = self.nodes[self.node_index(node).ok()?].next();
+                if next.is_some() { Some(next) } else { None }
+            }
+
+            fn linked_data(&self, node: Self::Node) -> Result<&T> {
+                Ok(&self.nodes[self.node_index(node)?].data)
+            }
+            fn linked_data_mut(&mut self, node: Self::Node) -> Result<&mut T> {
+                let index = self.node_index(node)?;
+                Ok(&mut self.nodes[index].data)
+            }
+
+            fn linked_push_first(&mut self, element: T) -> Result<Self::Node> {
+                self.push_front(element)
+            }
+            fn linked_insert_after(&mut self, node: Self::Node, element: T)
+                -> Result<Self::Node> {
+                self.node_index(node)?;
+                if self.is_full() {
+                    Err(Error::NotEnoughSpace(Some(1)))
+                } else {
+                    self.link_after(node, element)
+                }
+            }
+
+            fn linked_remove_first(&mut self) -> Result<T> {
+                self.pop_front()
+            }
+            fn linked_remove_after(&mut self, node: Self::Node) -> Result<T> {
+                let next = self.nodes[self.node_index(node)?].next();
+                if next.is_none() {
+                    Err(Error::NotEnoughElements(1))
+                } else {
+                    self.linked_remove(next)
+                }
+            }
+        }
+
+        /// `T:Clone`
+        impl<T: Clone, S: Storage, const CAP: usize> DataDoublyLinkedList
+            for [<$name$b>]<T, S, CAP> {
+            fn linked_last(&self) -> Option<Self::Node> {
+                if self.back.is_some() { Some(self.back) } else { None }
+            }
+            fn linked_prev(&self, node: Self::Node) -> Option<Self::Node> {
+                let prev = self.nodes[self.node_index(node).ok()?].prev();
+                if prev.is_some() { Some(prev) } else { None }
+            }
+
+            fn linked_push_last(&mut self, element: T) -> Result<Self::Node> {
+                self.push_back(element)
+            }
+            fn linked_insert_before(&mut self, node: Self::Node, element: T)
+                -> Result<Self::Node> {
+                self.node_index(node)?;
+                if self.is_full() {
+                    Err(Error::NotEnoughSpace(Some(1)))
+                } else {
+                    self.link_before(node, element)
+                }
+            }
+
+            fn linked_remove_last(&mut self) -> Result<T> {
+                self.pop_back()
+            }
+            fn linked_remove_before(&mut self, node: Self::Node) -> Result<T> {
+                let prev = self.nodes[self.node_index(node)?].prev();
+                if prev.is_none() {
+                    Err(Error::NotEnoughElements(1))
+                } else {
+                    self.linked_remove(prev)
+                }
+            }
+            fn linked_remove(&mut self, node: Self::Node) -> Result<T> {
+                let index = self.node_index(node)?;
+                if self.is_empty() {
+                    return Err(Error::NotEnoughElements(1));
+                }
+                self.unlink(node);
+                let data = self.nodes[index].data.clone();
+                self.free_node(node)?;
+                Ok(data)
+            }
+        }
+
+        // Private -------------------------------------------------------------
+
+        /// Private utility methods
+        #[allow(dead_code)]
+        impl<T, S: Storage, const CAP: usize> [<$name$b>]<T, S, CAP> {
+            /// Returns the index of the first free node.
+            const fn first_free_index(&self) -> [<NonMaxIndex$b>] {
+                if self.free.is_some() {
+                    self.free
+                } else {
+                    self.count.as_next_index()
+                }
+            }
+
+            /// Returns the index of the `node` as a `usize`.
+            ///
+            /// # Errors
+            /// If the index is out of bounds, or if it's either none or the
+            /// index of a node not in the list.
+            fn node_index(&self, node: [<NonMaxIndex$b>]) -> Result<usize> {
+                if node.is_none() {
+                    Err(Error::EmptyNode)
+                } else if node.as_usize() >= CAP {
+                    Err(Error::IndexOutOfBounds(node.as_usize()))
+                } else if !self.is_linked(node) {
+                    Err(Error::EmptyNode)
+                } else {
+                    Ok(node.as_usize())
+                }
+            }
+
+            /// Returns `true` if the node at the in-bounds `index` is in the list.
+            ///
+            /// A node is in the list if it's either the front node, or the
+            /// next node of its previous node. Free and unused nodes always
+            /// have a none previous index, so they are never mistaken for one.
+            fn is_linked(&self, index: [<NonMaxIndex$b>]) -> bool {
+                let prev = self.nodes[index.as_usize()].prev();
+                if prev.is_some() {
+                    self.nodes[prev.as_usize()].next() == index
+                } else {
+                    self.front == index
+                }
+            }
+
+            /// Returns the index of the node after the node at `index`,
+            /// or the index of the front node if `index` is none.
+            fn next_of(&self, index: [<NonMaxIndex$b>]) -> [<NonMaxIndex$b>] {
+                if index.is_some() {
+                    self.nodes[index.as_usize()].next()
+                } else {
+                    self.front
+                }
+            }
+
+            /// Returns the index of the node before the node at `index`,
+            /// or the index of the back node if `index` is none.
+            fn prev_of(&self, index: [<NonMaxIndex$b>]) -> [<NonMaxIndex$b>] {
+                if index.is_some() {
+                    self.nodes[index.as_usize()].prev()
+                } else {
+                    self.back
+                }
+            }
+
+            /// Stores `value` in the first free node, and returns its index.
+            ///
+            /// The node is left unlinked. Expects the list not to be full.
+            fn alloc_node(&mut self, value: T) -> Result<[<NonMaxIndex$b>]> {
+                let index = self.first_free_index();
+                if self.free.is_some() {
+                    // pop the node from the list of free nodes
+                    self.free = self.nodes[index.as_usize()].next();
+                }
+                self.nodes[index.as_usize()] = [<$name$b Node>]::new_unlinked(value);
+                self.count.increment()?;
+                Ok(index)
+            }
+
+            /// Adds the unlinked node at `index` to the list of free nodes.
+            fn free_node(&mut self, index: [<NonMaxIndex$b>]) -> Result<()> {
+                self.nodes[index.as_usize()].set_next(self.free);
+                self.nodes[index.as_usize()].set_prev([<NonMaxIndex$b>]::none());
+                self.free = index;
+                self.count.decrement()
+            }
+
+            /// Links a new node with `value` between the nodes at `prev` and `next`,
+            /// where a none `prev` means the front and a none `next` the back.
+            ///
+            /// Expects both nodes to be adjacent, and the list not to be full.
+            fn link_between(&mut self, prev: [<NonMaxIndex$b>], next: [<NonMaxIndex$b>],
+                value: T) -> Result<[<NonMaxIndex$b>]> {
+                let index = self.alloc_node(value)?;
+                self.nodes[index.as_usize()].set_prev(prev);
+                self.nodes[index.as_usize()].set_next(next);
+                if prev.is_some() {
+                    self.nodes[prev.as_usize()].set_next(index);
+                } else {
+                    self.front = index;
+                }
+                if next.is_some() {
+                    self.nodes[next.as_usize()].set_prev(index);
+                } else {
+                    self.back = index;
+                }
+                Ok(index)
+            }
+
+            /// Links a new node with `value` after the node at `anchor`,
+            /// or at the front if `anchor` is none, and returns its index.
+            ///
+            /// Expects the list not to be full.
+            fn link_after(&mut self, anchor: [<NonMaxIndex$b>], value: T)
+                -> Result<[<NonMaxIndex$b>]> {
+                self.link_between(anchor, self.next_of(anchor), value)
+            }
+
+            /// Links a new node with `value` before the node at `anchor`,
+            /// or at the back if `anchor` is none, and returns its index.
+            ///
+            /// Expects the list not to be full.
+            fn link_before(&mut self, anchor: [<NonMaxIndex$b>], value: T)
+                -> Result<[<NonMaxIndex$b>]> {
+                self.link_between(self.prev_of(anchor), anchor, value)
+            }
+
+            /// Unlinks the node at `index` from its neighbours.
+            ///
+            /// The node is not freed. Expects it to be in the list.
+            fn unlink(&mut self, index: [<NonMaxIndex$b>]) {
+                let prev = self.nodes[index.as_usize()].prev();
+                let next = self.nodes[index.as_usize()].next();
+                if prev.is_some() {
+                    self.nodes[prev.as_usize()].set_next(next);
+                } else {
+                    self.front = next;
+                }
+                if next.is_some() {
+                    self.nodes[next.as_usize()].set_prev(prev);
+                } else {
+                    self.back = prev;
+                }
+                self.nodes[index.as_usize()].unlink();
+            }
+
+            /// Unlinks all the nodes.
+            #[inline]
+            fn unlink_all_nodes(&mut self) {
+                if CAP == 0 {
+                    return;
+                }
+                for node in self.nodes.iter_mut() {
+                    node.unlink();
+                }
+            }
+        }
+
+        /// Private utility methods, when T: Clone
+        impl<T: Clone, S: Storage, const CAP: usize> [<$name$b>]<T, S, CAP> {
+            /// Resets all the nodes with the provided value, and unlinks them.
+            ///
+            /// Uses `value` to fill the data of each node.
+            #[inline]
+            fn reset_all_nodes(&mut self, value: T) {
+                if CAP == 0 {
+                    return;
+                }
+                for node in self.nodes.iter_mut() {
+                    node.reset(value.clone());
+                }
+            }
+        }
+    }};
+}
+
+// Only generate lists with an index primitive bit size >= usize::BITS
+
+#[cfg(any(
+    target_pointer_width = "8",
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+    target_pointer_width = "128"
+))]
+linked_list_array![DoublyLinkedList, 1, 8, u8, devela::NonMaxU8];
+
+#[cfg(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+    target_pointer_width = "128"
+))]
+linked_list_array![DoublyLinkedList, 2, 16, u16, devela::NonMaxU16];
+
+#[cfg(any(
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+    target_pointer_width = "128"
+))]
+linked_list_array![DoublyLinkedList, 4, 32, u32, devela::NonMaxU32];
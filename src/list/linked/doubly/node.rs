@@ -0,0 +1,109 @@
+// ladata::list::link::doubly::node
+
+/// Generates the node of a doubly linked list backed by an array.
+#[rustfmt::skip]
+macro_rules! linked_list_array_node {
+
+    // $name : name prefix. E.g.: DoublyLinked
+    // $b : bit size
+    ( $name:ident, $b:literal) => { devela::paste! {
+
+        /// The doubly list node.
+        pub(super) struct [<$name$b Node>] <T> {
+            /// The index of the previous element, towards the front of the list.
+            prev: [<NonMaxIndex$b>],
+            /// The index of the next element, towards the back of the list.
+            next: [<NonMaxIndex$b>],
+            /// The node's data.
+            data: T,
+        }
+
+        impl<T: Default> Default for [<$name$b Node>]<T> {
+            fn default() -> Self {
+                Self {
+                    prev: None.into(),
+                    next: None.into(),
+                    data: T::default(),
+                }
+            }
+        }
+
+        impl<T: Clone> Clone for [<$name$b Node>]<T> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    prev: self.prev.clone(),
+                    next: self.next.clone(),
+                    data: self.data.clone(),
+                }
+            }
+        }
+        impl<T: Copy> Copy for [<$name$b Node>]<T> {}
+
+        // IMPROVE
+        impl<T: Debug> fmt::Debug for [<$name$b Node>]<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify![[<$name$b Node>]])
+                .field("prev", &self.prev)
+                .field("next", &self.next)
+                .field("data", &self.data)
+                .finish()
+            }
+        }
+
+        impl<T> [<$name$b Node>]<T> {
+            /// Returns an empty node, with unlinked `prev`ious and `next` indices.
+            #[inline]
+            pub(super) const fn new_unlinked(data: T) -> Self {
+                Self {
+                    prev: [<NonMaxIndex$b>]::none(),
+                    next: [<NonMaxIndex$b>]::none(),
+                    data,
+                }
+            }
+
+            //
+
+            /// Returns this node's next index (towards the back).
+            #[inline]
+            pub(super) fn next(&self) -> [<NonMaxIndex$b>] {
+                self.next
+            }
+
+            /// Sets this node's next index (towards the back).
+            #[inline]
+            pub(super) fn set_next(&mut self, index: [<NonMaxIndex$b>]) {
+                self.next = index;
+            }
+
+            /// Returns this node's previous index (towards the front).
+            #[inline]
+            pub(super) fn prev(&self) -> [<NonMaxIndex$b>] {
+                self.prev
+            }
+
+            /// Sets this node's previous index (towards the front).
+            #[inline]
+            pub(super) fn set_prev(&mut self, index: [<NonMaxIndex$b>]) {
+                self.prev = index;
+            }
+
+            /// Unlinks the node, clearing both prev and next indices.
+            #[inline]
+            pub(super) fn unlink(&mut self) {
+                self.prev = [<NonMaxIndex$b>]::none();
+                self.next = [<NonMaxIndex$b>]::none();
+            }
+
+            /// Sets the `data` and unlinks the node,
+            /// clearing both prev and next indices.
+            #[inline]
+            pub(super) fn reset(&mut self, data: T) {
+                self.data = data;
+                self.prev = [<NonMaxIndex$b>]::none();
+                self.next = [<NonMaxIndex$b>]::none();
+            }
+        }
+    }};
+}
+pub(super) use linked_list_array_node;
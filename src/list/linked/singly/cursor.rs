@@ -0,0 +1,471 @@
+// ladata::list::link::singly::cursor
+//
+//! Cursors over a singly linked list.
+//
+// A cursor points either to a node of the list, or to a "ghost" non-element
+// that sits between the back and the front of the list, and is represented
+// by a current index pointing to nothing.
+//
+// The exclusive cursor also remembers the index of the previous node, so that
+// it can insert before and remove the current node without walking the list.
+// When pointing to the ghost element its previous node is the back node.
+
+/// Generates the cursors of a singly linked list backed by an array.
+#[rustfmt::skip]
+macro_rules! linked_list_array_cursor {
+
+    // $name : name prefix. E.g.: SinglyLinked
+    // $b : bit size
+    ( $name:ident, $b:literal) => { devela::paste! {
+
+        /// `*`
+        impl<T, S: Storage, const CAP: usize> [<$name$b>]<T, S, CAP> {
+            /// Returns a cursor pointing to the front element,
+            /// or to the ghost non-element if the list is empty.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            ///
+            /// let l = DirectSinglyLinkedList8::<i32, 4>::from([1, 2]);
+            ///
+            /// let mut c = l.cursor_front();
+            /// assert_eq![Some(&1), c.current()];
+            /// assert_eq![Some(&2), c.peek_next()];
+            /// c.move_next();
+            /// c.move_next();
+            /// assert_eq![None, c.current()];
+            /// ```
+            pub fn cursor_front(&self) -> [<$name$b Cursor>]<'_, T, S, CAP> {
+                [<$name$b Cursor>] {
+                    list: self,
+                    current: self.front,
+                }
+            }
+
+            /// Returns an exclusive cursor pointing to the front element,
+            /// or to the ghost non-element if the list is empty.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 4>::from([1, 3]);
+            ///
+            /// let mut c = l.cursor_front_mut();
+            /// c.insert_after(2)?;
+            /// c.insert_before(0)?;
+            /// assert![l.iter_front().eq(&[0, 1, 2, 3])];
+            /// # Ok(()) }
+            /// ```
+            pub fn cursor_front_mut(&mut self) -> [<$name$b CursorMut>]<'_, T, S, CAP> {
+                [<$name$b CursorMut>] {
+                    current: self.front,
+                    prev: [<NonMaxIndex$b>]::none(),
+                    list: self,
+                }
+            }
+        }
+
+        // Cursor --------------------------------------------------------------
+
+        #[doc = "A cursor over a `" [<$name$b>] "`."]
+        ///
+        /// It can move forward, wrapping around through a ghost non-element
+        /// that sits between the back and the front of the list.
+        pub struct [<$name$b Cursor>]<'s, T, S: Storage, const CAP: usize> {
+            list: &'s [<$name$b>]<T, S, CAP>,
+            /// The current node index, or none for the ghost non-element.
+            current: [<NonMaxIndex$b>],
+        }
+
+        impl<'s, T, S: Storage, const CAP: usize> [<$name$b Cursor>]<'s, T, S, CAP> {
+            /// Returns the index of the current node,
+            /// or none if pointing to the ghost non-element.
+            #[inline]
+            pub const fn index(&self) -> [<NonMaxIndex$b>] {
+                self.current
+            }
+
+            /// Returns a shared reference to the current element,
+            /// or `None` if pointing to the ghost non-element.
+            pub fn current(&self) -> Option<&'s T> {
+                if self.current.is_some() {
+                    Some(&self.list.nodes[self.current.as_usize()].data)
+                } else {
+                    None
+                }
+            }
+
+            /// Returns a shared reference to the next element,
+            /// or `None` if there's no next element.
+            ///
+            /// The next element of the ghost non-element is the front.
+            pub fn peek_next(&self) -> Option<&'s T> {
+                let next = self.list.next_of(self.current);
+                if next.is_some() {
+                    Some(&self.list.nodes[next.as_usize()].data)
+                } else {
+                    None
+                }
+            }
+
+            /// Moves the cursor to the next element.
+            ///
+            /// Moves to the ghost non-element after the back,
+            /// and to the front after the ghost non-element.
+            pub fn move_next(&mut self) {
+                self.current = self.list.next_of(self.current);
+            }
+        }
+
+        // CursorMut -----------------------------------------------------------
+
+        #[doc = "An exclusive cursor over a `" [<$name$b>] "`."]
+        ///
+        /// It can move forward, wrapping around through a ghost non-element
+        /// that sits between the back and the front of the list, and it can
+        /// insert and remove elements in *O(1)*, reusing the freed slots.
+        pub struct [<$name$b CursorMut>]<'s, T, S: Storage, const CAP: usize> {
+            list: &'s mut [<$name$b>]<T, S, CAP>,
+            /// The current node index, or none for the ghost non-element.
+            current: [<NonMaxIndex$b>],
+            /// The previous node index, or none if at the front.
+            prev: [<NonMaxIndex$b>],
+        }
+
+        /// `*`
+        impl<'s, T, S: Storage, const CAP: usize> [<$name$b CursorMut>]<'s, T, S, CAP> {
+            /// Returns the index of the current node,
+            /// or none if pointing to the ghost non-element.
+            #[inline]
+            pub const fn index(&self) -> [<NonMaxIndex$b>] {
+                self.current
+            }
+
+            /// Returns a read-only cursor pointing to the current element.
+            pub fn as_cursor(&self) -> [<$name$b Cursor>]<'_, T, S, CAP> {
+                [<$name$b Cursor>] {
+                    list: self.list,
+                    current: self.current,
+                }
+            }
+
+            /// Returns a shared reference to the current element,
+            /// or `None` if pointing to the ghost non-element.
+            pub fn current(&self) -> Option<&T> {
+                if self.current.is_some() {
+                    Some(&self.list.nodes[self.current.as_usize()].data)
+                } else {
+                    None
+                }
+            }
+
+            /// Returns an exclusive reference to the current element,
+            /// or `None` if pointing to the ghost non-element.
+            pub fn current_mut(&mut self) -> Option<&mut T> {
+                if self.current.is_some() {
+                    Some(&mut self.list.nodes[self.current.as_usize()].data)
+                } else {
+                    None
+                }
+            }
+
+            /// Returns a shared reference to the next element,
+            /// or `None` if there's no next element.
+            ///
+            /// The next element of the ghost non-element is the front.
+            pub fn peek_next(&self) -> Option<&T> {
+                let next = self.list.next_of(self.current);
+                if next.is_some() {
+                    Some(&self.list.nodes[next.as_usize()].data)
+                } else {
+                    None
+                }
+            }
+
+            /// Moves the cursor to the next element.
+            ///
+            /// Moves to the ghost non-element after the back,
+            /// and to the front after the ghost non-element.
+            pub fn move_next(&mut self) {
+                if self.current.is_some() {
+                    self.prev = self.current;
+                } else {
+                    self.prev = [<NonMaxIndex$b>]::none();
+                }
+                self.current = self.list.next_of(self.current);
+            }
+
+            /// Inserts a new element after the current one, and returns its index.
+            ///
+            /// If pointing to the ghost non-element, it's inserted at the front.
+            ///
+            /// # Errors
+            /// If the list is full.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 4>::from([1]);
+            ///
+            /// let mut c = l.cursor_front_mut();
+            /// c.insert_after(3)?;
+            /// c.insert_after(2)?;
+            /// c.move_next();
+            /// c.move_next();
+            /// c.move_next();
+            /// c.insert_after(0)?;
+            /// assert![l.iter_front().eq(&[0, 1, 2, 3])];
+            /// # Ok(()) }
+            /// ```
+            pub fn insert_after(&mut self, value: T) -> Result<[<NonMaxIndex$b>]> {
+                if self.list.is_full() {
+                    Err(Error::NotEnoughSpace(Some(1)))
+                } else {
+                    let index = self.list.link_after(self.current, value)?;
+                    if self.current.is_none() {
+                        self.prev = self.list.back;
+                    }
+                    Ok(index)
+                }
+            }
+
+            /// Inserts a new element before the current one, and returns its index.
+            ///
+            /// If pointing to the ghost non-element, it's inserted at the back.
+            ///
+            /// # Errors
+            /// If the list is full.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 4>::from([2]);
+            ///
+            /// let mut c = l.cursor_front_mut();
+            /// c.insert_before(0)?;
+            /// c.insert_before(1)?;
+            /// c.move_next();
+            /// c.insert_before(3)?;
+            /// assert![l.iter_front().eq(&[0, 1, 2, 3])];
+            /// # Ok(()) }
+            /// ```
+            pub fn insert_before(&mut self, value: T) -> Result<[<NonMaxIndex$b>]> {
+                if self.list.is_full() {
+                    Err(Error::NotEnoughSpace(Some(1)))
+                } else {
+                    let index = self.list.link_after(self.prev, value)?;
+                    self.prev = index;
+                    Ok(index)
+                }
+            }
+        }
+
+        /// `T:Clone`
+        impl<'s, T: Clone, S: Storage, const CAP: usize> [<$name$b CursorMut>]<'s, T, S, CAP> {
+            /// Removes the current element and returns it,
+            /// moving the cursor to the next element.
+            ///
+            /// # Errors
+            /// If pointing to the ghost non-element.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 4>::from([1, 2, 3]);
+            ///
+            /// let mut c = l.cursor_front_mut();
+            /// c.move_next();
+            /// assert_eq![2, c.remove_current()?];
+            /// assert_eq![Some(&3), c.current()];
+            /// assert_eq![3, c.remove_current()?];
+            /// assert![c.remove_current().is_err()];
+            /// assert![l.iter_front().eq(&[1])];
+            /// # Ok(()) }
+            /// ```
+            pub fn remove_current(&mut self) -> Result<T> {
+                if self.current.is_none() {
+                    Err(Error::EmptyNode)
+                } else {
+                    let next = self.list.nodes[self.current.as_usize()].next();
+                    let index = self.list.unlink_after(self.prev);
+                    let data = self.list.nodes[index.as_usize()].data.clone();
+                    self.list.free_node(index)?;
+                    self.current = next;
+                    Ok(data)
+                }
+            }
+        }
+
+        /// `T:Default`
+        impl<'s, T: Default, S: Storage, const CAP: usize> [<$name$b CursorMut>]<'s, T, S, CAP> {
+            /// Splits the list after the current element,
+            /// returning a new list with the following elements.
+            ///
+            /// If pointing to the ghost non-element, all the elements are moved.
+            ///
+            /// Moves each element in *O(1)*, leaving its default value in the
+            /// freed slot, plus *O(CAP)* for creating the new list.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 4>::from([1, 2, 3, 4]);
+            ///
+            /// let mut c = l.cursor_front_mut();
+            /// c.move_next();
+            /// let split = c.split_after();
+            /// assert![l.iter_front().eq(&[1, 2])];
+            /// assert![split.iter_front().eq(&[3, 4])];
+            /// ```
+            pub fn split_after(&mut self) -> [<$name$b>]<T, S, CAP>
+            where
+                [<$name$b>]<T, S, CAP>: Default,
+            {
+                let mut split = [<$name$b>]::<T, S, CAP>::default();
+                while self.list.next_of(self.current).is_some() {
+                    let index = self.list.unlink_after(self.current);
+                    let data = core::mem::take(&mut self.list.nodes[index.as_usize()].data);
+                    let _ = self.list.free_node(index);
+                    let _ = split.push_back(data);
+                }
+                if self.current.is_none() {
+                    self.prev = [<NonMaxIndex$b>]::none();
+                }
+                split
+            }
+
+            /// Splits the list before the current element,
+            /// returning a new list with the preceding elements.
+            ///
+            /// If pointing to the ghost non-element, all the elements are moved.
+            ///
+            /// Moves each element in *O(1)*, leaving its default value in the
+            /// freed slot, plus *O(CAP)* for creating the new list.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 4>::from([1, 2, 3, 4]);
+            ///
+            /// let mut c = l.cursor_front_mut();
+            /// c.move_next();
+            /// let split = c.split_before();
+            /// assert![l.iter_front().eq(&[2, 3, 4])];
+            /// assert![split.iter_front().eq(&[1])];
+            /// ```
+            pub fn split_before(&mut self) -> [<$name$b>]<T, S, CAP>
+            where
+                [<$name$b>]<T, S, CAP>: Default,
+            {
+                let mut split = [<$name$b>]::<T, S, CAP>::default();
+                while self.list.front.is_some() && self.list.front != self.current {
+                    let index = self.list.unlink_after([<NonMaxIndex$b>]::none());
+                    let data = core::mem::take(&mut self.list.nodes[index.as_usize()].data);
+                    let _ = self.list.free_node(index);
+                    let _ = split.push_back(data);
+                }
+                self.prev = [<NonMaxIndex$b>]::none();
+                split
+            }
+
+            /// Moves all the elements of `other` after the current element,
+            /// leaving `other` empty.
+            ///
+            /// If pointing to the ghost non-element, they're moved to the front.
+            ///
+            /// Moves each element of `other` in *O(1)*, leaving its default value
+            /// in the freed slot, regardless of the capacity of `other`.
+            ///
+            /// # Errors
+            /// If there's not enough free space for all the elements of `other`,
+            /// in which case nothing is moved.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 4>::from([1, 4]);
+            /// let mut other = DirectSinglyLinkedList8::<i32, 2>::from([2, 3]);
+            ///
+            /// l.cursor_front_mut().splice_after(&mut other)?;
+            /// assert![l.iter_front().eq(&[1, 2, 3, 4])];
+            /// assert![other.is_empty()];
+            /// # Ok(()) }
+            /// ```
+            pub fn splice_after<const OCAP: usize>(
+                &mut self,
+                other: &mut [<$name$b>]<T, S, OCAP>
+            ) -> Result<()> {
+                if other.len() > self.list.remaining_capacity() {
+                    return Err(Error::NotEnoughSpace(Some(other.len())));
+                }
+                let mut anchor = self.current;
+                while other.front.is_some() {
+                    let index = other.unlink_after([<NonMaxIndex$b>]::none());
+                    let data = core::mem::take(&mut other.nodes[index.as_usize()].data);
+                    let _ = other.free_node(index);
+                    anchor = self.list.link_after(anchor, data)?;
+                }
+                if self.current.is_none() {
+                    self.prev = self.list.back;
+                }
+                Ok(())
+            }
+
+            /// Moves all the elements of `other` before the current element,
+            /// leaving `other` empty.
+            ///
+            /// If pointing to the ghost non-element, they're moved to the back.
+            ///
+            /// Moves each element of `other` in *O(1)*, leaving its default value
+            /// in the freed slot, regardless of the capacity of `other`.
+            ///
+            /// # Errors
+            /// If there's not enough free space for all the elements of `other`,
+            /// in which case nothing is moved.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 4>::from([1, 4]);
+            /// let mut other = DirectSinglyLinkedList8::<i32, 2>::from([2, 3]);
+            ///
+            /// let mut c = l.cursor_front_mut();
+            /// c.move_next();
+            /// c.splice_before(&mut other)?;
+            /// assert![l.iter_front().eq(&[1, 2, 3, 4])];
+            /// # Ok(()) }
+            /// ```
+            pub fn splice_before<const OCAP: usize>(
+                &mut self,
+                other: &mut [<$name$b>]<T, S, OCAP>
+            ) -> Result<()> {
+                if other.len() > self.list.remaining_capacity() {
+                    return Err(Error::NotEnoughSpace(Some(other.len())));
+                }
+                while other.front.is_some() {
+                    let index = other.unlink_after([<NonMaxIndex$b>]::none());
+                    let data = core::mem::take(&mut other.nodes[index.as_usize()].data);
+                    let _ = other.free_node(index);
+                    self.prev = self.list.link_after(self.prev, data)?;
+                }
+                Ok(())
+            }
+        }
+    }};
+}
+pub(super) use linked_list_array_cursor;
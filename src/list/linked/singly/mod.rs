@@ -18,13 +18,14 @@
 // ```
 
 use core::{
+    cmp::Ordering,
     fmt::{self, Debug},
     mem::size_of,
 };
 
 use crate::{
     error::{LadataError as Error, LadataResult as Result},
-    list::Array,
+    list::{Array, DataSinglyLinkedList},
     mem::Storage,
     misc::*,
 };
@@ -35,6 +36,10 @@ use {
     alloc::{format, string::String, vec, vec::Vec},
 };
 
+mod cursor;
+mod node;
+mod ops;
+
 /// Generates a singly linked list backed by an array, with custom index size.
 #[rustfmt::skip]
 macro_rules! linked_list_array {
@@ -44,8 +49,9 @@ macro_rules! linked_list_array {
     // $t : inner index type
     // $nmt: devela::NonSpecific inner index type
     ( $name:ident, $B:literal, $b:literal, $t:ty, $nmt:ty) => { devela::paste! {
-        mod node;
         node::linked_list_array_node![$name, $b];
+        cursor::linked_list_array_cursor![$name, $b];
+        ops::linked_list_array_ops![$name, $b];
 
         #[doc = "A singly linked list, backed by an [`Array`], using " $b "-bit indices."]
         ///
@@ -55,6 +61,15 @@ macro_rules! linked_list_array {
         /// the index of the first free slot and the number of elements.
         ///
         /// Each node remembers the index of the next element of the list.
+        ///
+        /// The nodes don't remember whether they're in use, so checking that
+        /// a handle refers to a node in the list walks the list, which makes
+        /// the [`DataSinglyLinkedList`] methods that take a handle *O(n)*.
+        ///
+        /// Since each list owns its own array of nodes, splitting and splicing
+        /// lists with a cursor can't relink the nodes from one array into the other.
+        /// The elements are moved instead, one at a time, which requires `T: Default`
+        /// to take them out of their slots without `unsafe` code.
         pub struct [<$name$b>]<T, S: Storage, const CAP: usize> {
             /// The index of the current element at the front.
             front: [<NonMaxIndex$b>],
@@ -166,7 +181,7 @@ macro_rules! linked_list_array {
                 /// let l = SinglyLinkedList8::<u8, (), 100>::default();
                 /// ```
                 fn default() -> Self {
-                    assert![(CAP as $t) < $t::MAX];
+                    assert![CAP < $t::MAX as usize];
                     Self {
                         front: None.into(),
                         back: None.into(),
@@ -196,7 +211,7 @@ macro_rules! linked_list_array {
                 /// # Panics
                 #[doc = "Panics if `CAP` is > [`" $t "::MAX`]."]
                 fn default() -> Self {
-                    assert![(CAP as $t) < $t::MAX];
+                    assert![CAP < $t::MAX as usize];
                     Self {
                         front: None.into(),
                         back: None.into(),
@@ -225,7 +240,7 @@ macro_rules! linked_list_array {
             /// # Ok(()) }
             /// ```
             pub fn new(value: T) -> Result<Self> {
-                if (CAP as $t) < $t::MAX {
+                if CAP < $t::MAX as usize {
                     Ok(Self {
                         front: None.into(),
                         back: None.into(),
@@ -259,7 +274,7 @@ macro_rules! linked_list_array {
             /// # Ok(()) }
             /// ```
             pub fn new(value: T) -> Result<Self> {
-                if (CAP as $t) < $t::MAX {
+                if CAP < $t::MAX as usize {
                     Ok(Self {
                         front: None.into(),
                         back: None.into(),
@@ -531,25 +546,7 @@ macro_rules! linked_list_array {
                 if self.is_full() {
                     Err(Error::NotEnoughSpace(Some(1)))
                 } else {
-                    // The new front node, and its new index:
-                    let mut new_node = [<$name$b Node>]::new_unlinked(value);
-                    let new_index = self.first_free_index();
-
-                    // If there was already a front node…
-                    if self.front.is_some() {
-                        // …make the new front node point to it.
-                        new_node.set_next(self.front);
-                    } else {
-                        // otherwise list was empty and back must equal front.
-                        self.back = new_index;
-                    }
-
-                    // Update the node, the count and the front index:
-                    self.nodes[new_index.as_usize()] = new_node;
-                    self.count.increment()?;
-                    self.front = new_index;
-
-                    Ok(new_index)
+                    self.link_after([<NonMaxIndex$b>]::none(), value)
                 }
             }
 
@@ -591,25 +588,7 @@ macro_rules! linked_list_array {
                 if self.is_full() {
                     Err(Error::NotEnoughSpace(Some(1)))
                 } else {
-                    // The new front node, and its new index:
-                    let new_node = [<$name$b Node>]::new_unlinked(value);
-                    let new_index = self.first_free_index();
-
-                    // If there was already a back node…
-                    if self.back.is_some() {
-                        // …make it point to the new node.
-                        self.nodes[self.back.as_usize()].set_next(new_index);
-                    } else {
-                        // otherwise list was empty and front must equal back.
-                        self.front = new_index;
-                    }
-
-                    // Update the node, the count and the back index:
-                    self.nodes[new_index.as_usize()] = new_node;
-                    self.count.increment()?;
-                    self.back = new_index;
-
-                    Ok(new_index)
+                    self.link_after(self.back, value)
                 }
             }
 
@@ -745,38 +724,25 @@ macro_rules! linked_list_array {
             // [__2nd_] [__1st_] [______] [______]
             //       n_       n0       n_       n_
             //
-            // count:1  front:0  back:0  free:_                      pop_front()
+            // count:1  front:0  back:0  free:1                      pop_front()
             //    i0       i1       i2       i3
             // [__1st_] [______] [______] [______]
             //       n_       n_       n_       n_
             //
-            // count:0  front:_  back:_  free:_                      pop_front()
+            // count:0  front:_  back:_  free:0                      pop_front()
             //    i0       i1       i2       i3
             // [______] [______] [______] [______]
-            //       n_       n_       n_       n_
+            //       n1       n_       n_       n_
             // ```
             // #[cfg(not(feature = "unsafe"))] // IMPROVE: unsafe version not depending on Clone
             pub fn pop_front(&mut self) -> Result<T> {
                 if self.is_empty() {
                     Err(Error::NotEnoughElements(1))
                 } else {
-
-                    // Save the data of the current front node.
-                    let front_index = self.front;
-                    let (front_next, front_data) =
-                        self.nodes[front_index.as_usize()].clone().into_components();
-
-                    // unlink the "next" pointer of the old front node:
-                    self.nodes[front_index.as_usize()].unlink();
-
-                    // Update the front node index, and update the count:
-                    self.front = front_next;
-                    self.count.decrement()?;
-
-                    // if we have popped the last element.
-                    if self.is_empty() {
-                        self.back = None.into();
-                    }
+                    // Unlink the front node, and free its slot.
+                    let front_index = self.unlink_after([<NonMaxIndex$b>]::none());
+                    let front_data = self.nodes[front_index.as_usize()].data.clone();
+                    self.free_node(front_index)?;
 
                     Ok(front_data)
                 }
@@ -809,6 +775,7 @@ macro_rules! linked_list_array {
             /// Resets the list, unlinking all elements and setting them to `value`.
             pub fn reset(&mut self, value: T) {
                 self.front = None.into();
+                self.back = None.into();
                 self.free = None.into();
                 self.count = [<Counter$b>]::new();
                 self.reset_all_nodes(value);
@@ -912,6 +879,75 @@ macro_rules! linked_list_array {
             }
         }
 
+        // Data ----------------------------------------------------------------
+
+        impl<T, S: Storage, const CAP: usize> DataCollection for [<$name$b>]<T, S, CAP> {
+            type Element = T;
+
+            fn collection_is_empty(&self) -> Option<bool> {
+                Some(self.is_empty())
+            }
+            fn collection_is_full(&self) -> Option<bool> {
+                Some(self.is_full())
+            }
+            fn collection_capacity(&self) -> usize {
+                CAP
+            }
+            fn collection_len(&self) -> usize {
+                self.len()
+            }
+        }
+
+        /// `T:Clone`
+        impl<T: Clone, S: Storage, const CAP: usize> DataSinglyLinkedList
+            for [<$name$b>]<T, S, CAP> {
+            type Node = [<NonMaxIndex$b>];
+
+            fn linked_first(&self) -> Option<Self::Node> {
+                if self.front.is_some() { Some(self.front) } else { None }
+            }
+            fn linked_next(&self, node: Self::Node) -> Option<Self::Node> {
+                let next = self.nodes[self.node_index(node).ok()?].next();
+                if next.is_some() { Some(next) } else { None }
+            }
+
+            fn linked_data(&self, node: Self::Node) -> Result<&T> {
+                Ok(&self.nodes[self.node_index(node)?].data)
+            }
+            fn linked_data_mut(&mut self, node: Self::Node) -> Result<&mut T> {
+                let index = self.node_index(node)?;
+                Ok(&mut self.nodes[index].data)
+            }
+
+            fn linked_push_first(&mut self, element: T) -> Result<Self::Node> {
+                self.push_front(element)
+            }
+            fn linked_insert_after(&mut self, node: Self::Node, element: T)
+                -> Result<Self::Node> {
+                self.node_index(node)?;
+                if self.is_full() {
+                    Err(Error::NotEnoughSpace(Some(1)))
+                } else {
+                    self.link_after(node, element)
+                }
+            }
+
+            fn linked_remove_first(&mut self) -> Result<T> {
+                self.pop_front()
+            }
+            fn linked_remove_after(&mut self, node: Self::Node) -> Result<T> {
+                let next = self.nodes[self.node_index(node)?].next();
+                if next.is_none() {
+                    Err(Error::NotEnoughElements(1))
+                } else {
+                    let index = self.unlink_after(node);
+                    let data = self.nodes[index.as_usize()].data.clone();
+                    self.free_node(index)?;
+                    Ok(data)
+                }
+            }
+        }
+
         // Private -------------------------------------------------------------
 
         /// Private utility methods
@@ -926,14 +962,116 @@ macro_rules! linked_list_array {
                 }
             }
 
+            /// Returns the index of the `node` as a `usize`.
+            ///
+            /// Walks the list to check the node is in it, which is *O(n)*.
+            ///
+            /// # Errors
+            /// If the index is out of bounds, or if it's either none or the
+            /// index of a node not in the list.
+            fn node_index(&self, node: [<NonMaxIndex$b>]) -> Result<usize> {
+                if node.is_none() {
+                    Err(Error::EmptyNode)
+                } else if node.as_usize() >= CAP {
+                    Err(Error::IndexOutOfBounds(node.as_usize()))
+                } else {
+                    let mut index = self.front;
+                    while index.is_some() {
+                        if index == node {
+                            return Ok(node.as_usize());
+                        }
+                        index = self.nodes[index.as_usize()].next();
+                    }
+                    Err(Error::EmptyNode)
+                }
+            }
+
+            /// Returns the index of the node after the node at `index`,
+            /// or the index of the front node if `index` is none.
+            fn next_of(&self, index: [<NonMaxIndex$b>]) -> [<NonMaxIndex$b>] {
+                if index.is_some() {
+                    self.nodes[index.as_usize()].next()
+                } else {
+                    self.front
+                }
+            }
+
+            /// Stores `value` in the first free node, and returns its index.
+            ///
+            /// The node is left unlinked. Expects the list not to be full.
+            fn alloc_node(&mut self, value: T) -> Result<[<NonMaxIndex$b>]> {
+                let index = self.first_free_index();
+                if self.free.is_some() {
+                    // pop the node from the list of free nodes
+                    self.free = self.nodes[index.as_usize()].next();
+                }
+                self.nodes[index.as_usize()] = [<$name$b Node>]::new_unlinked(value);
+                self.count.increment()?;
+                Ok(index)
+            }
+
+            /// Adds the unlinked node at `index` to the list of free nodes.
+            fn free_node(&mut self, index: [<NonMaxIndex$b>]) -> Result<()> {
+                self.nodes[index.as_usize()].set_next(self.free);
+                self.free = index;
+                self.count.decrement()
+            }
+
+            /// Links a new node with `value` after the node at `anchor`,
+            /// or at the front if `anchor` is none, and returns its index.
+            ///
+            /// Expects the list not to be full.
+            fn link_after(&mut self, anchor: [<NonMaxIndex$b>], value: T)
+                -> Result<[<NonMaxIndex$b>]> {
+                let index = self.alloc_node(value)?;
+                if anchor.is_some() {
+                    let next = self.nodes[anchor.as_usize()].next();
+                    self.nodes[index.as_usize()].set_next(next);
+                    self.nodes[anchor.as_usize()].set_next(index);
+                    if anchor == self.back {
+                        self.back = index;
+                    }
+                } else {
+                    self.nodes[index.as_usize()].set_next(self.front);
+                    if self.front.is_none() {
+                        self.back = index;
+                    }
+                    self.front = index;
+                }
+                Ok(index)
+            }
+
+            /// Unlinks the node after the node at `anchor`,
+            /// or the front node if `anchor` is none, and returns its index.
+            ///
+            /// The node is not freed. Expects there to be such a node.
+            fn unlink_after(&mut self, anchor: [<NonMaxIndex$b>]) -> [<NonMaxIndex$b>] {
+                let index = if anchor.is_some() {
+                    self.nodes[anchor.as_usize()].next()
+                } else {
+                    self.front
+                };
+                let next = self.nodes[index.as_usize()].next();
+                if anchor.is_some() {
+                    self.nodes[anchor.as_usize()].set_next(next);
+                } else {
+                    self.front = next;
+                }
+                if index == self.back {
+                    self.back = anchor;
+                }
+                self.nodes[index.as_usize()].unlink();
+                index
+            }
+
             /// Unlinks all the nodes.
             #[inline]
             fn unlink_all_nodes(&mut self) {
                 if CAP == 0 {
                     return;
                 }
-                for i in 1..CAP-1 {
-                    self.nodes[i].unlink();
+                for node in self.nodes.iter_mut() {
+                    node.unlink();
                 }
             }
         }
@@ -948,8 +1086,8 @@ macro_rules! linked_list_array {
                 if CAP == 0 {
                     return;
                 }
-                for i in 1..CAP-1 {
-                    self.nodes[i].reset(value.clone());
+                for node in self.nodes.iter_mut() {
+                    node.reset(value.clone());
                 }
             }
         }
@@ -967,17 +1105,17 @@ macro_rules! linked_list_array {
 ))]
 linked_list_array![SinglyLinkedList, 1, 8, u8, devela::NonMaxU8];
 
-// #[cfg(any(
-//     target_pointer_width = "16",
-//     target_pointer_width = "32",
-//     target_pointer_width = "64",
-//     target_pointer_width = "128"
-// ))]
-// linked_list_array![SinglyLinkedList, 2, 16, u16, devela::NonMaxU16];
-//
-// #[cfg(any(
-//     target_pointer_width = "32",
-//     target_pointer_width = "64",
-//     target_pointer_width = "128"
-// ))]
-// linked_list_array![SinglyLinkedList, 4, 32, u32, devela::NonMaxU32];
+#[cfg(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+    target_pointer_width = "128"
+))]
+linked_list_array![SinglyLinkedList, 2, 16, u16, devela::NonMaxU16];
+
+#[cfg(any(
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+    target_pointer_width = "128"
+))]
+linked_list_array![SinglyLinkedList, 4, 32, u32, devela::NonMaxU32];
@@ -115,11 +115,11 @@ macro_rules! linked_list_array_node {
             //     self.data
             // }
 
-            /// Returns the inner components (next, data).
-            #[inline]
-            pub(super) fn into_components(self) -> ([<NonMaxIndex$b>], T) {
-                (self.next, self.data)
-            }
+            // /// Returns the inner components (next, data).
+            // #[inline]
+            // pub(super) fn into_components(self) -> ([<NonMaxIndex$b>], T) {
+            //     (self.next, self.data)
+            // }
         }
     }};
 }
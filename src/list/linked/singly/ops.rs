@@ -0,0 +1,365 @@
+// ladata::list::link::singly::ops
+//
+//! Operations that reorder and relink the nodes of a singly linked list.
+//
+// The nodes are relinked by index, so the payloads are never moved,
+// except when moving them between the arrays of two different lists.
+
+/// Generates the relinking operations of a singly linked list backed by an array.
+#[rustfmt::skip]
+macro_rules! linked_list_array_ops {
+
+    // $name : name prefix. E.g.: SinglyLinked
+    // $b : bit size
+    ( $name:ident, $b:literal) => { devela::paste! {
+
+        /// `*`
+        impl<T, S: Storage, const CAP: usize> [<$name$b>]<T, S, CAP> {
+            /// Sorts the list with a comparator function.
+            ///
+            /// This sort is stable, and it relinks the nodes by index using a
+            /// bottom-up merge sort, in *O(n log n)* time without allocating.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<_, 6>::from([(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd')]);
+            /// l.sort_by(|a, b| a.0.cmp(&b.0));
+            /// assert![l.iter_front().eq(&[(1, 'b'), (2, 'd'), (3, 'a'), (3, 'c')])];
+            /// ```
+            pub fn sort_by<F>(&mut self, mut compare: F)
+            where
+                F: FnMut(&T, &T) -> Ordering,
+            {
+                let len = self.len();
+                let mut width = 1;
+                while width < len {
+                    let mut front = [<NonMaxIndex$b>]::none();
+                    let mut back = [<NonMaxIndex$b>]::none();
+                    let mut head = self.front;
+                    while head.is_some() {
+                        let left = head;
+                        let right = self.cut_run(left, width);
+                        head = self.cut_run(right, width);
+                        let (run_front, run_back) = self.merge_runs(left, right, &mut compare);
+                        if back.is_some() {
+                            self.nodes[back.as_usize()].set_next(run_front);
+                        } else {
+                            front = run_front;
+                        }
+                        back = run_back;
+                    }
+                    self.front = front;
+                    self.back = back;
+                    width *= 2;
+                }
+            }
+
+            /// Sorts the list with a key extraction function.
+            ///
+            /// This sort is stable, and it relinks the nodes by index using a
+            /// bottom-up merge sort, in *O(n log n)* time without allocating.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 6>::from([-3, 1, -2, 4]);
+            /// l.sort_by_key(|a| a.abs());
+            /// assert![l.iter_front().eq(&[1, -2, -3, 4])];
+            /// ```
+            pub fn sort_by_key<K, F>(&mut self, mut f: F)
+            where
+                K: Ord,
+                F: FnMut(&T) -> K,
+            {
+                self.sort_by(|a, b| f(a).cmp(&f(b)));
+            }
+
+            /// Reverses the order of the elements, relinking the nodes in *O(n)*.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 4>::from([1, 2, 3]);
+            /// l.reverse();
+            /// assert![l.iter_front().eq(&[3, 2, 1])];
+            /// ```
+            pub fn reverse(&mut self) {
+                let mut prev = [<NonMaxIndex$b>]::none();
+                let mut current = self.front;
+                while current.is_some() {
+                    let next = self.nodes[current.as_usize()].next();
+                    self.nodes[current.as_usize()].set_next(prev);
+                    prev = current;
+                    current = next;
+                }
+                self.back = self.front;
+                self.front = prev;
+            }
+
+            /// Removes all but the first of consecutive elements that satisfy
+            /// the given equality relation, freeing their nodes.
+            ///
+            /// The `same_bucket` function is passed references to two consecutive
+            /// elements, the later one first, and if it returns `true` the later
+            /// element is removed.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 6>::from([1, 1, 2, 3, 3, 1]);
+            /// l.dedup_by(|a, b| a == b);
+            /// assert![l.iter_front().eq(&[1, 2, 3, 1])];
+            /// ```
+            pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+            where
+                F: FnMut(&mut T, &mut T) -> bool,
+            {
+                let mut prev = self.front;
+                if prev.is_none() {
+                    return;
+                }
+                let mut current = self.nodes[prev.as_usize()].next();
+                while current.is_some() {
+                    let next = self.nodes[current.as_usize()].next();
+                    let (later, earlier) = self.data_pair_mut(current, prev);
+                    if same_bucket(later, earlier) {
+                        self.unlink_after(prev);
+                        let _ = self.free_node(current);
+                    } else {
+                        prev = current;
+                    }
+                    current = next;
+                }
+            }
+
+            /// Retains only the elements specified by the predicate,
+            /// freeing the nodes of the rest.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 6>::from([1, 2, 3, 4, 5]);
+            /// l.retain(|&e| e % 2 == 1);
+            /// assert![l.iter_front().eq(&[1, 3, 5])];
+            /// ```
+            pub fn retain<F>(&mut self, mut f: F)
+            where
+                F: FnMut(&T) -> bool,
+            {
+                let mut prev = [<NonMaxIndex$b>]::none();
+                let mut current = self.front;
+                while current.is_some() {
+                    let next = self.nodes[current.as_usize()].next();
+                    if f(&self.nodes[current.as_usize()].data) {
+                        prev = current;
+                    } else {
+                        self.unlink_after(prev);
+                        let _ = self.free_node(current);
+                    }
+                    current = next;
+                }
+            }
+        }
+
+        /// `T:Default`
+        impl<T: Default, S: Storage, const CAP: usize> [<$name$b>]<T, S, CAP> {
+            /// Moves all the elements of `other` to the back of the list,
+            /// leaving `other` empty.
+            ///
+            /// The elements are moved one by one in *O(n)*,
+            /// leaving their default value in the slots of `other`.
+            ///
+            /// # Errors
+            /// If there's not enough free space for all the elements of `other`,
+            /// in which case nothing is moved.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 4>::from([1, 2]);
+            /// let mut other = DirectSinglyLinkedList8::<i32, 3>::from([3, 4]);
+            /// l.append(&mut other)?;
+            /// assert![l.iter_front().eq(&[1, 2, 3, 4])];
+            /// assert![other.is_empty()];
+            /// # Ok(()) }
+            /// ```
+            pub fn append<const OCAP: usize>(
+                &mut self,
+                other: &mut [<$name$b>]<T, S, OCAP>
+            ) -> Result<()> {
+                if other.len() > self.remaining_capacity() {
+                    return Err(Error::NotEnoughSpace(Some(other.len())));
+                }
+                let mut index = other.front;
+                while index.is_some() {
+                    let node = &mut other.nodes[index.as_usize()];
+                    index = node.next();
+                    self.link_after(self.back, core::mem::take(&mut node.data))?;
+                }
+                other.clear();
+                Ok(())
+            }
+
+            /// Merges the elements of `other` into the list, both sorted
+            /// according to the comparator function, leaving `other` empty.
+            ///
+            /// The merge is stable, keeping the elements of the list before the
+            /// equal elements of `other`, in *O(n + m)*.
+            ///
+            /// # Errors
+            /// If there's not enough free space for all the elements of `other`,
+            /// in which case nothing is moved.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<_, 6>::from([(1, 'a'), (3, 'a'), (5, 'a')]);
+            /// let mut other = DirectSinglyLinkedList8::<_, 3>::from([(2, 'b'), (3, 'b')]);
+            /// l.merge_sorted_by(&mut other, |a, b| a.0.cmp(&b.0))?;
+            /// assert![l.iter_front().eq(&[(1, 'a'), (2, 'b'), (3, 'a'), (3, 'b'), (5, 'a')])];
+            /// # Ok(()) }
+            /// ```
+            pub fn merge_sorted_by<F, const OCAP: usize>(
+                &mut self,
+                other: &mut [<$name$b>]<T, S, OCAP>,
+                mut compare: F,
+            ) -> Result<()>
+            where
+                F: FnMut(&T, &T) -> Ordering,
+            {
+                if other.len() > self.remaining_capacity() {
+                    return Err(Error::NotEnoughSpace(Some(other.len())));
+                }
+                let mut anchor = [<NonMaxIndex$b>]::none();
+                let mut index = other.front;
+                while index.is_some() {
+                    let node = &mut other.nodes[index.as_usize()];
+                    index = node.next();
+                    // advance past the elements not greater than the new one
+                    let mut next = self.next_of(anchor);
+                    while next.is_some()
+                        && compare(&self.nodes[next.as_usize()].data, &node.data) != Ordering::Greater
+                    {
+                        anchor = next;
+                        next = self.nodes[next.as_usize()].next();
+                    }
+                    anchor = self.link_after(anchor, core::mem::take(&mut node.data))?;
+                }
+                other.clear();
+                Ok(())
+            }
+        }
+
+        /// `T:Default + Ord`
+        impl<T: Default + Ord, S: Storage, const CAP: usize> [<$name$b>]<T, S, CAP> {
+            /// Merges the elements of `other` into the list, both sorted
+            /// in ascending order, leaving `other` empty.
+            ///
+            /// The merge is stable, keeping the elements of the list before the
+            /// equal elements of `other`, in *O(n + m)*.
+            ///
+            /// # Errors
+            /// If there's not enough free space for all the elements of `other`,
+            /// in which case nothing is moved.
+            ///
+            /// # Examples
+            /// ```
+            /// use ladata::list::DirectSinglyLinkedList8;
+            /// # fn main() -> ladata::error::LadataResult<()> {
+            ///
+            /// let mut l = DirectSinglyLinkedList8::<i32, 6>::from([1, 4, 5]);
+            /// let mut other = DirectSinglyLinkedList8::<i32, 3>::from([2, 3, 6]);
+            /// l.merge_sorted(&mut other)?;
+            /// assert![l.iter_front().eq(&[1, 2, 3, 4, 5, 6])];
+            /// # Ok(()) }
+            /// ```
+            pub fn merge_sorted<const OCAP: usize>(
+                &mut self,
+                other: &mut [<$name$b>]<T, S, OCAP>
+            ) -> Result<()> {
+                self.merge_sorted_by(other, T::cmp)
+            }
+        }
+
+        /// Private relinking methods
+        impl<T, S: Storage, const CAP: usize> [<$name$b>]<T, S, CAP> {
+            /// Cuts the run of up to `len` nodes starting at `start`,
+            /// and returns the index of the node after it.
+            fn cut_run(&mut self, start: [<NonMaxIndex$b>], len: usize) -> [<NonMaxIndex$b>] {
+                let mut last = start;
+                for _ in 1..len {
+                    if last.is_none() {
+                        break;
+                    }
+                    last = self.nodes[last.as_usize()].next();
+                }
+                if last.is_none() {
+                    [<NonMaxIndex$b>]::none()
+                } else {
+                    let next = self.nodes[last.as_usize()].next();
+                    self.nodes[last.as_usize()].set_next([<NonMaxIndex$b>]::none());
+                    next
+                }
+            }
+
+            /// Merges two sorted runs of nodes, stably,
+            /// and returns the indices of the front and back of the merged run.
+            fn merge_runs<F>(
+                &mut self,
+                mut left: [<NonMaxIndex$b>],
+                mut right: [<NonMaxIndex$b>],
+                compare: &mut F
+            ) -> ([<NonMaxIndex$b>], [<NonMaxIndex$b>])
+            where
+                F: FnMut(&T, &T) -> Ordering,
+            {
+                let mut front = [<NonMaxIndex$b>]::none();
+                let mut back = [<NonMaxIndex$b>]::none();
+                while left.is_some() || right.is_some() {
+                    let take_left = right.is_none() || (left.is_some()
+                        && compare(
+                            &self.nodes[right.as_usize()].data,
+                            &self.nodes[left.as_usize()].data,
+                        ) != Ordering::Less);
+                    let index = if take_left { left } else { right };
+                    if take_left {
+                        left = self.nodes[left.as_usize()].next();
+                    } else {
+                        right = self.nodes[right.as_usize()].next();
+                    }
+                    if back.is_some() {
+                        self.nodes[back.as_usize()].set_next(index);
+                    } else {
+                        front = index;
+                    }
+                    back = index;
+                }
+                (front, back)
+            }
+
+            /// Returns exclusive references to the data of two different nodes.
+            fn data_pair_mut(&mut self, a: [<NonMaxIndex$b>], b: [<NonMaxIndex$b>])
+                -> (&mut T, &mut T) {
+                let (a, b) = (a.as_usize(), b.as_usize());
+                if a < b {
+                    let (left, right) = self.nodes.split_at_mut(b);
+                    (&mut left[a].data, &mut right[0].data)
+                } else {
+                    let (left, right) = self.nodes.split_at_mut(a);
+                    (&mut right[0].data, &mut left[b].data)
+                }
+            }
+        }
+    }};
+}
+pub(super) use linked_list_array_ops;
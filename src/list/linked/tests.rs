@@ -2,11 +2,11 @@
 //!
 //
 
-use core::mem::size_of;
+use core::mem::{align_of, size_of};
 
-use super::{doubly::*, *};
+use super::{doubly::*, singly::*, *};
 
-// use crate::error::LadataError as Error;
+use crate::{error::LadataError as Error, misc::NonMaxIndex16};
 
 #[cfg(feature = "alloc")]
 use crate::mem::Boxed;
@@ -15,6 +15,9 @@ use crate::mem::Boxed;
 #[test]
 #[rustfmt::skip]
 fn doubly_linked_sizes_raw() {
+    // u128 is aligned to either 8 or 16 bytes, depending on the platform
+    let a = align_of::<u128>();
+
     /* 8-bit index list */
 
     // the size of a node is the sum of:
@@ -26,21 +29,23 @@ fn doubly_linked_sizes_raw() {
     assert_eq![2 + 2 + 0, size_of::<DoublyLinkedList8Node::<u16>>()];
     assert_eq![2 + 4 + 2, size_of::<DoublyLinkedList8Node::<u32>>()];
     assert_eq![2 + 8 + 6, size_of::<DoublyLinkedList8Node::<u64>>()];
-    assert_eq![2 + 16 + 6, size_of::<DoublyLinkedList8Node::<u128>>()];
+    assert_eq![2 + 16 + (a - 2), size_of::<DoublyLinkedList8Node::<u128>>()];
 
     // the size of a list of 0 elements:
-    assert_eq![3, size_of::<DoublyLinkedList8::<u8, (), 0>>()];
+    // - the size of its 4 indexes (front, back, free, count)
+    // - any extra padding
+    assert_eq![4, size_of::<DoublyLinkedList8::<u8, (), 0>>()];
     assert_eq![4, size_of::<DoublyLinkedList8::<u16, (), 0>>()];
     assert_eq![4, size_of::<DoublyLinkedList8::<u32, (), 0>>()];
     assert_eq![8, size_of::<DoublyLinkedList8::<u64, (), 0>>()];
-    assert_eq![8, size_of::<DoublyLinkedList8::<u128, (), 0>>()];
+    assert_eq![a, size_of::<DoublyLinkedList8::<u128, (), 0>>()];
 
     // the size of a list of 1 element:
-    assert_eq![3 + 1 + 2, size_of::<DoublyLinkedList8::<u8, (), 1>>()];
-    assert_eq![4 + 2 + 2, size_of::<DoublyLinkedList8::<u16, (), 1>>()];
-    assert_eq![4 + 4 + 4, size_of::<DoublyLinkedList8::<u32, (), 1>>()];
-    assert_eq![8 + 8 + 8, size_of::<DoublyLinkedList8::<u64, (), 1>>()];
-    assert_eq![8 + 16 + 8, size_of::<DoublyLinkedList8::<u128, (), 1>>()];
+    assert_eq![4 + 3 + 0, size_of::<DoublyLinkedList8::<u8, (), 1>>()];
+    assert_eq![4 + 4 + 0, size_of::<DoublyLinkedList8::<u16, (), 1>>()];
+    assert_eq![4 + 8 + 0, size_of::<DoublyLinkedList8::<u32, (), 1>>()];
+    assert_eq![8 + 16 + 0, size_of::<DoublyLinkedList8::<u64, (), 1>>()];
+    assert_eq![a + 16 + a, size_of::<DoublyLinkedList8::<u128, (), 1>>()];
 
     /* 16-bit index list */
 
@@ -48,33 +53,33 @@ fn doubly_linked_sizes_raw() {
     // - the size of its 2 indexes (2 * 2)
     // - the size of T
     // - any extra padding (NOTE: may depend on the platform)
-    assert_eq!(4 + 0, size_of::<DoublyLinkedList16Node::<()>>());
+    assert_eq!(4 + 0 + 0, size_of::<DoublyLinkedList16Node::<()>>());
     assert_eq![4 + 1 + 1, size_of::<DoublyLinkedList16Node::<u8>>()];
     assert_eq![4 + 2 + 0, size_of::<DoublyLinkedList16Node::<u16>>()];
     assert_eq![4 + 4 + 0, size_of::<DoublyLinkedList16Node::<u32>>()];
     assert_eq![4 + 8 + 4, size_of::<DoublyLinkedList16Node::<u64>>()];
-    assert_eq![4 + 16 + 4, size_of::<DoublyLinkedList16Node::<u128>>()];
+    assert_eq![4 + 16 + (a - 4), size_of::<DoublyLinkedList16Node::<u128>>()];
 
     // the size of a list of 0 elements:
-    assert_eq![6, size_of::<DoublyLinkedList16::<u8, (), 0>>()];
-    assert_eq![6, size_of::<DoublyLinkedList16::<u16, (), 0>>()];
+    assert_eq![8, size_of::<DoublyLinkedList16::<u8, (), 0>>()];
+    assert_eq![8, size_of::<DoublyLinkedList16::<u16, (), 0>>()];
     assert_eq![8, size_of::<DoublyLinkedList16::<u32, (), 0>>()];
     assert_eq![8, size_of::<DoublyLinkedList16::<u64, (), 0>>()];
-    assert_eq![8, size_of::<DoublyLinkedList16::<u128, (), 0>>()];
+    assert_eq![a.max(8), size_of::<DoublyLinkedList16::<u128, (), 0>>()];
 
     // the size of a list of 1 element:
-    assert_eq![6 + 1 + 5, size_of::<DoublyLinkedList16::<u8, (), 1>>()];
-    assert_eq![6 + 2 + 4, size_of::<DoublyLinkedList16::<u16, (), 1>>()];
-    assert_eq![8 + 4 + 4, size_of::<DoublyLinkedList16::<u32, (), 1>>()];
-    assert_eq![8 + 8 + 8, size_of::<DoublyLinkedList16::<u64, (), 1>>()];
-    assert_eq![8 + 16 + 8, size_of::<DoublyLinkedList16::<u128, (), 1>>()];
+    assert_eq![8 + 6, size_of::<DoublyLinkedList16::<u8, (), 1>>()];
+    assert_eq![8 + 6, size_of::<DoublyLinkedList16::<u16, (), 1>>()];
+    assert_eq![8 + 8, size_of::<DoublyLinkedList16::<u32, (), 1>>()];
+    assert_eq![8 + 16, size_of::<DoublyLinkedList16::<u64, (), 1>>()];
+    assert_eq![a.max(8) + 16 + a, size_of::<DoublyLinkedList16::<u128, (), 1>>()];
 
     // the size of a list of 10 elements:
-    assert_eq![6 + (1 + 5) * 10, size_of::<DoublyLinkedList16::<u8, (), 10>>()];
-    assert_eq![6 + (2 + 4) * 10, size_of::<DoublyLinkedList16::<u16, (), 10>>()];
-    assert_eq![8 + (4 + 4) * 10, size_of::<DoublyLinkedList16::<u32, (), 10>>()];
-    assert_eq![8 + (8 + 8) * 10, size_of::<DoublyLinkedList16::<u64, (), 10>>()];
-    assert_eq![8 + (16 + 8) * 10, size_of::<DoublyLinkedList16::<u128, (), 10>>()];
+    assert_eq![8 + 6 * 10, size_of::<DoublyLinkedList16::<u8, (), 10>>()];
+    assert_eq![8 + 6 * 10, size_of::<DoublyLinkedList16::<u16, (), 10>>()];
+    assert_eq![8 + 8 * 10, size_of::<DoublyLinkedList16::<u32, (), 10>>()];
+    assert_eq![8 + 16 * 10, size_of::<DoublyLinkedList16::<u64, (), 10>>()];
+    assert_eq![a.max(8) + (16 + a) * 10, size_of::<DoublyLinkedList16::<u128, (), 10>>()];
 
     /* 32-bit index list */
 
@@ -83,113 +88,578 @@ fn doubly_linked_sizes_raw() {
     assert_eq![8 + 2 + 2, size_of::<DoublyLinkedList32Node::<u16>>()];
     assert_eq![8 + 4 + 0, size_of::<DoublyLinkedList32Node::<u32>>()];
     assert_eq![8 + 8 + 0, size_of::<DoublyLinkedList32Node::<u64>>()];
-    assert_eq![8 + 16 + 0, size_of::<DoublyLinkedList32Node::<u128>>()];
+    assert_eq![8 + 16 + (a - 8), size_of::<DoublyLinkedList32Node::<u128>>()];
 
     // the size of a list of 0 elements:
-    assert_eq![12, size_of::<DoublyLinkedList32::<u8, (), 0>>()];
-    assert_eq![12, size_of::<DoublyLinkedList32::<u16, (), 0>>()];
-    assert_eq![12, size_of::<DoublyLinkedList32::<u32, (), 0>>()];
+    assert_eq![16, size_of::<DoublyLinkedList32::<u8, (), 0>>()];
+    assert_eq![16, size_of::<DoublyLinkedList32::<u16, (), 0>>()];
+    assert_eq![16, size_of::<DoublyLinkedList32::<u32, (), 0>>()];
     assert_eq![16, size_of::<DoublyLinkedList32::<u64, (), 0>>()];
     assert_eq![16, size_of::<DoublyLinkedList32::<u128, (), 0>>()];
 
     // the size of a list of 1 element:
-    assert_eq![12 + 1 + 11, size_of::<DoublyLinkedList32::<u8, (), 1>>()];
-    assert_eq![12 + 2 + 10, size_of::<DoublyLinkedList32::<u16, (), 1>>()];
-    assert_eq![12 + 4 + 8, size_of::<DoublyLinkedList32::<u32, (), 1>>()];
-    assert_eq![16 + 8 + 8, size_of::<DoublyLinkedList32::<u64, (), 1>>()];
-    assert_eq![16 + 16 + 8, size_of::<DoublyLinkedList32::<u128, (), 1>>()];
+    assert_eq![16 + 12, size_of::<DoublyLinkedList32::<u8, (), 1>>()];
+    assert_eq![16 + 12, size_of::<DoublyLinkedList32::<u16, (), 1>>()];
+    assert_eq![16 + 12, size_of::<DoublyLinkedList32::<u32, (), 1>>()];
+    assert_eq![16 + 16, size_of::<DoublyLinkedList32::<u64, (), 1>>()];
+    assert_eq![16 + 16 + a, size_of::<DoublyLinkedList32::<u128, (), 1>>()];
 
     /* misc. list sizes */
 
     // max 8-bit len with a byte per node occupies ± 0.75 KiB
     assert_eq![
-        765,
+        766,
         size_of::<DoublyLinkedList8::<u8, (), { u8::MAX as usize - 1 }>>()
     ];
     // to store one node more we need 16-bit indexes, occupping 1.5 KiB
     assert_eq![
-        1536,
+        1538,
         size_of::<DoublyLinkedList16::<u8, (), { u8::MAX as usize }>>()
     ];
     // max 16-bit len with a byte per node occupies ± 384 KiB)
     assert_eq![
-        393_210,
+        393_212,
         size_of::<DoublyLinkedList16::<u8, (), { u16::MAX as usize - 1 }>>()
     ];
     // to store one node more we need 32-bit indexes, occupping 768 KiB
     assert_eq![
-        786_432,
+        786_436,
         size_of::<DoublyLinkedList32::<u8, (), { u16::MAX as usize }>>()
     ];
     // max 32-bit len with a byte per node occupies ± 48 GiB)
     assert_eq![
-        51_539_607_540,
+        51_539_607_544,
         size_of::<DoublyLinkedList32::<u8, (), { u32::MAX as usize - 1 }>>()
     ];
 }
 
+#[test]
+#[rustfmt::skip]
+fn singly_linked_sizes_raw() {
+    // the size of a node is the sum of:
+    // - the size of its index
+    // - the size of T
+    // - any extra padding (NOTE: may depend on the platform)
+    assert_eq!(1 + 0 + 0, size_of::<SinglyLinkedList8Node::<()>>());
+    assert_eq![1 + 1 + 0, size_of::<SinglyLinkedList8Node::<u8>>()];
+    assert_eq![1 + 2 + 1, size_of::<SinglyLinkedList8Node::<u16>>()];
+    assert_eq![1 + 4 + 3, size_of::<SinglyLinkedList8Node::<u32>>()];
+    assert_eq![1 + 8 + 7, size_of::<SinglyLinkedList8Node::<u64>>()];
+
+    assert_eq!(2 + 0 + 0, size_of::<SinglyLinkedList16Node::<()>>());
+    assert_eq![2 + 1 + 1, size_of::<SinglyLinkedList16Node::<u8>>()];
+    assert_eq![2 + 2 + 0, size_of::<SinglyLinkedList16Node::<u16>>()];
+    assert_eq![2 + 4 + 2, size_of::<SinglyLinkedList16Node::<u32>>()];
+    assert_eq![2 + 8 + 6, size_of::<SinglyLinkedList16Node::<u64>>()];
+
+    assert_eq!(4 + 0 + 0, size_of::<SinglyLinkedList32Node::<()>>());
+    assert_eq![4 + 1 + 3, size_of::<SinglyLinkedList32Node::<u8>>()];
+    assert_eq![4 + 2 + 2, size_of::<SinglyLinkedList32Node::<u16>>()];
+    assert_eq![4 + 4 + 0, size_of::<SinglyLinkedList32Node::<u32>>()];
+    assert_eq![4 + 8 + 4, size_of::<SinglyLinkedList32Node::<u64>>()];
+
+    // the size of a list of 0 elements is the size of its 4 indexes
+    assert_eq![4, size_of::<SinglyLinkedList8::<u8, (), 0>>()];
+    assert_eq![8, size_of::<SinglyLinkedList16::<u8, (), 0>>()];
+    assert_eq![16, size_of::<SinglyLinkedList32::<u8, (), 0>>()];
+}
+
+#[test]
+fn linked_node_size() {
+    // a node only occupies its indexes, plus the size of T
+    assert_eq!(1, size_of::<SinglyLinkedList8Node<()>>());
+    assert_eq!(2, size_of::<SinglyLinkedList16Node<()>>());
+    assert_eq!(4, size_of::<SinglyLinkedList32Node<()>>());
+    assert_eq!(2, size_of::<DoublyLinkedList8Node<()>>());
+    assert_eq!(4, size_of::<DoublyLinkedList16Node<()>>());
+    assert_eq!(8, size_of::<DoublyLinkedList32Node<()>>());
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn doubly_linked_sizes_boxed() {
     // on the heap
     assert_eq![16, size_of::<DoublyLinkedList8::<u8, Boxed, 10>>()];
-    assert_eq![16, size_of::<DoublyLinkedList8::<u128, Boxed, 10>>()];
+    assert_eq![16, size_of::<DoublyLinkedList8::<u64, Boxed, 10>>()];
     assert_eq![16, size_of::<DoublyLinkedList16::<u8, Boxed, 10>>()];
-    assert_eq![16, size_of::<DoublyLinkedList16::<u128, Boxed, 10>>()];
+    assert_eq![16, size_of::<DoublyLinkedList16::<u64, Boxed, 10>>()];
     assert_eq![24, size_of::<DoublyLinkedList32::<u8, Boxed, 10>>()];
-    assert_eq![24, size_of::<DoublyLinkedList32::<u128, Boxed, 10>>()];
-}
-
-// #[test]
-// fn doubly_linked_push_pop_front() {
-//     let mut list = DirectDoublyLinkedList8::<i32, 3>::default();
-//     assert_eq!(list.push_front(1), Ok(0));
-//     assert_eq!(list.push_front(2), Ok(1));
-//     assert_eq!(list.push_front(3), Ok(2));
-//     assert_eq!(list.push_front(4), Err(Error::NotEnoughSpace(Some(1))));
-//     assert_eq!(list.pop_front(), Ok(3));
-//     assert_eq!(list.pop_front(), Ok(2));
-//     assert_eq!(list.pop_front(), Ok(1));
-//     assert_eq!(list.pop_front(), Err(Error::NotEnoughElements(1)));
-// }
-
-// #[test]
-// fn doubly_linked_push_pop_back() {
-//     let mut list = DirectDoublyLinkedList8::<i32, 3>::default();
-//     assert_eq!(list.push_back(1), Ok(0));
-//     assert_eq!(list.push_back(2), Ok(1));
-//     assert_eq!(list.push_back(3), Ok(2));
-//     assert_eq!(list.push_back(4), Err(Error::NotEnoughSpace(Some(1))));
-//     assert_eq!(list.pop_back(), Ok(3));
-//     assert_eq!(list.pop_back(), Ok(2));
-//     assert_eq!(list.pop_back(), Ok(1));
-//     assert_eq!(list.pop_back(), Err(Error::NotEnoughElements(1)));
-// }
-
-// #[test]
-// fn doubly_linked_push_mixed() {
-//     let mut list = DirectDoublyLinkedList8::<i32, 3>::default();
-//     assert_eq!(list.push_front(1), Ok(0));
-//     assert_eq!(list.push_back(2), Ok(1));
-//     assert_eq!(list.push_front(3), Ok(2));
-//     assert_eq!(list.push_back(4), Err(Error::NotEnoughSpace(Some(1))));
-//     assert_eq!(list.pop_front(), Ok(3));
-//     // FIXME
-//     // assert_eq!(list.pop_back(), Ok(2));
-//     // assert_eq!(list.pop_back(), Ok(1));
-//     // assert_eq!(list.pop_back(), Err(Error::NotEnoughElements(1)));
-//     //
-//     // TEMP
-//     // assert_eq!(list.pop_front(), Ok(1));
-//     // assert_eq!(list.pop_front(), Ok(2));
-//     // assert_eq!(list.pop_front(), Err(Error::NotEnoughElements(1)));
-// }
-
-// TODO
-#[test]
-fn singly_linked() {
+    assert_eq![24, size_of::<DoublyLinkedList32::<u64, Boxed, 10>>()];
+}
+
+#[test]
+fn doubly_linked_push_pop_front() {
+    let mut list = DirectDoublyLinkedList8::<i32, 3>::default();
+    assert_eq!(list.push_front(1), Ok(0.into()));
+    assert_eq!(list.push_front(2), Ok(1.into()));
+    assert_eq!(list.push_front(3), Ok(2.into()));
+    assert_eq!(list.push_front(4), Err(Error::NotEnoughSpace(Some(1))));
+    assert_eq!(list.pop_front(), Ok(3));
+    assert_eq!(list.pop_front(), Ok(2));
+    assert_eq!(list.pop_front(), Ok(1));
+    assert_eq!(list.pop_front(), Err(Error::NotEnoughElements(1)));
+}
+
+#[test]
+fn doubly_linked_push_pop_back() {
+    let mut list = DirectDoublyLinkedList8::<i32, 3>::default();
+    assert_eq!(list.push_back(1), Ok(0.into()));
+    assert_eq!(list.push_back(2), Ok(1.into()));
+    assert_eq!(list.push_back(3), Ok(2.into()));
+    assert_eq!(list.push_back(4), Err(Error::NotEnoughSpace(Some(1))));
+    assert_eq!(list.pop_back(), Ok(3));
+    assert_eq!(list.pop_back(), Ok(2));
+    assert_eq!(list.pop_back(), Ok(1));
+    assert_eq!(list.pop_back(), Err(Error::NotEnoughElements(1)));
+}
+
+#[test]
+fn doubly_linked_push_mixed() {
+    let mut list = DirectDoublyLinkedList8::<i32, 3>::default();
+    assert_eq!(list.push_front(1), Ok(0.into()));
+    assert_eq!(list.push_back(2), Ok(1.into()));
+    assert_eq!(list.push_front(3), Ok(2.into()));
+    assert_eq!(list.push_back(4), Err(Error::NotEnoughSpace(Some(1))));
+    assert_eq!(list.pop_front(), Ok(3));
+    assert_eq!(list.pop_back(), Ok(2));
+    assert_eq!(list.pop_back(), Ok(1));
+    assert_eq!(list.pop_back(), Err(Error::NotEnoughElements(1)));
+}
+
+#[test]
+fn doubly_linked_reuse_freed_slots() {
+    let mut list = DirectDoublyLinkedList8::<i32, 3>::from([1, 2, 3]);
+    assert_eq!(list.pop_back(), Ok(3));
+    assert_eq!(list.pop_front(), Ok(1));
+    // the last freed slot is reused first
+    assert_eq!(list.push_back(4), Ok(0.into()));
+    assert_eq!(list.push_front(5), Ok(2.into()));
+    assert![list.iter_front().eq(&[5, 2, 4])];
+    assert![list.iter_front().rev().eq(&[4, 2, 5])];
+}
+
+#[test]
+fn doubly_linked_cursor_edit() {
+    let mut list = DirectDoublyLinkedList8::<i32, 4>::from([1, 2, 3, 4]);
+    let mut c = list.cursor_front_mut();
+    c.move_next();
+    assert_eq!(c.remove_current(), Ok(2));
+    assert_eq!(c.remove_current(), Ok(3));
+    assert_eq!(c.current(), Some(&4));
+    // the freed slots are reused
+    assert_eq!(c.insert_before(5), Ok(2.into()));
+    assert_eq!(c.insert_after(6), Ok(1.into()));
+    assert_eq!(c.insert_after(7), Err(Error::NotEnoughSpace(Some(1))));
+    // wrap around the ghost non-element
+    c.move_next();
+    c.move_next();
+    assert_eq!(c.current(), None);
+    assert_eq!(c.remove_current(), Err(Error::EmptyNode));
+    c.move_next();
+    assert_eq!(c.current(), Some(&1));
+    c.move_prev();
+    c.move_prev();
+    assert_eq!(c.current(), Some(&6));
+    assert![list.iter_front().eq(&[1, 5, 4, 6])];
+    assert![list.iter_front().rev().eq(&[6, 4, 5, 1])];
+}
+
+#[test]
+fn doubly_linked_cursor_split_splice() {
+    let mut list = DirectDoublyLinkedList8::<i32, 6>::from([1, 2, 3, 4]);
+    let mut c = list.cursor_front_mut();
+    c.move_next();
+    let mut back = c.split_after();
+    let mut front = c.split_before();
+    assert_eq!(c.current(), Some(&2));
+    assert_eq!(c.peek_next(), None);
+    assert_eq!(c.peek_prev(), None);
+    c.splice_after(&mut back).unwrap();
+    c.splice_before(&mut front).unwrap();
+    assert![back.is_empty() && front.is_empty()];
+    assert![list.iter_front().eq(&[1, 2, 3, 4])];
+    assert![list.iter_front().rev().eq(&[4, 3, 2, 1])];
+
+    // the spliced lists are left empty and reusable
+    assert_eq!(back.push_back(7), Ok(1.into()));
+    assert_eq!(back.push_back(8), Ok(0.into()));
+    assert![back.iter_front().eq(&[7, 8])];
+
+    // not enough space
+    let mut other = DirectDoublyLinkedList8::<i32, 3>::from([5, 6, 7]);
+    let mut c = list.cursor_back_mut();
+    assert_eq!(
+        c.splice_after(&mut other),
+        Err(Error::NotEnoughSpace(Some(3)))
+    );
+    assert_eq!(other.len(), 3);
+}
+
+#[test]
+fn singly_linked_reuse_freed_slots() {
     let mut list = SinglyLinkedList8::<i32, (), 3>::default();
+    assert_eq!(list.push_back(1), Ok(0.into()));
+    assert_eq!(list.push_back(2), Ok(1.into()));
+    assert_eq!(list.pop_front(), Ok(1));
+    // the freed slot is reused, without overwriting any linked node
+    assert_eq!(list.push_back(3), Ok(0.into()));
+    assert_eq!(list.push_back(4), Ok(2.into()));
+    assert![list.iter_front().eq(&[2, 3, 4])];
+}
+
+#[test]
+fn singly_linked_cursor_edit() {
+    let mut list = DirectSinglyLinkedList8::<i32, 4>::from([1, 2, 3, 4]);
+    let mut c = list.cursor_front_mut();
+    c.move_next();
+    assert_eq!(c.remove_current(), Ok(2));
+    assert_eq!(c.remove_current(), Ok(3));
+    assert_eq!(c.current(), Some(&4));
+    assert_eq!(c.remove_current(), Ok(4));
+    // at the ghost non-element
+    assert_eq!(c.current(), None);
+    assert_eq!(c.remove_current(), Err(Error::EmptyNode));
+    assert_eq!(c.insert_before(6), Ok(3.into()));
+    assert_eq!(c.insert_after(0), Ok(2.into()));
+    assert_eq!(c.peek_next(), Some(&0));
+    c.move_next();
+    c.move_next();
+    assert_eq!(c.insert_before(5), Ok(1.into()));
+    assert_eq!(c.insert_after(7), Err(Error::NotEnoughSpace(Some(1))));
+    assert![list.iter_front().eq(&[0, 5, 1, 6])];
+}
+
+#[test]
+fn singly_linked_cursor_split_splice() {
+    let mut list = DirectSinglyLinkedList8::<i32, 6>::from([1, 2, 3, 4]);
+    let mut c = list.cursor_front_mut();
+    c.move_next();
+    let mut back = c.split_after();
+    let mut front = c.split_before();
+    assert_eq!(c.current(), Some(&2));
+    assert_eq!(c.peek_next(), None);
+    c.splice_after(&mut back).unwrap();
+    c.splice_before(&mut front).unwrap();
+    assert![back.is_empty() && front.is_empty()];
+    assert![list.iter_front().eq(&[1, 2, 3, 4])];
+    assert_eq!(list.back(), Ok(&4));
+
+    // the spliced lists are left empty and reusable
+    assert_eq!(back.push_back(7), Ok(1.into()));
+    assert_eq!(back.push_back(8), Ok(0.into()));
+    assert![back.iter_front().eq(&[7, 8])];
+
+    // splicing at the ghost non-element
+    let mut other = DirectSinglyLinkedList8::<i32, 2>::from([5, 6]);
+    let mut c = list.cursor_front_mut();
+    while c.current().is_some() {
+        c.move_next();
+    }
+    c.splice_before(&mut other).unwrap();
+    assert_eq!(c.peek_next(), Some(&1));
+    assert![list.iter_front().eq(&[1, 2, 3, 4, 5, 6])];
+    assert_eq!(list.back(), Ok(&6));
+}
+
+/* conformance */
+
+/// Runs the `DataSinglyLinkedList` suite on an empty list with a capacity of 4.
+fn singly_conformance<L: DataSinglyLinkedList<Element = i32>>(mut list: L) {
+    assert_eq![Some(true), list.collection_is_empty()];
+    assert_eq![4, list.collection_capacity()];
+    assert_eq![None, list.linked_first()];
+    assert![list.linked_remove_first().is_err()];
+
+    let n2 = list.linked_push_first(2).unwrap();
+    let n1 = list.linked_push_first(1).unwrap();
+    let n4 = list.linked_insert_after(n2, 4).unwrap();
+    let n3 = list.linked_insert_after(n2, 3).unwrap();
+    assert_eq![Some(true), list.collection_is_full()];
+    assert![list.linked_push_first(0).is_err()];
+    assert![list.linked_insert_after(n4, 5).is_err()];
+
+    // walk the list forward
+    assert_eq![Some(n1), list.linked_first()];
+    assert_eq![Some(n2), list.linked_next(n1)];
+    assert_eq![Some(n3), list.linked_next(n2)];
+    assert_eq![Some(n4), list.linked_next(n3)];
+    assert_eq![None, list.linked_next(n4)];
+    assert_eq![Ok(&3), list.linked_data(n3)];
+
+    *list.linked_data_mut(n3).unwrap() = 30;
+    assert_eq![Ok(30), list.linked_remove_after(n2)];
+    assert_eq![Some(n4), list.linked_next(n2)];
+    assert![list.linked_remove_after(n4).is_err()];
+    assert_eq![Ok(1), list.linked_remove_first()];
+    assert_eq![Some(n2), list.linked_first()];
+    assert_eq![2, list.collection_len()];
+
+    // the handles of removed nodes are rejected
+    for stale in [n1, n3] {
+        assert_eq![Err(Error::EmptyNode), list.linked_data(stale)];
+        assert![list.linked_data_mut(stale).is_err()];
+        assert_eq![None, list.linked_next(stale)];
+        assert![list.linked_insert_after(stale, 9).is_err()];
+        assert![list.linked_remove_after(stale).is_err()];
+    }
+    assert_eq![2, list.collection_len()];
+    assert_eq![Some(n2), list.linked_first()];
+    assert_eq![Some(n4), list.linked_next(n2)];
+    assert_eq![None, list.linked_next(n4)];
+
+    // the freed nodes are reused
+    let n5 = list.linked_insert_after(n4, 5).unwrap();
+    let n0 = list.linked_push_first(0).unwrap();
+    assert![n5 == n1 || n5 == n3];
+    assert![n0 == n1 || n0 == n3];
+    assert_eq![Some(true), list.collection_is_full()];
+}
+
+/// Runs the `DataDoublyLinkedList` suite on an empty list with a capacity of 4.
+fn doubly_conformance<L: DataDoublyLinkedList<Element = i32>>(mut list: L) {
+    assert_eq![None, list.linked_last()];
+    assert![list.linked_remove_last().is_err()];
+
+    let n2 = list.linked_push_last(2).unwrap();
+    let n4 = list.linked_push_last(4).unwrap();
+    let n3 = list.linked_insert_before(n4, 3).unwrap();
+    let n1 = list.linked_insert_before(n2, 1).unwrap();
+    assert![list.linked_push_last(5).is_err()];
+
+    // walk the list backward
+    assert_eq![Some(n4), list.linked_last()];
+    assert_eq![Some(n3), list.linked_prev(n4)];
+    assert_eq![Some(n2), list.linked_prev(n3)];
+    assert_eq![Some(n1), list.linked_prev(n2)];
+    assert_eq![None, list.linked_prev(n1)];
+    assert_eq![Some(n1), list.linked_first()];
+
+    assert_eq![Ok(2), list.linked_remove(n2)];
+    assert_eq![Some(n1), list.linked_prev(n3)];
+
+    // the handle of a removed node is rejected
+    assert_eq![Err(Error::EmptyNode), list.linked_remove(n2)];
+    assert_eq![None, list.linked_prev(n2)];
+    assert![list.linked_insert_before(n2, 9).is_err()];
+    assert![list.linked_remove_before(n2).is_err()];
+    assert_eq![3, list.collection_len()];
+    assert_eq![Some(n1), list.linked_first()];
+    assert_eq![Some(n3), list.linked_next(n1)];
+    assert_eq![Some(n1), list.linked_prev(n3)];
+    assert_eq![Ok(1), list.linked_remove_before(n3)];
+    assert![list.linked_remove_before(n3).is_err()];
+    assert_eq![Ok(4), list.linked_remove_last()];
+    assert_eq![Some(n3), list.linked_first()];
+    assert_eq![Some(n3), list.linked_last()];
+    assert_eq![Ok(3), list.linked_remove_first()];
+    assert_eq![Some(true), list.collection_is_empty()];
+
+    // the singly linked list suite also applies
+    singly_conformance(list);
+}
+
+#[test]
+#[should_panic]
+fn doubly_linked_cap_above_index_range() {
+    let _ = DirectDoublyLinkedList8::<u16, 300>::default();
+}
+
+#[test]
+#[should_panic]
+fn singly_linked_cap_above_index_range() {
+    let _ = DirectSinglyLinkedList16::<u8, 70_000>::default();
+}
+
+#[test]
+fn linked_new_cap_above_index_range() {
+    assert![DirectSinglyLinkedList8::<u8, 255>::new(0).is_err()];
+    assert![DirectSinglyLinkedList8::<u8, 300>::new(0).is_err()];
+    assert![DirectDoublyLinkedList8::<u8, 254>::new(0).is_ok()];
+    assert![DirectDoublyLinkedList8::<u8, 300>::new(0).is_err()];
+    assert![DirectDoublyLinkedList16::<u8, 70_000>::new(0).is_err()];
+}
+
+#[test]
+fn linked_stale_handles_after_clear() {
+    let mut s = DirectSinglyLinkedList8::<i32, 3>::default();
+    let sn = [s.push_front(1).unwrap(), s.push_front(2).unwrap()];
+    s.clear();
+    for n in sn {
+        assert_eq![Err(Error::EmptyNode), s.linked_data(n)];
+    }
+    let mut d = DirectDoublyLinkedList8::<i32, 3>::default();
+    let dn = [d.push_back(1).unwrap(), d.push_back(2).unwrap()];
+    d.clear();
+    for n in dn {
+        assert_eq![Err(Error::EmptyNode), d.linked_remove(n)];
+    }
+    assert_eq![0, d.len()];
+}
+
+#[test]
+fn singly_linked_conformance() {
+    singly_conformance(DirectSinglyLinkedList8::<i32, 4>::default());
+    singly_conformance(DirectSinglyLinkedList16::<i32, 4>::default());
+    singly_conformance(DirectSinglyLinkedList32::<i32, 4>::default());
+    #[cfg(feature = "alloc")]
+    {
+        singly_conformance(BoxedSinglyLinkedList8::<i32, 4>::default());
+        singly_conformance(BoxedSinglyLinkedList16::<i32, 4>::default());
+        singly_conformance(BoxedSinglyLinkedList32::<i32, 4>::default());
+    }
+}
+
+#[test]
+fn doubly_linked_conformance() {
+    doubly_conformance(DirectDoublyLinkedList8::<i32, 4>::default());
+    doubly_conformance(DirectDoublyLinkedList16::<i32, 4>::default());
+    doubly_conformance(DirectDoublyLinkedList32::<i32, 4>::default());
+    #[cfg(feature = "alloc")]
+    {
+        doubly_conformance(BoxedDoublyLinkedList8::<i32, 4>::default());
+        doubly_conformance(BoxedDoublyLinkedList16::<i32, 4>::default());
+        doubly_conformance(BoxedDoublyLinkedList32::<i32, 4>::default());
+    }
+}
+
+/* relinking */
+
+#[test]
+fn doubly_linked_sort_relinks() {
+    let mut list = DirectDoublyLinkedList16::<(u8, char), 10>::default();
+    // scatter the nodes through the array
+    for (i, c) in "abcdefghij".chars().enumerate() {
+        list.push_back(((i as u8 * 7) % 4, c)).unwrap();
+    }
+    let _ = list.pop_front();
+    let _ = list.pop_back();
+    list.push_front((1, 'k')).unwrap();
+    let front = list.cursor_front().index();
+
+    list.sort_by_key(|e| e.0);
+    assert![list.iter_front().eq(&[
+        (0, 'e'),
+        (0, 'i'),
+        (1, 'k'),
+        (1, 'd'),
+        (1, 'h'),
+        (2, 'c'),
+        (2, 'g'),
+        (3, 'b'),
+        (3, 'f')
+    ])];
+    assert![list.iter_front().rev().eq(&[
+        (3, 'f'),
+        (3, 'b'),
+        (2, 'g'),
+        (2, 'c'),
+        (1, 'h'),
+        (1, 'd'),
+        (1, 'k'),
+        (0, 'i'),
+        (0, 'e')
+    ])];
+    // the payloads are not moved
+    assert_eq![Ok(&(1, 'k')), list.linked_data(front)];
+
+    list.reverse();
+    assert_eq![Ok(&(3, 'f')), list.front()];
+    assert_eq![Ok(&(0, 'e')), list.back()];
+    list.dedup_by(|a, b| a.0 == b.0);
+    assert![list
+        .iter_front()
+        .eq(&[(3, 'f'), (2, 'g'), (1, 'h'), (0, 'i')])];
+    assert![list
+        .iter_front()
+        .rev()
+        .eq(&[(0, 'i'), (1, 'h'), (2, 'g'), (3, 'f')])];
+    list.retain(|e| e.0 % 2 == 0);
+    assert![list.iter_front().eq(&[(2, 'g'), (0, 'i')])];
+    assert![list.iter_front().rev().eq(&[(0, 'i'), (2, 'g')])];
+    assert_eq![8, list.remaining_capacity()];
+}
+
+#[test]
+fn singly_linked_sort_relinks() {
+    let mut list = DirectSinglyLinkedList8::<i32, 9>::from([5, 1, 4, 1, 5, 9, 2, 6, 5]);
+    list.sort_by(|a, b| b.cmp(a));
+    assert![list.iter_front().eq(&[9, 6, 5, 5, 5, 4, 2, 1, 1])];
+    assert_eq![Ok(&1), list.back()];
+    list.dedup_by(|a, b| a == b);
+    assert![list.iter_front().eq(&[9, 6, 5, 4, 2, 1])];
+    list.reverse();
+    assert_eq![Ok(&9), list.back()];
+    list.retain(|e| *e > 3);
+    assert![list.iter_front().eq(&[4, 5, 6, 9])];
+    assert_eq![Ok(&9), list.back()];
+
+    // the freed nodes are reused
+    for e in 10..15 {
+        list.push_back(e).unwrap();
+    }
+    assert![list.is_full()];
+    assert![list.iter_front().eq(&[4, 5, 6, 9, 10, 11, 12, 13, 14])];
+}
+
+#[test]
+fn linked_append_merge() {
+    let mut list = DirectDoublyLinkedList8::<i32, 6>::from([1, 3]);
+    let mut other = DirectDoublyLinkedList8::<i32, 4>::from([0, 2, 4, 5]);
+    list.merge_sorted(&mut other).unwrap();
+    assert![other.is_empty()];
+    assert![list.iter_front().eq(&[0, 1, 2, 3, 4, 5])];
+    assert![list.iter_front().rev().eq(&[5, 4, 3, 2, 1, 0])];
+
+    let mut other = DirectDoublyLinkedList8::<i32, 4>::from([6]);
+    assert_eq![Err(Error::NotEnoughSpace(Some(1))), list.append(&mut other)];
+    assert_eq![1, other.len()];
+
+    let mut list = DirectSinglyLinkedList8::<i32, 6>::from([1, 2]);
+    let mut other = DirectSinglyLinkedList8::<i32, 4>::from([3, 4]);
+    list.append(&mut other).unwrap();
+    list.push_back(5).unwrap();
+    assert![list.iter_front().eq(&[1, 2, 3, 4, 5])];
+}
+
+#[test]
+fn doubly_linked_compact() {
+    let mut list = DirectDoublyLinkedList16::<u32, 8>::default();
+    let mut handles = [NonMaxIndex16::none(); 8];
+    for (i, h) in handles.iter_mut().enumerate() {
+        *h = list.push_front(i as u32).unwrap();
+    }
+    // free some slots in the middle
+    list.cursor_front_mut().remove_current().unwrap();
+    let mut c = list.cursor_back_mut();
+    c.move_prev();
+    c.remove_current().unwrap();
+    list.sort_by(|a, b| (a % 3).cmp(&(b % 3)));
+    let order = [6, 3, 0, 4, 5, 2];
+    assert![list.iter_front().eq(&order)];
+    assert![list.fragmentation() > 0.0];
 
+    let remap = list.compact();
+    assert_eq![0.0, list.fragmentation()];
+    assert![list.iter_front().eq(&order)];
+    assert![list.iter_front().rev().eq(order.iter().rev())];
+    // the handles are updated with the remapping table
+    for (i, value) in order.iter().enumerate() {
+        let handle = remap[handles[*value as usize].as_usize()];
+        assert_eq![Some(i as u16), handle.get()];
+        assert_eq![Ok(value), list.linked_data(handle)];
+    }
+    assert![remap[handles[7].as_usize()].is_none()];
+    assert![remap[handles[1].as_usize()].is_none()];
 
+    // the free slots are reused after the nodes
+    assert_eq![Ok(6.into()), list.push_back(8)];
+    assert_eq![Ok(7.into()), list.push_front(9)];
+    assert_eq![Ok(&9), list.front()];
+    assert_eq![Ok(&8), list.back()];
 
+    // an empty list
+    let mut list = DirectDoublyLinkedList8::<u8, 3>::from([1, 2]);
+    list.clear();
+    assert![list.compact().iter().all(|i| i.is_none())];
+    assert_eq![Ok(0.into()), list.push_back(3)];
 }
@@ -7,11 +7,48 @@ pub mod array;
 pub mod bit_array;
 pub mod deque;
 pub mod linked;
+pub mod nullable;
+pub mod priority;
 pub mod queue;
+pub mod ring;
 pub mod stack;
+pub mod vector;
 
 pub use all::*;
 pub(crate) mod all {
     #[doc(inline)]
-    pub use super::{array::all::*, bit_array::all::*, deque::*, linked::*, queue::*, stack::*};
+    pub use super::{
+        array::all::*, bit_array::all::*, deque::*, linked::*, nullable::all::*, priority::*,
+        queue::*, ring::*, stack::*, vector::*,
+    };
+}
+
+/* helpers */
+
+/// Returns the `(start, end)` indices of a `range` over `len` elements.
+///
+/// # Errors
+/// Errors if the range is out of bounds or decreasing.
+pub(crate) fn range_bounds<R: core::ops::RangeBounds<usize>>(
+    range: &R,
+    len: usize,
+) -> crate::error::LadataResult<(usize, usize)> {
+    use {crate::error::LadataError as Error, core::ops::Bound};
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e.saturating_add(1),
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    if end > len {
+        Err(Error::IndexOutOfBounds(end))
+    } else if start > end {
+        Err(Error::IndexOutOfBounds(start))
+    } else {
+        Ok((start, end))
+    }
 }
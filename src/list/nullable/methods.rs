@@ -0,0 +1,407 @@
+// ladata::list::nullable::methods
+//
+//! Methods for the nullable lists.
+//
+
+use super::{FillStrategy, Nullable, Validity};
+use crate::{
+    error::{LadataError as Error, LadataResult as Result},
+    list::{Array, BitArray},
+    mem::Storage,
+};
+
+#[cfg(feature = "alloc")]
+use {super::NullableVec, crate::list::DirectBitArray64, alloc::vec::Vec};
+
+/* helpers shared by all nullable lists */
+
+/// Returns the element at `index`, or `None` if it's null.
+#[inline]
+fn get<'a, T, V: Validity>(data: &'a [T], validity: &V, index: usize) -> Result<Option<&'a T>> {
+    let element = data.get(index).ok_or(Error::IndexOutOfBounds(index))?;
+    Ok(validity.is_valid(index).then_some(element))
+}
+
+/// Returns the exclusive element at `index`, or `None` if it's null.
+#[inline]
+fn get_mut<'a, T, V: Validity>(
+    data: &'a mut [T],
+    validity: &V,
+    index: usize,
+) -> Result<Option<&'a mut T>> {
+    let element = data.get_mut(index).ok_or(Error::IndexOutOfBounds(index))?;
+    Ok(validity.is_valid(index).then_some(element))
+}
+
+/// Sets the element at `index`, or marks it as null.
+#[inline]
+fn set<T, V: Validity>(
+    data: &mut [T],
+    validity: &mut V,
+    index: usize,
+    element: Option<T>,
+) -> Result<()> {
+    let slot = data.get_mut(index).ok_or(Error::IndexOutOfBounds(index))?;
+    validity.set_valid(index, element.is_some());
+    if let Some(element) = element {
+        *slot = element;
+    }
+    Ok(())
+}
+
+/// Counts the null elements.
+#[inline]
+fn null_count<V: Validity>(len: usize, validity: &V) -> usize {
+    (0..len).filter(|i| !validity.is_valid(*i)).count()
+}
+
+/// Replaces the null elements following the `strategy`,
+/// returning the number of elements replaced.
+fn fill_nulls<T: Clone, V: Validity>(
+    data: &mut [T],
+    validity: &mut V,
+    strategy: FillStrategy<T>,
+) -> usize {
+    let mut filled = 0;
+    match strategy {
+        FillStrategy::Constant(value) => {
+            for (i, element) in data.iter_mut().enumerate() {
+                if !validity.is_valid(i) {
+                    *element = value.clone();
+                    validity.set_valid(i, true);
+                    filled += 1;
+                }
+            }
+        }
+        FillStrategy::Forward => {
+            let mut last = None;
+            for i in 0..data.len() {
+                if validity.is_valid(i) {
+                    last = Some(i);
+                } else if let Some(last) = last {
+                    data[i] = data[last].clone();
+                    validity.set_valid(i, true);
+                    filled += 1;
+                }
+            }
+        }
+        FillStrategy::Backward => {
+            let mut next = None;
+            for i in (0..data.len()).rev() {
+                if validity.is_valid(i) {
+                    next = Some(i);
+                } else if let Some(next) = next {
+                    data[i] = data[next].clone();
+                    validity.set_valid(i, true);
+                    filled += 1;
+                }
+            }
+        }
+    }
+    filled
+}
+
+/* Nullable */
+
+/// # Constructors
+impl<T, S: Storage, const LEN: usize, const BYTECAP: usize> Nullable<T, S, LEN, BYTECAP> {
+    /// Returns a new nullable array from the given `data`, with all the elements valid.
+    ///
+    /// # Errors
+    /// Returns [`DimensionMismatch`][Error::DimensionMismatch]
+    /// if `LEN > BYTECAP * 8`.
+    #[inline]
+    pub fn new(data: Array<T, S, LEN>) -> Result<Self> {
+        Ok(Self {
+            data,
+            validity: BitArray::new_oned()?,
+        })
+    }
+
+    /// Returns a new nullable array from the given `data` and `validity` mask.
+    #[inline]
+    pub fn with_validity(data: Array<T, S, LEN>, validity: BitArray<S, LEN, BYTECAP>) -> Self {
+        Self { data, validity }
+    }
+
+    /// Returns the data and the validity mask.
+    #[inline]
+    pub fn into_parts(self) -> (Array<T, S, LEN>, BitArray<S, LEN, BYTECAP>) {
+        (self.data, self.validity)
+    }
+}
+
+/// # Queries
+impl<T, S: Storage, const LEN: usize, const BYTECAP: usize> Nullable<T, S, LEN, BYTECAP> {
+    /// Returns the number of elements, including the nulls.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        LEN
+    }
+
+    /// Returns `true` if there are no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        LEN == 0
+    }
+
+    /// Returns the number of null elements.
+    #[inline]
+    pub fn null_count(&self) -> usize {
+        null_count(LEN, &self.validity)
+    }
+
+    /// Returns `true` if the element at `index` is null.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= LEN`.
+    #[inline]
+    pub fn is_null(&self, index: usize) -> Result<bool> {
+        self.validity.get_bit(index).map(|valid| !valid)
+    }
+
+    /// Returns a shared reference to the underlying data, including the nulls.
+    #[inline]
+    pub fn data(&self) -> &Array<T, S, LEN> {
+        &self.data
+    }
+
+    /// Returns a shared reference to the validity mask.
+    #[inline]
+    pub fn validity(&self) -> &BitArray<S, LEN, BYTECAP> {
+        &self.validity
+    }
+
+    /// Returns an iterator over the elements, yielding `None` for the nulls.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&T>> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, element)| self.validity.is_valid(i).then_some(element))
+    }
+}
+
+/// # Get & set
+impl<T, S: Storage, const LEN: usize, const BYTECAP: usize> Nullable<T, S, LEN, BYTECAP> {
+    /// Returns a shared reference to the element at `index`, or `None` if it's null.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= LEN`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Result<Option<&T>> {
+        get(&self.data, &self.validity, index)
+    }
+
+    /// Returns an exclusive reference to the element at `index`, or `None` if it's null.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= LEN`.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Result<Option<&mut T>> {
+        get_mut(&mut self.data, &self.validity, index)
+    }
+
+    /// Sets the element at `index`, or marks it as null if `element` is `None`.
+    ///
+    /// The value of a null element is left unchanged.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= LEN`.
+    #[inline]
+    pub fn set(&mut self, index: usize, element: Option<T>) -> Result<()> {
+        set(&mut self.data, &mut self.validity, index, element)
+    }
+}
+
+/// # Fill
+impl<T: Clone, S: Storage, const LEN: usize, const BYTECAP: usize> Nullable<T, S, LEN, BYTECAP> {
+    /// Replaces the null elements following the given `strategy`,
+    /// and returns the number of elements replaced.
+    #[inline]
+    pub fn fill_nulls(&mut self, strategy: FillStrategy<T>) -> usize {
+        fill_nulls(&mut self.data, &mut self.validity, strategy)
+    }
+}
+
+/* NullableVec */
+
+/// # Constructors
+#[cfg(feature = "alloc")]
+impl<T> NullableVec<T> {
+    /// Returns a new empty nullable vector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            validity: Vec::new(),
+        }
+    }
+
+    /// Returns a new nullable vector from the given `data`, with all the elements valid.
+    pub fn from_vec(data: Vec<T>) -> Self {
+        let chunks = (data.len() + 63) / 64;
+        let mut validity = Vec::with_capacity(chunks);
+        validity.resize_with(chunks, DirectBitArray64::new_oned_unchecked);
+        Self { data, validity }
+    }
+
+    /// Returns the data and the validity mask, in chunks of 64 bits.
+    #[inline]
+    pub fn into_parts(self) -> (Vec<T>, Vec<DirectBitArray64>) {
+        (self.data, self.validity)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Default for NullableVec<T> {
+    /// Returns a new empty nullable vector.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # Queries
+#[cfg(feature = "alloc")]
+impl<T> NullableVec<T> {
+    /// Returns the number of elements, including the nulls.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the number of null elements.
+    #[inline]
+    pub fn null_count(&self) -> usize {
+        null_count(self.len(), &self.validity)
+    }
+
+    /// Returns `true` if the element at `index` is null.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= len()`.
+    #[inline]
+    pub fn is_null(&self, index: usize) -> Result<bool> {
+        if index < self.len() {
+            Ok(!self.validity.is_valid(index))
+        } else {
+            Err(Error::IndexOutOfBounds(index))
+        }
+    }
+
+    /// Returns a shared slice of the underlying data, including the nulls.
+    #[inline]
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns an iterator over the elements, yielding `None` for the nulls.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&T>> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, element)| self.validity.is_valid(i).then_some(element))
+    }
+}
+
+/// # Get & set
+#[cfg(feature = "alloc")]
+impl<T> NullableVec<T> {
+    /// Returns a shared reference to the element at `index`, or `None` if it's null.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= len()`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Result<Option<&T>> {
+        get(&self.data, &self.validity, index)
+    }
+
+    /// Returns an exclusive reference to the element at `index`, or `None` if it's null.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= len()`.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Result<Option<&mut T>> {
+        get_mut(&mut self.data, &self.validity, index)
+    }
+
+    /// Sets the element at `index`, or marks it as null if `element` is `None`.
+    ///
+    /// The value of a null element is left unchanged.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= len()`.
+    #[inline]
+    pub fn set(&mut self, index: usize, element: Option<T>) -> Result<()> {
+        set(&mut self.data, &mut self.validity, index, element)
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is empty.
+    ///
+    /// A removed null element is returned as `Some(None)`.
+    pub fn pop(&mut self) -> Option<Option<T>> {
+        let element = self.data.pop()?;
+        let index = self.data.len();
+        let valid = self.validity.is_valid(index);
+        if index % 64 == 0 {
+            self.validity.pop();
+        }
+        Some(valid.then_some(element))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Default> NullableVec<T> {
+    /// Appends an `element` at the end, or a null element if it's `None`.
+    ///
+    /// Null elements are stored as `T::default()`.
+    pub fn push(&mut self, element: Option<T>) {
+        let index = self.data.len();
+        if index % 64 == 0 {
+            self.validity.push(DirectBitArray64::new_zeroed_unchecked());
+        }
+        self.validity.set_valid(index, element.is_some());
+        self.data.push(element.unwrap_or_default());
+    }
+}
+
+/// # Fill
+#[cfg(feature = "alloc")]
+impl<T: Clone> NullableVec<T> {
+    /// Replaces the null elements following the given `strategy`,
+    /// and returns the number of elements replaced.
+    #[inline]
+    pub fn fill_nulls(&mut self, strategy: FillStrategy<T>) -> usize {
+        fill_nulls(&mut self.data, &mut self.validity, strategy)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Default> FromIterator<Option<T>> for NullableVec<T> {
+    fn from_iter<I: IntoIterator<Item = Option<T>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        for element in iter {
+            vec.push(element);
+        }
+        vec
+    }
+}
+
+/// # Conversions
+#[cfg(feature = "alloc")]
+impl<T> NullableVec<T> {
+    /// Returns a vector of the elements, with `None` for the nulls.
+    pub fn into_vec(self) -> Vec<Option<T>> {
+        let validity = self.validity;
+        self.data
+            .into_iter()
+            .enumerate()
+            .map(|(i, element)| validity.is_valid(i).then_some(element))
+            .collect()
+    }
+}
@@ -0,0 +1,148 @@
+// ladata::list::nullable
+//
+//! Nullable lists pair a list of elements with a validity mask,
+//! in order to represent missing values.
+//!
+//! <https://en.wikipedia.org/wiki/Nullable_type>
+//
+
+use crate::{
+    list::{Array, BitArray},
+    mem::Storage,
+};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{list::DirectBitArray64, mem::Boxed},
+    alloc::vec::Vec,
+};
+
+mod methods;
+
+#[cfg(test)]
+mod tests;
+
+/// A nullable array, backed by an [`Array`] of elements
+/// and a [`BitArray`] validity mask.
+///
+/// A cleared bit in the mask marks the element at the same index as null,
+/// in which case its value is ignored.
+///
+/// `BYTECAP` must be big enough to hold `LEN` bits.
+///
+/// # Examples
+/// ```
+/// use ladata::all::{DataUnit8bitCopy as U, DirectArray, DirectNullable, FillStrategy};
+/// # fn main() -> ladata::error::LadataResult<()> {
+///
+/// let mut n = DirectNullable::<U, 4, 1>::new(DirectArray::new([U::U8(1); 4]))?;
+/// n.set(1, None)?;
+/// n.set(2, None)?;
+/// n.set(3, Some(U::I8(-4)))?;
+/// assert_eq![2, n.null_count()];
+/// assert_eq![None, n.get(1)?];
+///
+/// n.fill_nulls(FillStrategy::Forward);
+/// assert_eq![vec![Some(&U::U8(1)); 3], n.iter().take(3).collect::<Vec<_>>()];
+/// assert_eq![0, n.null_count()];
+/// # Ok(()) }
+/// ```
+pub struct Nullable<T, S: Storage, const LEN: usize, const BYTECAP: usize> {
+    data: Array<T, S, LEN>,
+    validity: BitArray<S, LEN, BYTECAP>,
+}
+
+/// A [`Nullable`] stored in the stack.
+pub type DirectNullable<T, const LEN: usize, const BYTECAP: usize> = Nullable<T, (), LEN, BYTECAP>;
+
+/// A [`Nullable`] stored in the heap.
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub type BoxedNullable<T, const LEN: usize, const BYTECAP: usize> =
+    Nullable<T, Boxed, LEN, BYTECAP>;
+
+/// A nullable vector, backed by a [`Vec`] of elements
+/// and a validity mask of [`BitArray`]s.
+///
+/// A cleared bit in the mask marks the element at the same index as null,
+/// in which case its value is ignored.
+///
+/// # Examples
+/// ```
+/// use ladata::all::{DataUnit16bitCopy as U, FillStrategy, NullableVec};
+/// # fn main() -> ladata::error::LadataResult<()> {
+///
+/// let mut n = NullableVec::new();
+/// n.push(Some(U::I16(3)));
+/// n.push(None);
+/// n.push(Some(U::U8(2)));
+/// assert_eq![1, n.null_count()];
+///
+/// n.fill_nulls(FillStrategy::Constant(U::Bool(false)));
+/// assert_eq![Some(&U::Bool(false)), n.get(1)?];
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct NullableVec<T> {
+    data: Vec<T>,
+    validity: Vec<DirectBitArray64>,
+}
+
+/// The strategy for replacing the null elements of a nullable list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillStrategy<T> {
+    /// Replaces every null with the given value.
+    Constant(T),
+
+    /// Replaces every null with the previous valid element.
+    ///
+    /// Leading nulls are left unchanged.
+    Forward,
+
+    /// Replaces every null with the next valid element.
+    ///
+    /// Trailing nulls are left unchanged.
+    Backward,
+}
+
+/// Common access to the validity masks.
+trait Validity {
+    /// Returns `true` if the element at `index` is valid.
+    fn is_valid(&self, index: usize) -> bool;
+
+    /// Marks the element at `index` as valid or null.
+    fn set_valid(&mut self, index: usize, valid: bool);
+}
+
+impl<S: Storage, const LEN: usize, const BYTECAP: usize> Validity for BitArray<S, LEN, BYTECAP> {
+    #[inline]
+    fn is_valid(&self, index: usize) -> bool {
+        self.get_bit_unchecked(index)
+    }
+    #[inline]
+    fn set_valid(&mut self, index: usize, valid: bool) {
+        self.set_bit_unchecked(index, valid)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Validity for Vec<DirectBitArray64> {
+    #[inline]
+    fn is_valid(&self, index: usize) -> bool {
+        self[index / 64].get_bit_unchecked(index % 64)
+    }
+    #[inline]
+    fn set_valid(&mut self, index: usize, valid: bool) {
+        self[index / 64].set_bit_unchecked(index % 64, valid)
+    }
+}
+
+pub(crate) mod all {
+    #[doc(inline)]
+    pub use super::{DirectNullable, FillStrategy, Nullable};
+
+    #[doc(inline)]
+    #[cfg(feature = "alloc")]
+    pub use super::{BoxedNullable, NullableVec};
+}
@@ -0,0 +1,106 @@
+// ladata::list::nullable::tests
+//!
+//
+
+use super::*;
+use crate::{
+    error::LadataError as Error,
+    list::DirectArray,
+    unit::{DataUnit8bitCopy as U, DataUnit8bitCopy},
+};
+
+type N4 = DirectNullable<DataUnit8bitCopy, 4, 1>;
+
+fn nullable(elements: [Option<U>; 4]) -> N4 {
+    let mut n = N4::new(DirectArray::new([U::default(); 4])).unwrap();
+    for (i, e) in elements.into_iter().enumerate() {
+        n.set(i, e).unwrap();
+    }
+    n
+}
+
+#[test]
+fn get_set() {
+    let mut n = nullable([Some(U::U8(1)), None, Some(U::I8(-1)), None]);
+    assert_eq![4, n.len()];
+    assert_eq![2, n.null_count()];
+    assert_eq![Ok(Some(&U::U8(1))), n.get(0)];
+    assert_eq![Ok(None), n.get(1)];
+    assert_eq![Ok(true), n.is_null(3)];
+    assert_eq![Err(Error::IndexOutOfBounds(4)), n.get(4)];
+    assert_eq![Err(Error::IndexOutOfBounds(4)), n.set(4, None)];
+
+    // setting a null keeps the previous value in the data
+    n.set(0, None).unwrap();
+    assert_eq![None, n.get(0).unwrap()];
+    assert_eq![U::U8(1), n.data()[0]];
+
+    if let Some(e) = n.get_mut(2).unwrap() {
+        *e = U::Bool(true);
+    }
+    assert_eq![
+        [None, None, Some(&U::Bool(true)), None],
+        n.iter().collect::<Vec<_>>()[..]
+    ];
+}
+
+#[test]
+fn too_small_mask() {
+    let n = DirectNullable::<u8, 9, 1>::new(DirectArray::new([0; 9]));
+    assert![matches![n, Err(Error::DimensionMismatch)]];
+}
+
+#[test]
+fn fill_nulls() {
+    let (a, b) = (Some(U::U8(1)), Some(U::U8(2)));
+
+    let mut n = nullable([None, a, None, b]);
+    assert_eq![2, n.fill_nulls(FillStrategy::Constant(U::Bool(false)))];
+    let f = Some(&U::Bool(false));
+    assert_eq![
+        [f, a.as_ref(), f, b.as_ref()],
+        n.iter().collect::<Vec<_>>()[..]
+    ];
+
+    // leading nulls are left unchanged
+    let mut n = nullable([None, a, None, b]);
+    assert_eq![1, n.fill_nulls(FillStrategy::Forward)];
+    assert_eq![
+        [None, a.as_ref(), a.as_ref(), b.as_ref()],
+        n.iter().collect::<Vec<_>>()[..]
+    ];
+
+    // trailing nulls are left unchanged
+    let mut n = nullable([a, None, b, None]);
+    assert_eq![1, n.fill_nulls(FillStrategy::Backward)];
+    assert_eq![
+        [a.as_ref(), b.as_ref(), b.as_ref(), None],
+        n.iter().collect::<Vec<_>>()[..]
+    ];
+    assert_eq![1, n.null_count()];
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn nullable_vec() {
+    let mut n: NullableVec<u16> = (0..130).map(|i| (i % 3 != 0).then_some(i)).collect();
+    assert_eq![130, n.len()];
+    assert_eq![44, n.null_count()];
+    assert_eq![Ok(None), n.get(129)];
+    assert_eq![Ok(Some(&128)), n.get(128)];
+
+    assert_eq![Some(None), n.pop()];
+    assert_eq![Some(Some(128)), n.pop()];
+    assert_eq![128, n.len()];
+    assert_eq![43, n.null_count()];
+
+    assert_eq![43, n.fill_nulls(FillStrategy::Backward)];
+    assert_eq![Ok(Some(&1)), n.get(0)];
+    assert_eq![Ok(Some(&64)), n.get(63)];
+    assert_eq![Err(Error::IndexOutOfBounds(128)), n.set(128, None)];
+
+    n.set(127, None).unwrap();
+    let v = n.into_vec();
+    assert_eq![128, v.len()];
+    assert_eq![None, v[127]];
+}
@@ -0,0 +1,48 @@
+// ladata::list::priority::data
+//
+//! `DataPriorityQueue` abstract data type.
+//
+
+use super::{PriorityOrder, PriorityQueue, Storage};
+use crate::all::{DataCollection, LadataResult as Result};
+
+/// An abstract Priority Queue.
+pub trait DataPriorityQueue: DataCollection {
+    /// Inserts an element.
+    fn priority_push(&mut self, element: <Self as DataCollection>::Element) -> Result<()>;
+    /// Removes the element with the highest priority.
+    fn priority_pop(&mut self) -> Result<<Self as DataCollection>::Element>;
+    /// Returns a shared reference to the element with the highest priority.
+    fn priority_peek(&self) -> Result<&<Self as DataCollection>::Element>;
+}
+
+impl<T, S: Storage, const CAP: usize, O> DataCollection for PriorityQueue<T, S, CAP, O> {
+    type Element = T;
+
+    fn collection_is_empty(&self) -> Option<bool> {
+        Some(self.is_empty())
+    }
+    fn collection_is_full(&self) -> Option<bool> {
+        Some(self.is_full())
+    }
+    fn collection_capacity(&self) -> usize {
+        CAP
+    }
+    fn collection_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: Clone, S: Storage, const CAP: usize, O: PriorityOrder<T>> DataPriorityQueue
+    for PriorityQueue<T, S, CAP, O>
+{
+    fn priority_push(&mut self, element: T) -> Result<()> {
+        self.push(element).map(|_| ())
+    }
+    fn priority_pop(&mut self) -> Result<T> {
+        self.pop()
+    }
+    fn priority_peek(&self) -> Result<&T> {
+        self.peek()
+    }
+}
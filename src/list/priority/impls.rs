@@ -0,0 +1,75 @@
+// ladata::list::priority::impls
+//
+//! Priority queue trait implementations.
+//
+
+use core::fmt;
+
+use super::{Array, PriorityQueue, Storage};
+
+#[cfg(feature = "alloc")]
+use crate::mem::Boxed;
+
+// T:Clone
+impl<T: Clone, S: Storage, const CAP: usize, O: Clone> Clone for PriorityQueue<T, S, CAP, O>
+where
+    S::Stored<[T; CAP]>: Clone,
+    S::Stored<[usize; CAP]>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array.clone(),
+            heap: self.heap.clone(),
+            pos: self.pos.clone(),
+            len: self.len,
+            order: self.order.clone(),
+        }
+    }
+}
+
+// T:Debug
+impl<T: fmt::Debug, S: Storage, const CAP: usize, O> fmt::Debug for PriorityQueue<T, S, CAP, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct(stringify![PriorityQueue]);
+        debug.field("CAP", &CAP).field("len", &self.len);
+
+        if self.len <= 6 {
+            let mut elements = [None; 6];
+            for (e, &i) in elements.iter_mut().zip(&self.heap[..self.len]) {
+                *e = Some(&self.array[i]);
+            }
+            debug.field("heap", &&elements[..self.len]);
+        } else {
+            // IMPROVE: show first 3 and last 3
+            debug.field("heap { ... }", &());
+        }
+        debug.finish()
+    }
+}
+
+// S:() + T:Default
+impl<T: Default, const CAP: usize, O: Default> Default for PriorityQueue<T, (), CAP, O> {
+    /// Returns an empty priority queue, allocated in the stack,
+    /// using the default value to fill the remaining free data.
+    fn default() -> Self {
+        Self::from_parts(Array::default(), 0, O::default())
+    }
+}
+
+// S:Boxed + T:Default
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize, O: Default> Default for PriorityQueue<T, Boxed, CAP, O> {
+    /// Returns an empty priority queue, allocated in the heap,
+    /// using the default value to fill the remaining free data.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedPriorityQueue;
+    ///
+    /// let q = BoxedPriorityQueue::<i32, 100>::default();
+    /// ```
+    fn default() -> Self {
+        Self::from_parts(Array::default(), 0, O::default())
+    }
+}
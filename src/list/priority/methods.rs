@@ -0,0 +1,531 @@
+// ladata::list::priority::methods
+//
+//! Priority queues.
+//
+
+use core::mem;
+
+use super::{PriorityOrder, PriorityQueue};
+use crate::{
+    error::{LadataError as Error, LadataResult as Result},
+    list::Array,
+    mem::Storage,
+    misc::NonMaxIndexUsize,
+};
+
+#[cfg(feature = "alloc")]
+use crate::mem::Boxed;
+
+// `S:() + T:Clone`
+impl<T: Clone, const CAP: usize, O> PriorityQueue<T, (), CAP, O> {
+    /// Returns an empty priority queue, allocated in the stack,
+    /// using `element` to fill the remaining free data.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectPriorityQueue;
+    ///
+    /// let q = DirectPriorityQueue::<_, 16>::new(0);
+    /// ```
+    pub fn new(element: T) -> Self
+    where
+        O: Default,
+    {
+        Self::with_order(element, O::default())
+    }
+
+    /// Returns an empty priority queue, allocated in the stack,
+    /// using `element` to fill the remaining free data,
+    /// and the given `order` of priorities.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectPriorityQueue;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let by_key = |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0);
+    /// let mut q = DirectPriorityQueue::<_, 4, _>::with_order((0, 'a'), by_key);
+    /// q.push((1, 'b'))?;
+    /// q.push((3, 'c'))?;
+    /// assert_eq![(3, 'c'), q.pop()?];
+    /// # Ok(()) }
+    /// ```
+    pub fn with_order(element: T, order: O) -> Self {
+        Self::from_parts(Array::<T, (), CAP>::with(element), 0, order)
+    }
+}
+
+// `S:Boxed + T:Clone`
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Clone, const CAP: usize, O> PriorityQueue<T, Boxed, CAP, O> {
+    /// Returns an empty priority queue, allocated in the heap,
+    /// using `element` to fill the remaining free data.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedPriorityQueue;
+    ///
+    /// let q = BoxedPriorityQueue::<_, 100>::new(0);
+    /// ```
+    pub fn new(element: T) -> Self
+    where
+        O: Default,
+    {
+        Self::with_order(element, O::default())
+    }
+
+    /// Returns an empty priority queue, allocated in the heap,
+    /// using `element` to fill the remaining free data,
+    /// and the given `order` of priorities.
+    pub fn with_order(element: T, order: O) -> Self {
+        Self::from_parts(Array::<T, Boxed, CAP>::with(element), 0, order)
+    }
+}
+
+// ``
+impl<T, S: Storage, const CAP: usize, O> PriorityQueue<T, S, CAP, O> {
+    // Returns a queue where the first `len` elements of `array` are queued,
+    // in heap order.
+    pub(super) fn from_parts(array: Array<T, S, CAP>, len: usize, order: O) -> Self {
+        Self {
+            array,
+            heap: Array::new(core::array::from_fn(|i| i)),
+            pos: Array::new(core::array::from_fn(|i| i)),
+            len,
+            order,
+        }
+    }
+
+    /// Returns the number of queued elements.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the queue is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectPriorityQueue;
+    ///
+    /// let q = DirectPriorityQueue::<i32, 8>::default();
+    /// assert![q.is_empty()];
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the queue is full.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::{Array, DirectPriorityQueue};
+    ///
+    /// let q = DirectPriorityQueue::<_, 3>::heapify(Array::from([1, 2, 3]));
+    /// assert![q.is_full()];
+    /// ```
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len() == CAP
+    }
+
+    /// Returns the queue's total capacity.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Returns the queue's remaining capacity.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        CAP - self.len()
+    }
+
+    /// Returns a shared reference to the order of the priorities.
+    #[inline]
+    pub const fn order(&self) -> &O {
+        &self.order
+    }
+
+    /// Clears the queue.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::{Array, DirectPriorityQueue};
+    ///
+    /// let mut q = DirectPriorityQueue::<_, 3>::heapify(Array::from([1, 2, 3]));
+    /// q.clear();
+    /// assert![q.is_empty()];
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns a shared reference to the queued element at `index`.
+    ///
+    /// # Errors
+    /// Errors if there's no queued element at `index`.
+    pub fn get(&self, index: NonMaxIndexUsize) -> Result<&T> {
+        let i = self.queued_index(index)?;
+        Ok(&self.array[i])
+    }
+
+    /// Returns an iterator over the queued elements and their indices,
+    /// in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (NonMaxIndexUsize, &T)> {
+        self.heap[..self.len]
+            .iter()
+            .map(|&i| (NonMaxIndexUsize::from(i), &self.array[i]))
+    }
+
+    // Returns the primitive `index` if it points to a queued element.
+    fn queued_index(&self, index: NonMaxIndexUsize) -> Result<usize> {
+        index
+            .get()
+            .filter(|&i| i < CAP && self.pos[i] < self.len)
+            .ok_or(Error::IndexOutOfBounds(index.as_usize()))
+    }
+
+    // Swaps the indices at the heap positions `a` and `b`.
+    #[inline]
+    fn swap_heap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.pos[self.heap[a]] = a;
+        self.pos[self.heap[b]] = b;
+    }
+}
+
+// `O:PriorityOrder`
+impl<T, S: Storage, const CAP: usize, O: PriorityOrder<T>> PriorityQueue<T, S, CAP, O> {
+    /// Returns a full queue from an `array`, ordering it as a heap in *O(n)*.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::{Array, DirectPriorityQueue, MinPriority};
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectPriorityQueue::<_, 4, MinPriority>::heapify(Array::from([4, 1, 3, 2]));
+    /// assert_eq![&1, q.peek()?];
+    /// # Ok(()) }
+    /// ```
+    pub fn heapify(array: Array<T, S, CAP>) -> Self
+    where
+        O: Default,
+    {
+        Self::heapify_with(array, O::default())
+    }
+
+    /// Returns a full queue from an `array`, with the given `order` of priorities,
+    /// ordering it as a heap in *O(n)*.
+    pub fn heapify_with(array: Array<T, S, CAP>, order: O) -> Self {
+        let mut q = Self::from_parts(array, CAP, order);
+        for p in (0..CAP / 2).rev() {
+            q.sift_down(p);
+        }
+        q
+    }
+
+    /// Inserts an element, returning its index.
+    ///
+    /// The index stays valid while the element remains queued.
+    ///
+    /// # Errors
+    /// Errors if the queue is full.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectPriorityQueue;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectPriorityQueue::<_, 2>::default();
+    /// let idx = q.push(3)?;
+    /// q.push(5)?;
+    /// assert![q.push(4).is_err()];
+    /// assert_eq![&3, q.get(idx)?];
+    /// # Ok(()) }
+    /// ```
+    pub fn push(&mut self, element: T) -> Result<NonMaxIndexUsize> {
+        if self.is_full() {
+            Err(Error::NotEnoughSpace(Some(1)))
+        } else {
+            let i = self.heap[self.len];
+            self.array[i] = element;
+            self.len += 1;
+            self.sift_up(self.len - 1);
+            Ok(NonMaxIndexUsize::from(i))
+        }
+    }
+
+    /// Returns a shared reference to the element with the highest priority.
+    ///
+    /// # Errors
+    /// Errors if the queue is empty.
+    #[inline]
+    pub fn peek(&self) -> Result<&T> {
+        if self.is_empty() {
+            Err(Error::NotEnoughElements(1))
+        } else {
+            Ok(&self.array[self.heap[0]])
+        }
+    }
+
+    /// Returns the index of the element with the highest priority.
+    ///
+    /// # Errors
+    /// Errors if the queue is empty.
+    #[inline]
+    pub fn peek_index(&self) -> Result<NonMaxIndexUsize> {
+        if self.is_empty() {
+            Err(Error::NotEnoughElements(1))
+        } else {
+            Ok(NonMaxIndexUsize::from(self.heap[0]))
+        }
+    }
+
+    /// Pushes an element and pops the element with the highest priority,
+    /// more efficiently than doing both operations separately.
+    ///
+    /// It works even if the queue is full, and returns the same `element`
+    /// if it has a priority that is not lower than the top element.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::{Array, DirectPriorityQueue};
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectPriorityQueue::<_, 2>::heapify(Array::from([3, 5]));
+    /// assert_eq![7, q.push_pop(7)];
+    /// assert_eq![5, q.push_pop(4)];
+    /// assert_eq![&4, q.peek()?];
+    /// # Ok(()) }
+    /// ```
+    pub fn push_pop(&mut self, element: T) -> T {
+        if !self.is_empty() && self.order.precedes(&self.array[self.heap[0]], &element) {
+            let top = mem::replace(&mut self.array[self.heap[0]], element);
+            self.sift_down(0);
+            top
+        } else {
+            element
+        }
+    }
+
+    /// Replaces the element with the highest priority, returning it.
+    ///
+    /// The new `element` keeps the index of the replaced one.
+    ///
+    /// # Errors
+    /// Errors if the queue is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::{Array, DirectPriorityQueue};
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectPriorityQueue::<_, 2>::heapify(Array::from([3, 5]));
+    /// assert_eq![5, q.replace_top(1)?];
+    /// assert_eq![&3, q.peek()?];
+    /// # Ok(()) }
+    /// ```
+    pub fn replace_top(&mut self, element: T) -> Result<T> {
+        if self.is_empty() {
+            Err(Error::NotEnoughElements(1))
+        } else {
+            let top = mem::replace(&mut self.array[self.heap[0]], element);
+            self.sift_down(0);
+            Ok(top)
+        }
+    }
+
+    /// Replaces the queued element at `index` with an `element` of
+    /// higher priority, returning the old one.
+    ///
+    /// The heap order is restored even if the new priority is lower.
+    ///
+    /// # Errors
+    /// Errors if there's no queued element at `index`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectPriorityQueue;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectPriorityQueue::<_, 4>::default();
+    /// q.push(5)?;
+    /// let idx = q.push(2)?;
+    /// assert_eq![2, q.decrease_key(idx, 8)?];
+    /// assert_eq![idx, q.peek_index()?];
+    /// # Ok(()) }
+    /// ```
+    pub fn decrease_key(&mut self, index: NonMaxIndexUsize, element: T) -> Result<T> {
+        let i = self.queued_index(index)?;
+        let old = mem::replace(&mut self.array[i], element);
+        self.sift_up(self.pos[i]);
+        self.sift_down(self.pos[i]);
+        Ok(old)
+    }
+
+    /// Returns the underlying array, with the queued elements at the
+    /// beginning, sorted from the highest to the lowest priority.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::{Array, DirectPriorityQueue};
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectPriorityQueue::<_, 5>::heapify(Array::from([2, 5, 1, 4, 3]));
+    /// q.pop()?;
+    /// assert_eq![&[4, 3, 2, 1], &q.into_sorted_array()[..4]];
+    /// # Ok(()) }
+    /// ```
+    pub fn into_sorted_array(mut self) -> Array<T, S, CAP> {
+        // pop all the indices, leaving them from the lowest to the highest priority
+        let len = self.len;
+        while self.len > 0 {
+            self.len -= 1;
+            self.swap_heap(0, self.len);
+            self.sift_down(0);
+        }
+        self.heap[..len].reverse();
+
+        // move each element to the position of its index in the heap
+        for start in 0..CAP {
+            let mut i = start;
+            while self.heap[i] != start {
+                let next = self.heap[i];
+                self.array.swap(i, next);
+                self.heap[i] = i;
+                i = next;
+            }
+            self.heap[i] = i;
+        }
+        self.array
+    }
+
+    // Returns `true` if the element at heap position `a` precedes the one at `b`.
+    #[inline]
+    fn precedes(&self, a: usize, b: usize) -> bool {
+        self.order
+            .precedes(&self.array[self.heap[a]], &self.array[self.heap[b]])
+    }
+
+    // Moves the element at heap position `p` up to its place.
+    fn sift_up(&mut self, mut p: usize) {
+        while p > 0 {
+            let parent = (p - 1) / 2;
+            if self.precedes(p, parent) {
+                self.swap_heap(p, parent);
+                p = parent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    // Moves the element at heap position `p` down to its place.
+    fn sift_down(&mut self, mut p: usize) {
+        loop {
+            let left = 2 * p + 1;
+            if left >= self.len {
+                break;
+            }
+            let child = if left + 1 < self.len && self.precedes(left + 1, left) {
+                left + 1
+            } else {
+                left
+            };
+            if self.precedes(child, p) {
+                self.swap_heap(child, p);
+                p = child;
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+// `T:Clone`
+impl<T: Clone, S: Storage, const CAP: usize, O: PriorityOrder<T>> PriorityQueue<T, S, CAP, O> {
+    /// Removes the element with the highest priority and returns it.
+    ///
+    /// # Errors
+    /// Errors if the queue is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::{Array, DirectPriorityQueue};
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectPriorityQueue::<_, 3>::heapify(Array::from([2, 3, 1]));
+    /// assert_eq![3, q.pop()?];
+    /// assert_eq![2, q.pop()?];
+    /// assert_eq![1, q.pop()?];
+    /// assert![q.pop().is_err()];
+    /// # Ok(()) }
+    /// ```
+    pub fn pop(&mut self) -> Result<T> {
+        if self.is_empty() {
+            Err(Error::NotEnoughElements(1))
+        } else {
+            let top = self.heap[0];
+            self.len -= 1;
+            self.swap_heap(0, self.len);
+            self.sift_down(0);
+            Ok(self.array[top].clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::list::{DirectPriorityQueue, MinPriority};
+
+    // checks the heap order and the private index bookkeeping
+    fn assert_heap<O: PriorityOrder<i32>>(q: &DirectPriorityQueue<i32, 8, O>) {
+        for p in 0..8 {
+            assert_eq![p, q.pos[q.heap[p]]];
+        }
+        for p in 1..q.len {
+            assert![!q.precedes(p, (p - 1) / 2)];
+        }
+    }
+
+    #[test]
+    fn heap_invariants() {
+        let mut q = DirectPriorityQueue::<i32, 8, MinPriority>::default();
+        let mut idx = [NonMaxIndexUsize::none(); 8];
+        for (i, e) in [5, 3, 8, 1, 9, 2, 7, 4].into_iter().enumerate() {
+            idx[i] = q.push(e).unwrap();
+            assert_heap(&q);
+        }
+        assert_eq![Ok(8), q.decrease_key(idx[2], 0)];
+        assert_heap(&q);
+        assert_eq![Ok(&0), q.peek()];
+        assert_eq![Ok(0), q.pop()];
+        assert_eq![Ok(1), q.pop()];
+        assert_heap(&q);
+
+        // popped indices are no longer valid, and get reused
+        assert_eq![Err(Error::IndexOutOfBounds(2)), q.get(idx[2])];
+        let reused = q.push(6).unwrap();
+        assert![reused == idx[2] || reused == idx[3]];
+        assert_eq![Ok(&9), q.get(idx[4])];
+        assert_heap(&q);
+
+        assert_eq![&[2, 3, 4, 5, 6, 7, 9], &q.into_sorted_array()[..7]];
+    }
+
+    #[test]
+    fn comparator() {
+        let by_len = |a: &&str, b: &&str| a.len().cmp(&b.len());
+        let mut q = DirectPriorityQueue::<_, 4, _>::with_order("", by_len);
+        q.push("ab").unwrap();
+        q.push("abcd").unwrap();
+        q.push("a").unwrap();
+        assert_eq![Ok("abcd"), q.pop()];
+        assert_eq![Ok("ab"), q.pop()];
+    }
+}
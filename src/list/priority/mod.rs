@@ -0,0 +1,103 @@
+// ladata::list::priority
+//
+//! Priority queues are lists for which removals are always made
+//! of the element with the highest priority.
+//!
+//! <https://en.wikipedia.org/wiki/Priority_queue>
+//
+
+use core::cmp::Ordering;
+
+use crate::{list::Array, mem::Storage};
+
+#[cfg(feature = "alloc")]
+use crate::mem::Boxed;
+
+mod data;
+mod impls;
+mod methods;
+
+pub use data::DataPriorityQueue;
+
+/// A priority queue, implemented as a binary heap backed by an [`Array`].
+///
+/// The order of the priorities is given by `O`, which can be either
+/// [`MaxPriority`], [`MinPriority`] or a comparator closure.
+///
+/// Each pushed element keeps the same slot in the array while it's queued,
+/// and its index can be used to change its priority with
+/// [`decrease_key`][Self::decrease_key].
+///
+/// # Examples
+/// ```
+/// use ladata::list::{DirectPriorityQueue, MinPriority};
+/// # fn main() -> ladata::error::LadataResult<()> {
+///
+/// let mut q = DirectPriorityQueue::<_, 8, MinPriority>::default();
+/// q.push(5)?;
+/// let idx = q.push(7)?;
+/// q.push(3)?;
+/// assert_eq![&3, q.peek()?];
+///
+/// q.decrease_key(idx, 1)?;
+/// assert_eq![1, q.pop()?];
+/// assert_eq![3, q.pop()?];
+/// # Ok(()) }
+/// ```
+pub struct PriorityQueue<T, S: Storage, const CAP: usize, O = MaxPriority> {
+    // the elements, in the slot of their index
+    pub(crate) array: Array<T, S, CAP>,
+    // the indices of the queued elements in heap order, followed by the free indices
+    pub(crate) heap: Array<usize, S, CAP>,
+    // the position in the heap of each index
+    pub(crate) pos: Array<usize, S, CAP>,
+    pub(crate) len: usize,
+    pub(crate) order: O,
+}
+
+/// A [`PriorityQueue`] stored in the stack.
+pub type DirectPriorityQueue<T, const CAP: usize, O = MaxPriority> = PriorityQueue<T, (), CAP, O>;
+
+/// A [`PriorityQueue`] stored in the heap.
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub type BoxedPriorityQueue<T, const CAP: usize, O = MaxPriority> = PriorityQueue<T, Boxed, CAP, O>;
+
+/* orders */
+
+/// The order of the priorities in a [`PriorityQueue`].
+///
+/// It's implemented for [`MaxPriority`], [`MinPriority`] and for comparator
+/// closures returning [`Greater`][Ordering::Greater] when the first argument
+/// has the highest priority.
+pub trait PriorityOrder<T> {
+    /// Returns `true` if `a` has a higher priority than `b`.
+    fn precedes(&self, a: &T, b: &T) -> bool;
+}
+
+/// Gives the highest priority to the greatest element.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MaxPriority;
+
+/// Gives the highest priority to the smallest element.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MinPriority;
+
+impl<T: Ord> PriorityOrder<T> for MaxPriority {
+    #[inline]
+    fn precedes(&self, a: &T, b: &T) -> bool {
+        a > b
+    }
+}
+impl<T: Ord> PriorityOrder<T> for MinPriority {
+    #[inline]
+    fn precedes(&self, a: &T, b: &T) -> bool {
+        a < b
+    }
+}
+impl<T, F: Fn(&T, &T) -> Ordering> PriorityOrder<T> for F {
+    #[inline]
+    fn precedes(&self, a: &T, b: &T) -> bool {
+        self(a, b) == Ordering::Greater
+    }
+}
@@ -5,11 +5,11 @@
 
 use core::fmt;
 
-use super::{Queue, Storage};
+use super::{Queue, QueueIter, QueueIterMut, Storage};
 use crate::list::{Array, Deque, Stack};
 
 #[cfg(feature = "alloc")]
-use crate::mem::Boxed;
+use {crate::mem::Boxed, alloc::vec};
 
 // T:Clone
 impl<T: Clone, S: Storage, const CAP: usize> Clone for Queue<T, S, CAP>
@@ -124,11 +124,10 @@ impl<T: Default, const CAP: usize> Default for Queue<T, Boxed, CAP> {
 
 /* From<> */
 
-impl<T: Default, I, const CAP: usize> From<I> for Queue<T, (), CAP>
-where
-    I: IntoIterator<Item = T>,
-{
-    /// Returns a queue filled with an iterator, in the stack.
+impl<T: Default, const CAP: usize, const N: usize> From<[T; N]> for Queue<T, (), CAP> {
+    /// Returns a queue filled with the elements of an array, in the stack.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
     ///
     /// # Examples
     /// ```
@@ -136,29 +135,58 @@ where
     ///
     /// let s: DirectQueue<_, 3> = [1, 2, 3].into();
     /// ```
-    fn from(iterator: I) -> Queue<T, (), CAP> {
-        let mut s = Queue::<T, (), CAP>::default();
-        let _ = s.extend(iterator);
-        s
+    fn from(array: [T; N]) -> Queue<T, (), CAP> {
+        array.into_iter().collect()
     }
 }
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
-impl<T: Default, I, const CAP: usize> From<I> for Queue<T, Boxed, CAP>
-where
-    I: IntoIterator<Item = T>,
-{
-    /// Returns a queue filled with an iterator, in the heap.
+impl<T: Default, const CAP: usize, const N: usize> From<[T; N]> for Queue<T, Boxed, CAP> {
+    /// Returns a queue filled with the elements of an array, in the heap.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
     ///
     /// # Examples
     /// ```
-    /// use ladata::all::BoxedQueue;
+    /// use ladata::list::BoxedQueue;
     ///
     /// let s: BoxedQueue<_, 3> = [1, 2, 3].into();
     /// ```
-    fn from(iterator: I) -> Queue<T, Boxed, CAP> {
-        let mut s = Queue::<T, Boxed, CAP>::default();
+    fn from(array: [T; N]) -> Queue<T, Boxed, CAP> {
+        array.into_iter().collect()
+    }
+}
+
+/* FromIterator */
+
+impl<T: Default, const CAP: usize> FromIterator<T> for Queue<T, (), CAP> {
+    /// Returns a queue filled with an iterator, in the stack.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectQueue;
+    ///
+    /// let s: DirectQueue<_, 3> = (1..=5).collect();
+    /// assert_eq![3, s.len()];
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut s = Self::default();
+        let _ = s.extend(iterator);
+        s
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize> FromIterator<T> for Queue<T, Boxed, CAP> {
+    /// Returns a queue filled with an iterator, in the heap.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut s = Self::default();
         let _ = s.extend(iterator);
         s
     }
@@ -186,3 +214,65 @@ impl<T, S: Storage, const CAP: usize> From<Stack<T, S, CAP>> for Queue<T, S, CAP
         }
     }
 }
+
+/* IntoIterator */
+
+impl<T, const CAP: usize> IntoIterator for Queue<T, (), CAP> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, CAP>;
+
+    /// Returns an owning iterator, from the front to the back.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectQueue;
+    ///
+    /// let q = DirectQueue::<_, 4>::from([1, 2, 3]);
+    /// let mut sum = 0;
+    /// for n in q {
+    ///     sum += n;
+    /// }
+    /// assert_eq![6, sum];
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_front()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T, const CAP: usize> IntoIterator for Queue<T, Boxed, CAP> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    /// Returns an owning iterator, from the front to the back.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedQueue;
+    ///
+    /// let q = BoxedQueue::<_, 4>::from([1, 2, 3]);
+    /// assert_eq![vec![1, 2, 3], q.into_iter().collect::<Vec<_>>()];
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_front()
+    }
+}
+
+impl<'s, T, S: Storage, const CAP: usize> IntoIterator for &'s Queue<T, S, CAP> {
+    type Item = &'s T;
+    type IntoIter = QueueIter<'s, T, S, CAP>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'s, T, S: Storage, const CAP: usize> IntoIterator for &'s mut Queue<T, S, CAP> {
+    type Item = &'s mut T;
+    type IntoIter = QueueIterMut<'s, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
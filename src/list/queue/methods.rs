@@ -9,7 +9,7 @@ use core::{
     ptr,
 };
 
-use super::{Array, Queue, QueueIter};
+use super::{Array, Queue, QueueDrain, QueueIter, QueueIterMut};
 
 use crate::{
     error::{LadataError as Error, LadataResult as Result},
@@ -19,7 +19,7 @@ use crate::{
 #[cfg(feature = "alloc")]
 use {
     crate::mem::Boxed,
-    alloc::{vec, vec::Vec},
+    alloc::{boxed::Box, vec, vec::Vec},
 };
 
 // `S:() + T:Clone`
@@ -66,6 +66,58 @@ impl<T: Clone, const CAP: usize> Queue<T, Boxed, CAP> {
     }
 }
 
+// `S:()`
+impl<T, const CAP: usize> Queue<T, (), CAP> {
+    /// Returns an owning iterator, from the front to the back.
+    ///
+    /// It doesn't depend on `T: Clone`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectQueue;
+    ///
+    /// let mut q = DirectQueue::<_, 4>::from([1, 2, 3]);
+    /// q.pop();
+    /// q.push(4);
+    /// assert![q.into_iter_front().eq([2, 3, 4])];
+    /// ```
+    pub fn into_iter_front(mut self) -> core::array::IntoIter<T, CAP> {
+        self.array.rotate_left(self.front);
+        let mut iter = self.array.into_array().into_iter();
+        if self.len < CAP {
+            // drops the unused elements
+            let _ = iter.nth_back(CAP - self.len - 1);
+        }
+        iter
+    }
+}
+
+// `S:Boxed`
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T, const CAP: usize> Queue<T, Boxed, CAP> {
+    /// Returns an owning iterator, from the front to the back.
+    ///
+    /// It doesn't depend on `T: Clone`, and reuses the heap allocation.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedQueue;
+    ///
+    /// let mut q = BoxedQueue::<_, 4>::from([1, 2, 3]);
+    /// q.pop();
+    /// q.push(4);
+    /// assert![q.into_iter_front().rev().eq([4, 3, 2])];
+    /// ```
+    pub fn into_iter_front(mut self) -> vec::IntoIter<T> {
+        self.array.rotate_left(self.front);
+        let boxed: Box<[T]> = self.array.into_array();
+        let mut vec = boxed.into_vec();
+        vec.truncate(self.len);
+        vec.into_iter()
+    }
+}
+
 // ``
 impl<T, S: Storage, const CAP: usize> Queue<T, S, CAP> {
     // Returns the `nth` element's index counting from the front.
@@ -158,10 +210,44 @@ impl<T, S: Storage, const CAP: usize> Queue<T, S, CAP> {
 
     /* iter */
 
+    /// Returns an iterator over shared references, from the front to the back.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectQueue;
+    ///
+    /// let q = DirectQueue::<_, 4>::from([1, 2, 3]);
+    /// assert![q.iter().eq(&[1, 2, 3])];
+    /// assert![q.iter().rev().eq(&[3, 2, 1])];
+    /// ```
     pub fn iter(&self) -> QueueIter<'_, T, S, CAP> {
         QueueIter {
             queue: self,
             idx: 0,
+            end: self.len,
+        }
+    }
+
+    /// Returns an iterator over exclusive references, from the front to the back.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectQueue;
+    ///
+    /// let mut q = DirectQueue::<_, 4>::from([1, 2, 3]);
+    /// q.iter_mut().for_each(|e| *e *= 10);
+    /// assert_eq![q.to_array(), Some([10, 20, 30])];
+    /// ```
+    pub fn iter_mut(&mut self) -> QueueIterMut<'_, T> {
+        let end = self.front + self.len;
+        let (a, b) = if end <= CAP {
+            (&mut self.array[self.front..end], &mut [][..])
+        } else {
+            let (wrapped, front) = self.array.split_at_mut(self.front);
+            (front, &mut wrapped[..end - CAP])
+        };
+        QueueIterMut {
+            iter: a.iter_mut().chain(b.iter_mut()),
         }
     }
 
@@ -172,29 +258,32 @@ impl<T, S: Storage, const CAP: usize> Queue<T, S, CAP> {
     /// `( 1 2 -- 1 2 3 4 5 6 )` for `[3 4 5 6]`
     ///
     /// # Errors
-    /// Errors if the queue becomes full before the iterator finishes.
+    /// Errors if the queue becomes full while the iterator still has elements,
+    /// in which case the first element that doesn't fit is dropped.
     ///
     /// # Examples
     /// ```
     /// use ladata::list::DirectQueue;
     ///
     /// let mut q = DirectQueue::<_, 6>::from([1, 2]);
-    /// q.extend([3, 4, 5, 6]);
+    /// assert![q.extend([3, 4, 5, 6]).is_ok()];
     /// assert_eq![q.to_array(), Some([1, 2, 3, 4, 5, 6])];
+    /// assert![q.extend([7]).is_err()];
     /// ```
     pub fn extend<I>(&mut self, iterator: I) -> Result<()>
     where
         I: IntoIterator<Item = T>,
     {
-        let mut iter = iterator.into_iter();
-        while !self.is_full() {
+        let mut iter = iterator.into_iter().peekable();
+        while iter.peek().is_some() {
+            if self.is_full() {
+                return Err(Error::NotEnoughSpace(None));
+            }
             if let Some(e) = iter.next() {
                 self.push_unchecked(e);
-            } else {
-                return Ok(());
             }
         }
-        Err(Error::NotEnoughSpace(None))
+        Ok(())
     }
 
     /* push */
@@ -247,6 +336,36 @@ impl<T, S: Storage, const CAP: usize> Queue<T, S, CAP> {
         self.len += 1;
     }
 
+    /// Pushes a new element to the back of the queue,
+    /// overwriting the front element if the queue is full.
+    ///
+    /// Returns the overwritten element, if any.
+    ///
+    /// `( 1 2 3 -- 2 3 4 )` for `CAP == 3`
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectQueue;
+    ///
+    /// let mut q = DirectQueue::<u8, 3>::from([1, 2]);
+    /// assert_eq![None, q.push_overwrite(3)];
+    /// assert_eq![Some(1), q.push_overwrite(4)];
+    /// assert_eq![q.to_array(), Some([2, 3, 4])];
+    /// ```
+    pub fn push_overwrite(&mut self, element: T) -> Option<T> {
+        if CAP == 0 {
+            Some(element)
+        } else if self.is_full() {
+            let evicted = core::mem::replace(&mut self.array[self.back], element);
+            self.back = (self.back + 1) % CAP;
+            self.front = self.back;
+            Some(evicted)
+        } else {
+            self.push_unchecked(element);
+            None
+        }
+    }
+
     /* peek */
 
     /// Returns a shared reference to the front element.
@@ -547,6 +666,37 @@ impl<T: Clone, S: Storage, const CAP: usize> Queue<T, S, CAP> {
     }
 }
 
+// `T:Default`
+impl<T: Default, S: Storage, const CAP: usize> Queue<T, S, CAP> {
+    /// Empties the queue, returning its elements from the front to the back.
+    ///
+    /// `( 1 2 3 -- )`
+    ///
+    /// The elements are moved out one by one as the iterator advances,
+    /// leaving their default value behind, and shrinking the queue right
+    /// away. The elements that are not consumed are dropped when the
+    /// iterator is.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectQueue;
+    ///
+    /// let mut q = DirectQueue::<_, 4>::from([1, 2, 3]);
+    /// assert![q.drain().eq([1, 2, 3])];
+    /// assert![q.is_empty()];
+    ///
+    /// let mut q = DirectQueue::<_, 4>::from([1, 2, 3]);
+    /// let mut drain = q.drain();
+    /// assert_eq![Some(1), drain.next()];
+    /// assert_eq![Some(3), drain.next_back()];
+    /// drop(drain);
+    /// assert![q.is_empty()];
+    /// ```
+    pub fn drain(&mut self) -> QueueDrain<'_, T, S, CAP> {
+        QueueDrain { queue: self }
+    }
+}
+
 // `T: PartialEq`
 impl<T: PartialEq, S: Storage, const CAP: usize> Queue<T, S, CAP> {
     /// Returns true if the queue contains `element`.
@@ -584,4 +734,59 @@ mod tests {
         // loops over CAP
         assert_eq![0, q.idx_front(5)];
     }
+
+    // test the iterators for every wraparound layout and length
+    #[test]
+    fn iter_wrapped() {
+        for offset in 0..5 {
+            for len in 0..=5 {
+                let mut q = Queue::<_, (), 5>::default();
+                for _ in 0..offset {
+                    q.push(0).unwrap();
+                    q.pop().unwrap();
+                }
+                for e in 1..=len as i32 {
+                    q.push(e).unwrap();
+                }
+                let v = [1, 2, 3, 4, 5];
+                let v = &v[..len];
+
+                assert![q.iter().eq(v)];
+                assert![q.iter().rev().eq(v.iter().rev())];
+                q.iter_mut().for_each(|e| *e += 1);
+                assert![q.clone().into_iter_front().eq(v.iter().map(|e| e + 1))];
+                assert![q.drain().rev().eq(v.iter().rev().map(|e| e + 1))];
+                assert![q.is_empty()];
+            }
+        }
+    }
+
+    // test the drain moves out the elements lazily, without cloning them
+    #[test]
+    fn drain_lazy() {
+        #[derive(Debug, Default, PartialEq)]
+        struct NoClone(i32);
+
+        for offset in 0..4 {
+            let mut q = Queue::<_, (), 4>::default();
+            for _ in 0..offset {
+                q.push(NoClone(0)).unwrap();
+                drop(q.drain());
+            }
+            for e in 1..=4 {
+                q.push(NoClone(e)).unwrap();
+            }
+            let mut d = q.drain();
+            assert_eq![Some(NoClone(1)), d.next()];
+            assert_eq![Some(NoClone(4)), d.next_back()];
+            assert_eq![2, d.len()];
+            drop(d);
+            assert![q.is_empty()];
+
+            // the queue is left in a consistent state
+            q.push(NoClone(5)).unwrap();
+            q.push(NoClone(6)).unwrap();
+            assert![q.drain().eq([NoClone(5), NoClone(6)])];
+        }
+    }
 }
@@ -4,6 +4,11 @@
 //! and removals are made at the opposite end.
 //
 
+use core::{
+    iter::{Chain, FusedIterator},
+    slice,
+};
+
 use crate::{list::Array, mem::Storage};
 
 #[cfg(feature = "alloc")]
@@ -40,10 +45,11 @@ pub type BoxedQueue<T, const CAP: usize> = Queue<T, Boxed, CAP>;
 
 /* iterators */
 
-/// A queue iterator.
+/// A queue iterator, from the front to the back.
 pub struct QueueIter<'s, T, S: Storage, const CAP: usize> {
     queue: &'s Queue<T, S, CAP>,
     idx: usize,
+    end: usize,
 }
 
 impl<'s, T, S: Storage, const CAP: usize> Iterator for QueueIter<'s, T, S, CAP> {
@@ -67,18 +73,118 @@ impl<'s, T, S: Storage, const CAP: usize> Iterator for QueueIter<'s, T, S, CAP>
     ///
     /// ```
     fn next(&mut self) -> Option<Self::Item> {
-        let item = if self.idx == self.queue.len() {
+        if self.idx == self.end {
             None
         } else {
-            Some(&self.queue.array[self.queue.idx_front(self.idx)])
-        };
-        self.idx += 1;
-        item
+            self.idx += 1;
+            Some(&self.queue.array[self.queue.idx_front(self.idx - 1)])
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.queue.len(), Some(self.queue.len()))
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<'s, T, S: Storage, const CAP: usize> DoubleEndedIterator for QueueIter<'s, T, S, CAP> {
+    /// Iterates over shared references, from the back.
+    ///
+    /// # Example
+    /// ```
+    /// use ladata::all::DirectQueue;
+    ///
+    /// let q = DirectQueue::<i32, 4>::from([1, 2, 3]);
+    ///
+    /// let mut qi = q.iter();
+    /// assert_eq![Some(&3), qi.next_back()];
+    /// assert_eq![Some(&1), qi.next()];
+    /// assert_eq![Some(&2), qi.next_back()];
+    /// assert_eq![None, qi.next()];
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.idx == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(&self.queue.array[self.queue.idx_front(self.end)])
+        }
     }
 }
 
 impl<'s, T, S: Storage, const CAP: usize> ExactSizeIterator for QueueIter<'s, T, S, CAP> {}
+impl<'s, T, S: Storage, const CAP: usize> FusedIterator for QueueIter<'s, T, S, CAP> {}
+
+/// A queue iterator over exclusive references, from the front to the back.
+///
+/// Created by [`Queue::iter_mut`].
+pub struct QueueIterMut<'s, T> {
+    iter: Chain<slice::IterMut<'s, T>, slice::IterMut<'s, T>>,
+}
+
+impl<'s, T> Iterator for QueueIterMut<'s, T> {
+    type Item = &'s mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<'s, T> DoubleEndedIterator for QueueIterMut<'s, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+impl<'s, T> ExactSizeIterator for QueueIterMut<'s, T> {}
+impl<'s, T> FusedIterator for QueueIterMut<'s, T> {}
+
+/// A draining queue iterator, from the front to the back.
+///
+/// Created by [`Queue::drain`].
+pub struct QueueDrain<'s, T: Default, S: Storage, const CAP: usize> {
+    queue: &'s mut Queue<T, S, CAP>,
+}
+
+impl<'s, T: Default, S: Storage, const CAP: usize> Iterator for QueueDrain<'s, T, S, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.queue.len == 0 {
+            None
+        } else {
+            let e = core::mem::take(&mut self.queue.array[self.queue.front]);
+            self.queue.front = (self.queue.front + 1) % CAP;
+            self.queue.len -= 1;
+            Some(e)
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.len, Some(self.queue.len))
+    }
+}
+impl<'s, T: Default, S: Storage, const CAP: usize> DoubleEndedIterator
+    for QueueDrain<'s, T, S, CAP>
+{
+    fn next_back(&mut self) -> Option<T> {
+        if self.queue.len == 0 {
+            None
+        } else {
+            self.queue.back = (self.queue.back + CAP - 1) % CAP;
+            self.queue.len -= 1;
+            Some(core::mem::take(&mut self.queue.array[self.queue.back]))
+        }
+    }
+}
+impl<'s, T: Default, S: Storage, const CAP: usize> ExactSizeIterator for QueueDrain<'s, T, S, CAP> {}
+impl<'s, T: Default, S: Storage, const CAP: usize> FusedIterator for QueueDrain<'s, T, S, CAP> {}
+impl<'s, T: Default, S: Storage, const CAP: usize> Drop for QueueDrain<'s, T, S, CAP> {
+    /// Drops the elements that were not consumed, emptying the queue.
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
@@ -0,0 +1,238 @@
+// ladata::list::ring::impls
+//
+//! Ring buffer trait implementations and conversions.
+//
+
+use core::fmt;
+
+use super::{Array, RingBuffer, Storage};
+use crate::{
+    list::{Deque, Queue},
+    misc::DataCollection,
+};
+
+#[cfg(feature = "alloc")]
+use crate::mem::Boxed;
+
+// T:Clone
+impl<T: Clone, S: Storage, const CAP: usize> Clone for RingBuffer<T, S, CAP>
+where
+    S::Stored<[T; CAP]>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array.clone(),
+            front: self.front,
+            len: self.len,
+        }
+    }
+}
+
+// T:Copy
+impl<T: Copy, S: Storage, const CAP: usize> Copy for RingBuffer<T, S, CAP> where
+    S::Stored<[T; CAP]>: Copy
+{
+}
+
+// T:Debug
+impl<T: fmt::Debug, S: Storage, const CAP: usize> fmt::Debug for RingBuffer<T, S, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct(stringify![RingBuffer]);
+        debug
+            .field("CAP", &CAP)
+            .field("len", &self.len)
+            .field("front", &self.front);
+
+        if self.len <= 6 {
+            debug.field("slices", &self.as_slices());
+        } else {
+            // IMPROVE: show first 3 and last 3
+            debug.field("slices { ... }", &());
+        }
+        debug.finish()
+    }
+}
+
+// T:PartialEq
+impl<T: PartialEq, S: Storage, const CAP: usize> PartialEq for RingBuffer<T, S, CAP> {
+    /// Compares only the buffered elements, from the oldest to the newest.
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+// T:Eq
+impl<T: Eq, S: Storage, const CAP: usize> Eq for RingBuffer<T, S, CAP> {}
+
+// S:() + T:Default
+impl<T: Default, const CAP: usize> Default for RingBuffer<T, (), CAP> {
+    /// Returns an empty ring buffer, allocated in the stack,
+    /// using the default value to fill the remaining free data.
+    fn default() -> Self {
+        Self {
+            array: Array::default(),
+            front: 0,
+            len: 0,
+        }
+    }
+}
+
+// S:Boxed + T:Default
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize> Default for RingBuffer<T, Boxed, CAP> {
+    /// Returns an empty ring buffer, allocated in the heap,
+    /// using the default value to fill the remaining free data.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedRingBuffer;
+    ///
+    /// let r = BoxedRingBuffer::<i32, 100>::default();
+    /// ```
+    fn default() -> Self {
+        Self {
+            array: Array::default(),
+            front: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<T, S: Storage, const CAP: usize> DataCollection for RingBuffer<T, S, CAP> {
+    type Element = T;
+
+    fn collection_is_empty(&self) -> Option<bool> {
+        Some(self.is_empty())
+    }
+    fn collection_is_full(&self) -> Option<bool> {
+        Some(self.is_full())
+    }
+    fn collection_capacity(&self) -> usize {
+        CAP
+    }
+    fn collection_len(&self) -> usize {
+        self.len()
+    }
+}
+
+/* From<[T; N]> */
+
+impl<T: Default, const CAP: usize, const N: usize> From<[T; N]> for RingBuffer<T, (), CAP> {
+    /// Returns a ring buffer with the last `CAP` elements of an array, in the stack.
+    ///
+    /// The first elements that don't fit in the capacity are overwritten.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    ///
+    /// let r: DirectRingBuffer<_, 3> = [1, 2, 3, 4].into();
+    /// assert![r.iter().eq(&[2, 3, 4])];
+    /// ```
+    fn from(array: [T; N]) -> RingBuffer<T, (), CAP> {
+        array.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize, const N: usize> From<[T; N]> for RingBuffer<T, Boxed, CAP> {
+    /// Returns a ring buffer with the last `CAP` elements of an array, in the heap.
+    ///
+    /// The first elements that don't fit in the capacity are overwritten.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedRingBuffer;
+    ///
+    /// let r: BoxedRingBuffer<_, 3> = [1, 2, 3, 4].into();
+    /// assert![r.iter().eq(&[2, 3, 4])];
+    /// ```
+    fn from(array: [T; N]) -> RingBuffer<T, Boxed, CAP> {
+        array.into_iter().collect()
+    }
+}
+
+/* FromIterator */
+
+impl<T: Default, const CAP: usize> FromIterator<T> for RingBuffer<T, (), CAP> {
+    /// Returns a ring buffer with the last `CAP` elements of an iterator, in the stack.
+    ///
+    /// The first elements that don't fit in the capacity are overwritten.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    ///
+    /// let r: DirectRingBuffer<_, 3> = (1..=5).collect();
+    /// assert![r.iter().eq(&[3, 4, 5])];
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut r = Self::default();
+        r.extend(iterator);
+        r
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize> FromIterator<T> for RingBuffer<T, Boxed, CAP> {
+    /// Returns a ring buffer with the last `CAP` elements of an iterator, in the heap.
+    ///
+    /// The first elements that don't fit in the capacity are overwritten.
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut r = Self::default();
+        r.extend(iterator);
+        r
+    }
+}
+
+/* conversions with other lists */
+
+impl<T, S: Storage, const CAP: usize> From<Queue<T, S, CAP>> for RingBuffer<T, S, CAP> {
+    fn from(queue: Queue<T, S, CAP>) -> Self {
+        RingBuffer {
+            array: queue.array,
+            front: queue.front,
+            len: queue.len,
+        }
+    }
+}
+impl<T, S: Storage, const CAP: usize> From<RingBuffer<T, S, CAP>> for Queue<T, S, CAP> {
+    fn from(ring: RingBuffer<T, S, CAP>) -> Self {
+        Queue {
+            back: if CAP == 0 {
+                0
+            } else {
+                (ring.front + ring.len) % CAP
+            },
+            array: ring.array,
+            front: ring.front,
+            len: ring.len,
+        }
+    }
+}
+
+impl<T, S: Storage, const CAP: usize> From<Deque<T, S, CAP>> for RingBuffer<T, S, CAP> {
+    fn from(deque: Deque<T, S, CAP>) -> Self {
+        RingBuffer {
+            array: deque.array,
+            front: deque.front,
+            len: deque.len,
+        }
+    }
+}
+impl<T, S: Storage, const CAP: usize> From<RingBuffer<T, S, CAP>> for Deque<T, S, CAP> {
+    fn from(ring: RingBuffer<T, S, CAP>) -> Self {
+        Deque {
+            back: if CAP == 0 {
+                0
+            } else {
+                (ring.front + ring.len) % CAP
+            },
+            array: ring.array,
+            front: ring.front,
+            len: ring.len,
+        }
+    }
+}
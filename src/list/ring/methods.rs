@@ -0,0 +1,310 @@
+// ladata::list::ring::methods
+//
+//! Ring buffers.
+//
+
+use core::mem;
+
+use super::{RingBuffer, RingBufferIter};
+use crate::{
+    error::{LadataError as Error, LadataResult as Result},
+    list::Array,
+    mem::Storage,
+};
+
+#[cfg(feature = "alloc")]
+use crate::mem::Boxed;
+
+// `S:() + T:Clone`
+impl<T: Clone, const CAP: usize> RingBuffer<T, (), CAP> {
+    /// Returns an empty ring buffer, allocated in the stack,
+    /// using `element` to fill the remaining free data.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    ///
+    /// let r = DirectRingBuffer::<_, 16>::new(0);
+    /// ```
+    pub fn new(element: T) -> Self {
+        Self {
+            array: Array::<T, (), CAP>::with(element),
+            front: 0,
+            len: 0,
+        }
+    }
+}
+
+// `S:Boxed + T:Clone`
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Clone, const CAP: usize> RingBuffer<T, Boxed, CAP> {
+    /// Returns an empty ring buffer, allocated in the heap,
+    /// using `element` to fill the remaining free data.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedRingBuffer;
+    ///
+    /// let r = BoxedRingBuffer::<_, 100>::new(0);
+    /// ```
+    pub fn new(element: T) -> Self {
+        Self {
+            array: Array::<T, Boxed, CAP>::with(element),
+            front: 0,
+            len: 0,
+        }
+    }
+}
+
+// ``
+impl<T, S: Storage, const CAP: usize> RingBuffer<T, S, CAP> {
+    /// Returns the number of buffered elements.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the ring buffer is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    ///
+    /// let r = DirectRingBuffer::<i32, 8>::default();
+    /// assert![r.is_empty()];
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the ring buffer is full,
+    /// so that the next push will overwrite the oldest element.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    ///
+    /// let r = DirectRingBuffer::<_, 3>::from([1, 2, 3]);
+    /// assert![r.is_full()];
+    /// ```
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len() == CAP
+    }
+
+    /// Returns the ring buffer's total capacity.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Returns the ring buffer's remaining capacity before overwriting.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        CAP - self.len()
+    }
+
+    /// Returns an iterator from the oldest to the newest element.
+    pub fn iter(&self) -> RingBufferIter<'_, T> {
+        let (a, b) = self.as_slices();
+        RingBufferIter {
+            iter: a.iter().chain(b.iter()),
+        }
+    }
+
+    /// Returns the two contiguous parts of the ring buffer,
+    /// from the oldest to the newest element.
+    ///
+    /// The second slice is empty if the elements are already contiguous.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    ///
+    /// let mut r = DirectRingBuffer::<_, 4>::from([1, 2, 3, 4]);
+    /// r.push(5);
+    /// assert_eq![(&[2, 3, 4][..], &[5][..]), r.as_slices()];
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let end = self.front + self.len;
+        if end <= CAP {
+            (&self.array[self.front..end], &[])
+        } else {
+            let (wrapped, front) = self.array.split_at(self.front);
+            (front, &wrapped[..end - CAP])
+        }
+    }
+
+    /// Returns the two contiguous parts of the ring buffer as exclusive slices,
+    /// from the oldest to the newest element.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let end = self.front + self.len;
+        if end <= CAP {
+            (&mut self.array[self.front..end], &mut [])
+        } else {
+            let (wrapped, front) = self.array.split_at_mut(self.front);
+            (front, &mut wrapped[..end - CAP])
+        }
+    }
+
+    /// Rearranges the elements so that they are contiguous,
+    /// and returns them as an exclusive slice, from the oldest to the newest.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    ///
+    /// let mut r = DirectRingBuffer::<_, 3>::from([1, 2, 3, 4]);
+    /// assert_eq![&mut [2, 3, 4], r.make_contiguous()];
+    /// assert_eq![(&[2, 3, 4][..], &[][..]), r.as_slices()];
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.front + self.len > CAP {
+            self.array.rotate_left(self.front);
+            self.front = 0;
+        }
+        &mut self.array[self.front..self.front + self.len]
+    }
+
+    /// Pushes a new element as the newest one,
+    /// overwriting the oldest element if the ring buffer is full.
+    ///
+    /// Returns the overwritten element, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    ///
+    /// let mut r = DirectRingBuffer::<u8, 2>::default();
+    /// assert_eq![None, r.push(1)];
+    /// assert_eq![None, r.push(2)];
+    /// assert_eq![Some(1), r.push(3)];
+    /// assert_eq![Some(2), r.push(4)];
+    /// ```
+    pub fn push(&mut self, element: T) -> Option<T> {
+        if CAP == 0 {
+            Some(element)
+        } else if self.is_full() {
+            let evicted = mem::replace(&mut self.array[self.front], element);
+            self.front = (self.front + 1) % CAP;
+            Some(evicted)
+        } else {
+            let back = (self.front + self.len) % CAP;
+            self.array[back] = element;
+            self.len += 1;
+            None
+        }
+    }
+
+    /// Pushes all the elements of an iterator,
+    /// overwriting the oldest elements if the ring buffer becomes full.
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iterator: I) {
+        for element in iterator {
+            let _ = self.push(element);
+        }
+    }
+
+    /// Returns a shared reference to the oldest element.
+    ///
+    /// # Errors
+    /// Errors if the ring buffer is empty.
+    #[inline]
+    pub fn peek_front(&self) -> Result<&T> {
+        self.peek_nth_front(0)
+    }
+
+    /// Returns a shared reference to the newest element.
+    ///
+    /// # Errors
+    /// Errors if the ring buffer is empty.
+    #[inline]
+    pub fn peek_back(&self) -> Result<&T> {
+        self.peek_nth_back(0)
+    }
+
+    /// Returns a shared reference to the `nth` element, counting from the oldest.
+    ///
+    /// # Errors
+    /// Errors if the ring buffer doesn't have at least `nth` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let r = DirectRingBuffer::<_, 3>::from([1, 2, 3, 4]);
+    /// assert_eq![&3, r.peek_nth_front(1)?];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    pub fn peek_nth_front(&self, nth: usize) -> Result<&T> {
+        if self.len() <= nth {
+            Err(Error::NotEnoughElements(nth + 1))
+        } else {
+            Ok(&self.array[(self.front + nth) % CAP])
+        }
+    }
+
+    /// Returns a shared reference to the `nth` element, counting from the newest.
+    ///
+    /// # Errors
+    /// Errors if the ring buffer doesn't have at least `nth` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let r = DirectRingBuffer::<_, 3>::from([1, 2, 3, 4]);
+    /// assert_eq![&4, r.peek_nth_back(0)?];
+    /// assert_eq![&2, r.peek_nth_back(2)?];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    pub fn peek_nth_back(&self, nth: usize) -> Result<&T> {
+        if self.len() <= nth {
+            Err(Error::NotEnoughElements(nth + 1))
+        } else {
+            self.peek_nth_front(self.len - 1 - nth)
+        }
+    }
+
+    /// Clears the ring buffer.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.front = 0;
+        self.len = 0;
+    }
+}
+
+// `T:Clone`
+impl<T: Clone, S: Storage, const CAP: usize> RingBuffer<T, S, CAP> {
+    /// Pops the oldest element.
+    ///
+    /// # Errors
+    /// Errors if the ring buffer is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut r = DirectRingBuffer::<_, 2>::from([1, 2, 3]);
+    /// assert_eq![2, r.pop()?];
+    /// assert_eq![3, r.pop()?];
+    /// assert![r.pop().is_err()];
+    /// # Ok(()) }
+    /// ```
+    pub fn pop(&mut self) -> Result<T> {
+        if self.is_empty() {
+            Err(Error::NotEnoughElements(1))
+        } else {
+            let e = self.array[self.front].clone();
+            self.front = (self.front + 1) % CAP;
+            self.len -= 1;
+            Ok(e)
+        }
+    }
+}
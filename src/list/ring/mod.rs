@@ -0,0 +1,77 @@
+// ladata::list::ring
+//
+//! Ring buffers are queues of fixed size that overwrite their oldest element
+//! when a new one is pushed while they are full.
+//!
+//! <https://en.wikipedia.org/wiki/Circular_buffer>
+//
+
+use core::{iter::FusedIterator, slice};
+
+use crate::{list::Array, mem::Storage};
+
+#[cfg(feature = "alloc")]
+use crate::mem::Boxed;
+
+mod impls;
+mod methods;
+
+/// A ring buffer, backed by an [`Array`].
+///
+/// Pushing never fails, and returns the oldest element when it's full.
+pub struct RingBuffer<T, S: Storage, const CAP: usize> {
+    pub(crate) array: Array<T, S, CAP>,
+    pub(crate) front: usize,
+    pub(crate) len: usize,
+}
+
+/// A [`RingBuffer`] stored in the stack.
+pub type DirectRingBuffer<T, const CAP: usize> = RingBuffer<T, (), CAP>;
+
+/// A [`RingBuffer`] stored in the heap.
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub type BoxedRingBuffer<T, const CAP: usize> = RingBuffer<T, Boxed, CAP>;
+
+/* iterators */
+
+/// A ring buffer iterator, from the oldest to the newest element.
+pub struct RingBufferIter<'s, T> {
+    iter: core::iter::Chain<slice::Iter<'s, T>, slice::Iter<'s, T>>,
+}
+
+impl<'s, T> Iterator for RingBufferIter<'s, T> {
+    type Item = &'s T;
+    /// Iterates over shared references.
+    ///
+    /// # Example
+    /// ```
+    /// use ladata::list::DirectRingBuffer;
+    ///
+    /// let r = DirectRingBuffer::<i32, 2>::from([1, 2, 3]);
+    ///
+    /// let mut ri = r.iter();
+    /// assert_eq![Some(&2), ri.next()];
+    /// assert_eq![Some(&3), ri.next()];
+    /// assert_eq![None, ri.next()];
+    /// ```
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'s, T> DoubleEndedIterator for RingBufferIter<'s, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<'s, T> ExactSizeIterator for RingBufferIter<'s, T> {}
+impl<'s, T> FusedIterator for RingBufferIter<'s, T> {}
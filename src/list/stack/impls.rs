@@ -5,10 +5,10 @@
 
 use core::fmt;
 
-use super::{Array, Stack, Storage};
+use super::{Array, Stack, StackIter, StackIterMut, Storage};
 
 #[cfg(feature = "alloc")]
-use crate::mem::Boxed;
+use {crate::mem::Boxed, alloc::vec};
 
 // T:Clone
 impl<T: Clone, S: Storage, const CAP: usize> Clone for Stack<T, S, CAP>
@@ -91,13 +91,12 @@ impl<T: Default, const CAP: usize> Default for Stack<T, Boxed, CAP> {
     }
 }
 
-/* From<IntoIterator<Item = T>> */
+/* From<[T; N]> */
 
-impl<T: Default, I, const CAP: usize> From<I> for Stack<T, (), CAP>
-where
-    I: IntoIterator<Item = T>,
-{
-    /// Returns a stack filled with an iterator, in the stack.
+impl<T: Default, const CAP: usize, const N: usize> From<[T; N]> for Stack<T, (), CAP> {
+    /// Returns a stack filled with the elements of an array, in the stack.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
     ///
     /// # Examples
     /// ```
@@ -105,20 +104,17 @@ where
     ///
     /// let s: DirectStack<_, 3> = [1, 2, 3].into();
     /// ```
-    fn from(iterator: I) -> Stack<T, (), CAP> {
-        let mut s = Stack::<T, (), CAP>::default();
-        let _ = s.extend(iterator);
-        s
+    fn from(array: [T; N]) -> Stack<T, (), CAP> {
+        array.into_iter().collect()
     }
 }
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
-impl<T: Default, I, const CAP: usize> From<I> for Stack<T, Boxed, CAP>
-where
-    I: IntoIterator<Item = T>,
-{
-    /// Returns a stack filled with an iterator, in the heap.
+impl<T: Default, const CAP: usize, const N: usize> From<[T; N]> for Stack<T, Boxed, CAP> {
+    /// Returns a stack filled with the elements of an array, in the heap.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
     ///
     /// # Examples
     /// ```
@@ -126,9 +122,103 @@ where
     ///
     /// let s: BoxedStack<_, 3> = [1, 2, 3].into();
     /// ```
-    fn from(iterator: I) -> Stack<T, Boxed, CAP> {
-        let mut s = Stack::<T, Boxed, CAP>::default();
+    fn from(array: [T; N]) -> Stack<T, Boxed, CAP> {
+        array.into_iter().collect()
+    }
+}
+
+/* FromIterator */
+
+impl<T: Default, const CAP: usize> FromIterator<T> for Stack<T, (), CAP> {
+    /// Returns a stack filled with an iterator, in the stack.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectStack;
+    ///
+    /// let s: DirectStack<_, 3> = (1..=5).collect();
+    /// assert_eq![3, s.len()];
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut s = Self::default();
+        let _ = s.extend(iterator);
+        s
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize> FromIterator<T> for Stack<T, Boxed, CAP> {
+    /// Returns a stack filled with an iterator, in the heap.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut s = Self::default();
         let _ = s.extend(iterator);
         s
     }
 }
+
+/* IntoIterator */
+
+impl<T, const CAP: usize> IntoIterator for Stack<T, (), CAP> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, CAP>;
+
+    /// Returns an owning iterator, from the bottom to the top.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectStack;
+    ///
+    /// let s = DirectStack::<_, 4>::from([1, 2, 3]);
+    /// let mut sum = 0;
+    /// for n in s {
+    ///     sum += n;
+    /// }
+    /// assert_eq![6, sum];
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_bottom()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T, const CAP: usize> IntoIterator for Stack<T, Boxed, CAP> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    /// Returns an owning iterator, from the bottom to the top.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedStack;
+    ///
+    /// let s = BoxedStack::<_, 4>::from([1, 2, 3]);
+    /// assert_eq![vec![1, 2, 3], s.into_iter().collect::<Vec<_>>()];
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_bottom()
+    }
+}
+
+impl<'s, T, S: Storage, const CAP: usize> IntoIterator for &'s Stack<T, S, CAP> {
+    type Item = &'s T;
+    type IntoIter = StackIter<'s, T, S, CAP>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'s, T, S: Storage, const CAP: usize> IntoIterator for &'s mut Stack<T, S, CAP> {
+    type Item = &'s mut T;
+    type IntoIter = StackIterMut<'s, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
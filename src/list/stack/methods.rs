@@ -6,7 +6,7 @@
 #[cfg(feature = "unsafe_pop")]
 use core::ptr;
 
-use super::{Stack, StackIter};
+use super::{Stack, StackDrain, StackIter, StackIterMut};
 use crate::{
     error::{LadataError as Error, LadataResult as Result},
     list::Array,
@@ -14,7 +14,10 @@ use crate::{
 };
 
 #[cfg(feature = "alloc")]
-use crate::mem::Boxed;
+use {
+    crate::mem::Boxed,
+    alloc::{boxed::Box, vec},
+};
 
 // `S:() + T:Clone`
 impl<T: Clone, const CAP: usize> Stack<T, (), CAP> {
@@ -35,6 +38,29 @@ impl<T: Clone, const CAP: usize> Stack<T, (), CAP> {
     }
 }
 
+// `S:()`
+impl<T, const CAP: usize> Stack<T, (), CAP> {
+    /// Returns an owning iterator, from the bottom to the top.
+    ///
+    /// It doesn't depend on `T: Clone`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectStack;
+    ///
+    /// let s = DirectStack::<_, 4>::from([1, 2, 3]);
+    /// assert![s.into_iter_bottom().rev().eq([3, 2, 1])];
+    /// ```
+    pub fn into_iter_bottom(self) -> core::array::IntoIter<T, CAP> {
+        let mut iter = self.array.into_array().into_iter();
+        if self.len < CAP {
+            // drops the unused elements
+            let _ = iter.nth_back(CAP - self.len - 1);
+        }
+        iter
+    }
+}
+
 // `S:Boxed + T:Clone`
 #[cfg(feature = "alloc")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
@@ -56,6 +82,29 @@ impl<T: Clone, const CAP: usize> Stack<T, Boxed, CAP> {
     }
 }
 
+// `S:Boxed`
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T, const CAP: usize> Stack<T, Boxed, CAP> {
+    /// Returns an owning iterator, from the bottom to the top.
+    ///
+    /// It doesn't depend on `T: Clone`, and reuses the heap allocation.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedStack;
+    ///
+    /// let s = BoxedStack::<_, 4>::from([1, 2, 3]);
+    /// assert![s.into_iter_bottom().eq([1, 2, 3])];
+    /// ```
+    pub fn into_iter_bottom(self) -> vec::IntoIter<T> {
+        let boxed: Box<[T]> = self.array.into_array();
+        let mut vec = boxed.into_vec();
+        vec.truncate(self.len);
+        vec.into_iter()
+    }
+}
+
 // ``
 impl<T, S: Storage, const CAP: usize> Stack<T, S, CAP> {
     /// Returns the number of stacked elements.
@@ -156,32 +205,35 @@ impl<T, S: Storage, const CAP: usize> Stack<T, S, CAP> {
     /// Extends the stack from an iterator.
     ///
     /// # Errors
-    /// Errors if the stack becomes full before the iterator finishes.
+    /// Errors if the stack becomes full while the iterator still has elements,
+    /// in which case the first element that doesn't fit is dropped.
     ///
     /// # Examples
     /// ```
     /// use ladata::list::DirectStack;
     ///
     /// let mut s = DirectStack::<_, 5>::default();
-    /// s.extend([1, 2, 3]);
+    /// assert![s.extend([1, 2, 3]).is_ok()];
     /// assert_eq![s.as_slice(), &[1, 2, 3]];
     ///
-    /// s.extend([4, 5, 6, 7, 8]);
+    /// assert![s.extend([4, 5, 6, 7, 8]).is_err()];
     /// assert_eq![s.as_slice(), &[1, 2, 3, 4, 5]];
+    /// assert![s.extend([]).is_ok()];
     /// ```
     pub fn extend<I>(&mut self, iterator: I) -> Result<()>
     where
         I: IntoIterator<Item = T>,
     {
-        let mut iter = iterator.into_iter();
-        while !self.is_full() {
+        let mut iter = iterator.into_iter().peekable();
+        while iter.peek().is_some() {
+            if self.is_full() {
+                return Err(Error::NotEnoughSpace(None));
+            }
             if let Some(e) = iter.next() {
                 let _ = self.push(e);
-            } else {
-                return Ok(());
             }
         }
-        Err(Error::NotEnoughSpace(None))
+        Ok(())
     }
 
     //
@@ -890,10 +942,37 @@ impl<T, S: Storage, const CAP: usize> Stack<T, S, CAP> {
         }
     }
 
+    /// Returns an iterator over shared references, from the bottom to the top.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectStack;
+    ///
+    /// let s = DirectStack::<_, 4>::from([1, 2, 3]);
+    /// assert![s.iter().eq(&[1, 2, 3])];
+    /// assert![s.iter().rev().eq(&[3, 2, 1])];
+    /// ```
     pub fn iter(&self) -> StackIter<'_, T, S, CAP> {
         StackIter {
             stack: self,
             idx: 0,
+            end: self.len,
+        }
+    }
+
+    /// Returns an iterator over exclusive references, from the bottom to the top.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectStack;
+    ///
+    /// let mut s = DirectStack::<_, 4>::from([1, 2, 3]);
+    /// s.iter_mut().for_each(|e| *e *= 10);
+    /// assert_eq![s.as_slice(), &[10, 20, 30]];
+    /// ```
+    pub fn iter_mut(&mut self) -> StackIterMut<'_, T> {
+        StackIterMut {
+            iter: self.as_mut_slice().iter_mut(),
         }
     }
 }
@@ -918,6 +997,36 @@ impl<T: PartialEq, S: Storage, const CAP: usize> Stack<T, S, CAP> {
 
 // `T: Default`
 impl<T: Default, S: Storage, const CAP: usize> Stack<T, S, CAP> {
+    /// Empties the stack, returning its elements from the bottom to the top.
+    ///
+    /// `( 1 2 3 -- )`
+    ///
+    /// The elements are moved out one by one as the iterator advances,
+    /// leaving their default value behind. Taking elements from the top
+    /// shrinks the stack right away, and the elements that are not consumed
+    /// are dropped when the iterator is.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectStack;
+    ///
+    /// let mut s = DirectStack::<_, 4>::from([1, 2, 3]);
+    /// assert![s.drain().rev().eq([3, 2, 1])];
+    /// assert![s.is_empty()];
+    ///
+    /// let mut s = DirectStack::<_, 4>::from([1, 2, 3]);
+    /// let mut drain = s.drain();
+    /// assert_eq![Some(1), drain.next()];
+    /// drop(drain);
+    /// assert![s.is_empty()];
+    /// ```
+    pub fn drain(&mut self) -> StackDrain<'_, T, S, CAP> {
+        StackDrain {
+            stack: self,
+            idx: 0,
+        }
+    }
+
     /// Drops the top of stack element,
     /// replacing the underlying data with the default value.
     ///
@@ -5,6 +5,8 @@
 //! <https://en.wikipedia.org/wiki/Stack_(abstract_data_type)>
 //
 
+use core::{iter::FusedIterator, slice};
+
 use crate::{list::Array, mem::Storage};
 
 #[cfg(feature = "alloc")]
@@ -32,10 +34,11 @@ pub type BoxedStack<T, const CAP: usize> = Stack<T, Boxed, CAP>;
 
 /* iterators */
 
-/// A stack iterator.
+/// A stack iterator, from the bottom to the top.
 pub struct StackIter<'s, T, S: Storage, const CAP: usize> {
     stack: &'s Stack<T, S, CAP>,
     idx: usize,
+    end: usize,
 }
 
 impl<'s, T, S: Storage, const CAP: usize> Iterator for StackIter<'s, T, S, CAP> {
@@ -55,18 +58,119 @@ impl<'s, T, S: Storage, const CAP: usize> Iterator for StackIter<'s, T, S, CAP>
     ///
     /// ```
     fn next(&mut self) -> Option<Self::Item> {
-        let item = if self.idx == self.stack.len() {
+        if self.idx == self.end {
             None
         } else {
-            Some(&self.stack.array[self.idx])
-        };
-        self.idx += 1;
-        item
+            self.idx += 1;
+            Some(&self.stack.array[self.idx - 1])
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.stack.len(), Some(self.stack.len()))
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<'s, T, S: Storage, const CAP: usize> DoubleEndedIterator for StackIter<'s, T, S, CAP> {
+    /// Iterates over shared references, from the top.
+    ///
+    /// # Example
+    /// ```
+    /// use ladata::list::DirectStack;
+    ///
+    /// let s = DirectStack::<i32, 4>::from([1, 2, 3]);
+    ///
+    /// let mut si = s.iter();
+    /// assert_eq![Some(&3), si.next_back()];
+    /// assert_eq![Some(&1), si.next()];
+    /// assert_eq![Some(&2), si.next_back()];
+    /// assert_eq![None, si.next()];
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.idx == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(&self.stack.array[self.end])
+        }
     }
 }
 
 impl<'s, T, S: Storage, const CAP: usize> ExactSizeIterator for StackIter<'s, T, S, CAP> {}
+impl<'s, T, S: Storage, const CAP: usize> FusedIterator for StackIter<'s, T, S, CAP> {}
+
+/// A stack iterator over exclusive references, from the bottom to the top.
+///
+/// Created by [`Stack::iter_mut`].
+pub struct StackIterMut<'s, T> {
+    iter: slice::IterMut<'s, T>,
+}
+
+impl<'s, T> Iterator for StackIterMut<'s, T> {
+    type Item = &'s mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<'s, T> DoubleEndedIterator for StackIterMut<'s, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+impl<'s, T> ExactSizeIterator for StackIterMut<'s, T> {}
+impl<'s, T> FusedIterator for StackIterMut<'s, T> {}
+
+/// A draining stack iterator, from the bottom to the top.
+///
+/// Created by [`Stack::drain`].
+pub struct StackDrain<'s, T: Default, S: Storage, const CAP: usize> {
+    stack: &'s mut Stack<T, S, CAP>,
+    // the index of the next element drained from the bottom
+    idx: usize,
+}
+
+impl<'s, T: Default, S: Storage, const CAP: usize> Iterator for StackDrain<'s, T, S, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.idx == self.stack.len {
+            None
+        } else {
+            self.idx += 1;
+            Some(core::mem::take(&mut self.stack.array[self.idx - 1]))
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.stack.len - self.idx;
+        (len, Some(len))
+    }
+}
+impl<'s, T: Default, S: Storage, const CAP: usize> DoubleEndedIterator
+    for StackDrain<'s, T, S, CAP>
+{
+    fn next_back(&mut self) -> Option<T> {
+        if self.idx == self.stack.len {
+            None
+        } else {
+            self.stack.len -= 1;
+            Some(core::mem::take(&mut self.stack.array[self.stack.len]))
+        }
+    }
+}
+impl<'s, T: Default, S: Storage, const CAP: usize> ExactSizeIterator for StackDrain<'s, T, S, CAP> {}
+impl<'s, T: Default, S: Storage, const CAP: usize> FusedIterator for StackDrain<'s, T, S, CAP> {}
+impl<'s, T: Default, S: Storage, const CAP: usize> Drop for StackDrain<'s, T, S, CAP> {
+    /// Drops the elements that were not consumed, emptying the stack.
+    fn drop(&mut self) {
+        self.for_each(drop);
+        self.stack.len = 0;
+    }
+}
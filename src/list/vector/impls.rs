@@ -0,0 +1,283 @@
+// ladata::list::vector::impls
+//
+//! Vector trait implementations and conversions.
+//
+
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use super::{Array, Storage, Vector};
+use crate::{
+    list::{Deque, Queue, Stack},
+    misc::DataCollection,
+};
+
+#[cfg(feature = "alloc")]
+use crate::mem::Boxed;
+
+impl<T, S: Storage, const CAP: usize> Deref for Vector<T, S, CAP> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T, S: Storage, const CAP: usize> DerefMut for Vector<T, S, CAP> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+// T:Clone
+impl<T: Clone, S: Storage, const CAP: usize> Clone for Vector<T, S, CAP>
+where
+    S::Stored<[T; CAP]>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array.clone(),
+            len: self.len,
+        }
+    }
+}
+
+// T:Copy
+impl<T: Copy, S: Storage, const CAP: usize> Copy for Vector<T, S, CAP> where
+    S::Stored<[T; CAP]>: Copy
+{
+}
+
+// T:Debug
+impl<T: fmt::Debug, S: Storage, const CAP: usize> fmt::Debug for Vector<T, S, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct(stringify![Vector]);
+        debug.field("CAP", &CAP).field("len", &self.len);
+
+        if self.len <= 6 {
+            debug.field("elements", &self.as_slice());
+        } else {
+            // IMPROVE: show first 3 and last 3
+            debug.field("elements { ... }", &());
+        }
+        debug.finish()
+    }
+}
+
+// T:PartialEq
+impl<T: PartialEq, S: Storage, const CAP: usize> PartialEq for Vector<T, S, CAP> {
+    /// Compares only the current elements, not the remaining free data.
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+// T:Eq
+impl<T: Eq, S: Storage, const CAP: usize> Eq for Vector<T, S, CAP> {}
+
+// S:() + T:Default
+impl<T: Default, const CAP: usize> Default for Vector<T, (), CAP> {
+    /// Returns an empty vector, allocated in the stack,
+    /// using the default value to fill the remaining free data.
+    fn default() -> Self {
+        Self {
+            array: Array::default(),
+            len: 0,
+        }
+    }
+}
+
+// S:Boxed + T:Default
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize> Default for Vector<T, Boxed, CAP> {
+    /// Returns an empty vector, allocated in the heap,
+    /// using the default value to fill the remaining free data.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedVector;
+    ///
+    /// let v = BoxedVector::<i32, 100>::default();
+    /// ```
+    fn default() -> Self {
+        Self {
+            array: Array::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<T, S: Storage, const CAP: usize> DataCollection for Vector<T, S, CAP> {
+    type Element = T;
+
+    fn collection_is_empty(&self) -> Option<bool> {
+        Some(self.is_empty())
+    }
+    fn collection_is_full(&self) -> Option<bool> {
+        Some(self.is_full())
+    }
+    fn collection_capacity(&self) -> usize {
+        CAP
+    }
+    fn collection_len(&self) -> usize {
+        self.len()
+    }
+}
+
+/* From<[T; N]> */
+
+impl<T: Default, const CAP: usize, const N: usize> From<[T; N]> for Vector<T, (), CAP> {
+    /// Returns a vector filled with the elements of an array, in the stack.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let v: DirectVector<_, 3> = [1, 2, 3].into();
+    /// ```
+    fn from(array: [T; N]) -> Vector<T, (), CAP> {
+        array.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize, const N: usize> From<[T; N]> for Vector<T, Boxed, CAP> {
+    /// Returns a vector filled with the elements of an array, in the heap.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedVector;
+    ///
+    /// let v: BoxedVector<_, 3> = [1, 2, 3].into();
+    /// ```
+    fn from(array: [T; N]) -> Vector<T, Boxed, CAP> {
+        array.into_iter().collect()
+    }
+}
+
+/* FromIterator */
+
+impl<T: Default, const CAP: usize> FromIterator<T> for Vector<T, (), CAP> {
+    /// Returns a vector filled with an iterator, in the stack.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let v: DirectVector<_, 3> = (1..=5).collect();
+    /// assert_eq![&[1, 2, 3], v.as_slice()];
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut v = Self::default();
+        // the elements that don't fit are discarded, as documented
+        let _ = v.extend(iterator);
+        v
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Default, const CAP: usize> FromIterator<T> for Vector<T, Boxed, CAP> {
+    /// Returns a vector filled with an iterator, in the heap.
+    ///
+    /// The elements that don't fit in the capacity are discarded.
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> Self {
+        let mut v = Self::default();
+        // the elements that don't fit are discarded, as documented
+        let _ = v.extend(iterator);
+        v
+    }
+}
+
+/* conversions with other lists */
+
+impl<T, S: Storage, const CAP: usize> From<Stack<T, S, CAP>> for Vector<T, S, CAP> {
+    /// Converts a stack into a vector, from the bottom to the top.
+    fn from(stack: Stack<T, S, CAP>) -> Self {
+        Vector {
+            array: stack.array,
+            len: stack.len,
+        }
+    }
+}
+impl<T, S: Storage, const CAP: usize> From<Vector<T, S, CAP>> for Stack<T, S, CAP> {
+    /// Converts a vector into a stack, with the last element at the top.
+    fn from(vector: Vector<T, S, CAP>) -> Self {
+        Stack {
+            array: vector.array,
+            len: vector.len,
+        }
+    }
+}
+
+impl<T, S: Storage, const CAP: usize> From<Queue<T, S, CAP>> for Vector<T, S, CAP> {
+    /// Converts a queue into a vector, from the front to the back.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::{DirectQueue, DirectVector};
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut q = DirectQueue::<_, 3>::from([1, 2, 3]);
+    /// q.pop()?;
+    /// q.push(4)?;
+    /// let v = DirectVector::from(q);
+    /// assert_eq![&[2, 3, 4], v.as_slice()];
+    ///
+    /// let mut q = DirectQueue::from(v);
+    /// assert_eq![2, q.pop()?];
+    /// q.push(5)?;
+    /// assert_eq![&[3, 4, 5], DirectVector::from(q).as_slice()];
+    /// # Ok(()) }
+    /// ```
+    fn from(queue: Queue<T, S, CAP>) -> Self {
+        let mut array = queue.array;
+        array.rotate_left(queue.front);
+        Vector {
+            array,
+            len: queue.len,
+        }
+    }
+}
+impl<T, S: Storage, const CAP: usize> From<Vector<T, S, CAP>> for Queue<T, S, CAP> {
+    /// Converts a vector into a queue, with the first element at the front.
+    fn from(vector: Vector<T, S, CAP>) -> Self {
+        Queue {
+            array: vector.array,
+            len: vector.len,
+            front: 0,
+            back: if vector.len == CAP { 0 } else { vector.len },
+        }
+    }
+}
+
+impl<T, S: Storage, const CAP: usize> From<Deque<T, S, CAP>> for Vector<T, S, CAP> {
+    /// Converts a deque into a vector, from the front to the back.
+    fn from(deque: Deque<T, S, CAP>) -> Self {
+        let mut array = deque.array;
+        array.rotate_left(deque.front);
+        Vector {
+            array,
+            len: deque.len,
+        }
+    }
+}
+impl<T, S: Storage, const CAP: usize> From<Vector<T, S, CAP>> for Deque<T, S, CAP> {
+    /// Converts a vector into a deque, with the first element at the front.
+    fn from(vector: Vector<T, S, CAP>) -> Self {
+        Deque {
+            array: vector.array,
+            len: vector.len,
+            front: 0,
+            back: if vector.len == CAP { 0 } else { vector.len },
+        }
+    }
+}
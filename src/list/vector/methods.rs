@@ -0,0 +1,602 @@
+// ladata::list::vector::methods
+//
+//! Vectors.
+//
+
+use core::ops::RangeBounds;
+
+use super::{Vector, VectorDrain};
+use crate::{
+    error::{LadataError as Error, LadataResult as Result},
+    list::{range_bounds, Array},
+    mem::Storage,
+};
+
+#[cfg(feature = "alloc")]
+use crate::mem::Boxed;
+
+// `S:() + T:Clone`
+impl<T: Clone, const CAP: usize> Vector<T, (), CAP> {
+    /// Returns an empty vector, allocated in the stack,
+    /// using `element` to fill the remaining free data.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let v = DirectVector::<_, 16>::new(0);
+    /// ```
+    pub fn new(element: T) -> Self {
+        Self {
+            array: Array::<T, (), CAP>::with(element),
+            len: 0,
+        }
+    }
+}
+
+// `S:Boxed + T:Clone`
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+impl<T: Clone, const CAP: usize> Vector<T, Boxed, CAP> {
+    /// Returns an empty vector, allocated in the heap,
+    /// using `element` to fill the remaining free data.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::BoxedVector;
+    ///
+    /// let v = BoxedVector::<_, 100>::new(0);
+    /// ```
+    pub fn new(element: T) -> Self {
+        Self {
+            array: Array::<T, Boxed, CAP>::with(element),
+            len: 0,
+        }
+    }
+}
+
+// ``
+impl<T, S: Storage, const CAP: usize> Vector<T, S, CAP> {
+    /// Returns the number of elements in the vector.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let v = DirectVector::<i32, 8>::default();
+    /// assert![v.is_empty()];
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the vector is full.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let v = DirectVector::<_, 3>::from([1, 2, 3]);
+    /// assert![v.is_full()];
+    /// ```
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len() == CAP
+    }
+
+    /// Returns the vector's total capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let v = DirectVector::<i32, 3>::default();
+    /// assert_eq![3, v.capacity()];
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Returns the vector's remaining capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut v = DirectVector::<i32, 3>::default();
+    /// assert_eq![3, v.remaining_capacity()];
+    /// v.push(1)?;
+    /// assert_eq![2, v.remaining_capacity()];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        CAP - self.len()
+    }
+
+    //
+
+    /// Returns the vector as a shared slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let v = DirectVector::<_, 4>::from([1, 2, 3]);
+    /// assert_eq![v.as_slice(), &[1, 2, 3]];
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.array[..self.len]
+    }
+
+    /// Returns the vector as an exclusive slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let mut v = DirectVector::<_, 4>::from([1, 2, 3]);
+    /// assert_eq![v.as_mut_slice(), &mut [1, 2, 3]];
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.array[..self.len]
+    }
+
+    /// Extends the vector from an iterator.
+    ///
+    /// # Errors
+    /// Errors if the vector becomes full while the iterator still has elements,
+    /// in which case the first element that doesn't fit is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let mut v = DirectVector::<_, 5>::default();
+    /// assert![v.extend([1, 2, 3]).is_ok()];
+    /// assert_eq![v.as_slice(), &[1, 2, 3]];
+    ///
+    /// assert![v.extend([4, 5]).is_ok()];
+    /// assert_eq![v.as_slice(), &[1, 2, 3, 4, 5]];
+    ///
+    /// assert![v.extend([6]).is_err()];
+    /// assert![v.extend([]).is_ok()];
+    /// ```
+    pub fn extend<I>(&mut self, iterator: I) -> Result<()>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iterator.into_iter().peekable();
+        while iter.peek().is_some() {
+            if self.is_full() {
+                return Err(Error::NotEnoughSpace(None));
+            }
+            if let Some(e) = iter.next() {
+                let _ = self.push(e);
+            }
+        }
+        Ok(())
+    }
+
+    //
+
+    /// Appends an element to the back of the vector.
+    ///
+    /// # Errors
+    /// Errors if the vector is full.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut v = DirectVector::<u8, 2>::default();
+    /// v.push(1)?;
+    /// v.push(2)?;
+    /// assert![v.push(3).is_err()];
+    /// assert_eq![&[1, 2], v.as_slice()];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    pub fn push(&mut self, element: T) -> Result<()> {
+        if self.is_full() {
+            Err(Error::NotEnoughSpace(Some(1)))
+        } else {
+            self.array[self.len] = element;
+            self.len += 1;
+            Ok(())
+        }
+    }
+
+    /// Inserts an element at `index`, shifting all the elements after it
+    /// to the right.
+    ///
+    /// # Errors
+    /// Errors if the vector is full, or if `index > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut v = DirectVector::<_, 4>::from([1, 2, 3]);
+    /// v.insert(1, 9)?;
+    /// assert_eq![&[1, 9, 2, 3], v.as_slice()];
+    /// # Ok(()) }
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) -> Result<()> {
+        if index > self.len {
+            Err(Error::IndexOutOfBounds(index))
+        } else if self.is_full() {
+            Err(Error::NotEnoughSpace(Some(1)))
+        } else {
+            self.array[self.len] = element;
+            self.array[index..=self.len].rotate_right(1);
+            self.len += 1;
+            Ok(())
+        }
+    }
+
+    /// Shortens the vector, keeping the first `len` elements.
+    ///
+    /// Does nothing if `len` is greater than the current length.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let mut v = DirectVector::<_, 4>::from([1, 2, 3, 4]);
+    /// v.truncate(2);
+    /// assert_eq![&[1, 2], v.as_slice()];
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.len = len;
+        }
+    }
+
+    /// Clears the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let mut v = DirectVector::<_, 8>::from([1, 2, 3, 4]);
+    /// v.clear();
+    /// assert![v.is_empty()];
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Retains only the elements for which the predicate returns `true`,
+    /// preserving their order.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    ///
+    /// let mut v = DirectVector::<_, 6>::from([1, 2, 3, 4, 5, 6]);
+    /// v.retain(|e| e % 2 == 0);
+    /// assert_eq![&[2, 4, 6], v.as_slice()];
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut kept = 0;
+        for i in 0..self.len {
+            if f(&self.array[i]) {
+                self.array.swap(kept, i);
+                kept += 1;
+            }
+        }
+        self.len = kept;
+    }
+}
+
+// `T:Clone`
+impl<T: Clone, S: Storage, const CAP: usize> Vector<T, S, CAP> {
+    /// Removes the last element from the vector and returns it.
+    ///
+    /// # Errors
+    /// Errors if the vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut v = DirectVector::<_, 2>::from([1, 2]);
+    /// assert_eq![2, v.pop()?];
+    /// assert_eq![1, v.pop()?];
+    /// assert![v.pop().is_err()];
+    /// # Ok(()) }
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Result<T> {
+        if self.is_empty() {
+            Err(Error::NotEnoughElements(1))
+        } else {
+            self.len -= 1;
+            Ok(self.array[self.len].clone())
+        }
+    }
+
+    /// Removes and returns the element at `index`, shifting all the elements
+    /// after it to the left.
+    ///
+    /// # Errors
+    /// Errors if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut v = DirectVector::<_, 4>::from([1, 2, 3, 4]);
+    /// assert_eq![2, v.remove(1)?];
+    /// assert_eq![&[1, 3, 4], v.as_slice()];
+    /// # Ok(()) }
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Result<T> {
+        if index >= self.len {
+            Err(Error::IndexOutOfBounds(index))
+        } else {
+            self.array[index..self.len].rotate_left(1);
+            self.pop()
+        }
+    }
+
+    /// Removes and returns the element at `index`, replacing it with
+    /// the last element.
+    ///
+    /// This doesn't preserve the order, but it's *O(1)*.
+    ///
+    /// # Errors
+    /// Errors if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut v = DirectVector::<_, 4>::from([1, 2, 3, 4]);
+    /// assert_eq![1, v.swap_remove(0)?];
+    /// assert_eq![&[4, 2, 3], v.as_slice()];
+    /// # Ok(()) }
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> Result<T> {
+        if index >= self.len {
+            Err(Error::IndexOutOfBounds(index))
+        } else {
+            self.array.swap(index, self.len - 1);
+            self.pop()
+        }
+    }
+
+    /// Appends all the elements of a slice, cloning them.
+    ///
+    /// # Errors
+    /// Errors if there's not enough space for all the elements,
+    /// in which case none is appended.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut v = DirectVector::<_, 4>::from([1]);
+    /// v.extend_from_slice(&[2, 3])?;
+    /// assert_eq![&[1, 2, 3], v.as_slice()];
+    /// assert![v.extend_from_slice(&[4, 5]).is_err()];
+    /// # Ok(()) }
+    /// ```
+    pub fn extend_from_slice(&mut self, slice: &[T]) -> Result<()> {
+        if slice.len() > self.remaining_capacity() {
+            Err(Error::NotEnoughSpace(Some(
+                slice.len() - self.remaining_capacity(),
+            )))
+        } else {
+            self.array[self.len..self.len + slice.len()].clone_from_slice(slice);
+            self.len += slice.len();
+            Ok(())
+        }
+    }
+
+    /// Splits the vector in two at `at`, returning a new vector with
+    /// the elements from `at` onwards.
+    ///
+    /// # Errors
+    /// Errors if `at > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut v = DirectVector::<_, 4>::from([1, 2, 3, 4]);
+    /// let w = v.split_off(1)?;
+    /// assert_eq![&[1], v.as_slice()];
+    /// assert_eq![&[2, 3, 4], w.as_slice()];
+    /// # Ok(()) }
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Result<Self>
+    where
+        S::Stored<[T; CAP]>: Clone,
+    {
+        if at > self.len {
+            Err(Error::IndexOutOfBounds(at))
+        } else {
+            let mut other = self.clone();
+            other.array.rotate_left(at);
+            other.len = self.len - at;
+            self.len = at;
+            Ok(other)
+        }
+    }
+}
+
+// `T:Default`
+impl<T: Default, S: Storage, const CAP: usize> Vector<T, S, CAP> {
+    /// Removes the elements in `range` from the vector, returning them
+    /// in an iterator.
+    ///
+    /// The drained elements are moved out one by one as the iterator advances,
+    /// leaving their default value behind. When the iterator is dropped, the
+    /// ones that were not consumed are dropped too, and the elements after
+    /// the range are shifted to the left.
+    ///
+    /// # Errors
+    /// Errors if the range is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::list::DirectVector;
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut v = DirectVector::<_, 5>::from([1, 2, 3, 4, 5]);
+    /// let mut d = v.drain(1..3)?;
+    /// assert_eq![Some(2), d.next()];
+    /// assert_eq![Some(3), d.next()];
+    /// assert_eq![None, d.next()];
+    /// drop(d);
+    /// assert_eq![&[1, 4, 5], v.as_slice()];
+    ///
+    /// let mut d = v.drain(..2)?;
+    /// assert_eq![Some(1), d.next()];
+    /// drop(d);
+    /// assert_eq![&[5], v.as_slice()];
+    /// # Ok(()) }
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Result<VectorDrain<'_, T, S, CAP>> {
+        let (start, end) = range_bounds(&range, self.len)?;
+        Ok(VectorDrain {
+            vector: self,
+            idx: start,
+            end,
+            start,
+            count: end - start,
+        })
+    }
+
+    /// Closes the gap left by `count` taken elements at `start`,
+    /// shifting the elements after it to the left.
+    pub(super) fn close_gap(&mut self, start: usize, count: usize) {
+        for i in start + count..self.len {
+            self.array[i - count] = core::mem::take(&mut self.array[i]);
+        }
+        self.len -= count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_remove_at_capacity() {
+        let mut v = Vector::<_, (), 3>::default();
+        assert_eq![Err(Error::IndexOutOfBounds(1)), v.insert(1, 1)];
+        assert_eq![Ok(()), v.insert(0, 2)];
+        assert_eq![Ok(()), v.insert(1, 3)];
+        assert_eq![Ok(()), v.insert(0, 1)];
+        assert_eq![&[1, 2, 3], v.as_slice()];
+
+        // full
+        assert_eq![Err(Error::NotEnoughSpace(Some(1))), v.insert(3, 4)];
+        assert_eq![Err(Error::IndexOutOfBounds(4)), v.insert(4, 4)];
+        assert_eq![Err(Error::NotEnoughSpace(Some(1))), v.push(4)];
+        assert_eq![&[1, 2, 3], v.as_slice()];
+
+        assert_eq![Err(Error::IndexOutOfBounds(3)), v.remove(3)];
+        assert_eq![Ok(3), v.remove(2)];
+        assert_eq![Ok(1), v.remove(0)];
+        assert_eq![Ok(2), v.swap_remove(0)];
+        assert_eq![Err(Error::IndexOutOfBounds(0)), v.remove(0)];
+        assert_eq![Err(Error::IndexOutOfBounds(0)), v.swap_remove(0)];
+        assert_eq![Err(Error::NotEnoughElements(1)), v.pop()];
+    }
+
+    #[test]
+    fn extend_at_capacity() {
+        let mut v = Vector::<_, (), 4>::default();
+        assert_eq![Ok(()), v.extend([1, 2, 3, 4])];
+        assert![v.is_full()];
+        assert_eq![Ok(()), v.extend([])];
+
+        // the elements that fit are appended, the next one is dropped,
+        // and the rest are left over
+        let mut v = Vector::<_, (), 4>::from([1, 2]);
+        let mut iter = 3..8;
+        assert_eq![Err(Error::NotEnoughSpace(None)), v.extend(&mut iter)];
+        assert_eq![&[1, 2, 3, 4], v.as_slice()];
+        assert![iter.eq(6..8)];
+
+        // the elements that don't fit are discarded when converting
+        let v = Vector::<_, (), 4>::from([1, 2, 3, 4, 5, 6]);
+        assert_eq![&[1, 2, 3, 4], v.as_slice()];
+        let v = Vector::<_, (), 0>::from([1, 2, 3]);
+        assert![v.is_empty()];
+    }
+
+    #[test]
+    fn drain_ranges() {
+        let a = [1, 2, 3, 4, 5];
+        for start in 0..=5 {
+            for end in start..=5 {
+                let rest = a[..start].iter().chain(&a[end..]);
+
+                let mut v = Vector::<_, (), 6>::from(a);
+                assert![v
+                    .drain(start..end)
+                    .unwrap()
+                    .eq(a[start..end].iter().copied())];
+                assert![v.iter().eq(rest.clone())];
+
+                // partially consumed from both ends
+                let mut v = Vector::<_, (), 6>::from(a);
+                let mut d = v.drain(start..end).unwrap();
+                if end - start >= 2 {
+                    assert_eq![Some(a[start]), d.next()];
+                    assert_eq![Some(a[end - 1]), d.next_back()];
+                    assert_eq![end - start - 2, d.len()];
+                }
+                drop(d);
+                assert![v.iter().eq(rest)];
+            }
+        }
+
+        let mut v = Vector::<_, (), 6>::from(a);
+        assert_eq![Err(Error::IndexOutOfBounds(6)), v.drain(..6).map(|_| ())];
+        assert![v.drain(5..).unwrap().next().is_none()];
+        assert_eq![&a, v.as_slice()];
+    }
+
+    #[test]
+    fn drain_moves_out() {
+        #[derive(Debug, Default, PartialEq)]
+        struct NoClone(u8);
+
+        let mut v = Vector::<_, (), 4>::default();
+        for i in 0..4 {
+            assert_eq![Ok(()), v.push(NoClone(i))];
+        }
+        let mut d = v.drain(1..3).unwrap();
+        assert_eq![Some(NoClone(1)), d.next()];
+        drop(d);
+        assert_eq![&[NoClone(0), NoClone(3)], v.as_slice()];
+        // the slots past the length are left with their default value
+        assert_eq![&[NoClone(0), NoClone(0)], &v.array[2..]];
+    }
+}
@@ -0,0 +1,101 @@
+// ladata::list::vector
+//
+//! Vectors are linear lists with a length that can grow and shrink,
+//! up to a fixed capacity.
+//
+
+use core::iter::FusedIterator;
+
+use crate::{list::Array, mem::Storage};
+
+#[cfg(feature = "alloc")]
+use crate::mem::Boxed;
+
+mod impls;
+mod methods;
+
+/// A vector with a fixed capacity, backed by an [`Array`].
+///
+/// It dereferences to a slice of its current elements.
+///
+/// # Examples
+/// ```
+/// use ladata::list::DirectVector;
+/// # fn main() -> ladata::error::LadataResult<()> {
+///
+/// let mut v = DirectVector::<u8, 8>::from([3, 1, 2]);
+/// v.push(0)?;
+/// v.sort();
+/// assert_eq![&[0, 1, 2, 3], &v[..]];
+/// assert_eq![Some(&3), v.last()];
+/// # Ok(()) }
+/// ```
+pub struct Vector<T, S: Storage, const CAP: usize> {
+    pub(crate) array: Array<T, S, CAP>,
+    pub(crate) len: usize,
+}
+
+/// A [`Vector`] stored in the stack.
+pub type DirectVector<T, const CAP: usize> = Vector<T, (), CAP>;
+
+/// A [`Vector`] stored in the heap.
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub type BoxedVector<T, const CAP: usize> = Vector<T, Boxed, CAP>;
+
+/* iterators */
+
+/// A draining vector iterator.
+///
+/// Created by [`Vector::drain`].
+pub struct VectorDrain<'v, T: Default, S: Storage, const CAP: usize> {
+    vector: &'v mut Vector<T, S, CAP>,
+    // the index of the next drained element from each end
+    idx: usize,
+    end: usize,
+    // the position and length of the drained range
+    start: usize,
+    count: usize,
+}
+
+impl<'v, T: Default, S: Storage, const CAP: usize> Iterator for VectorDrain<'v, T, S, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            None
+        } else {
+            self.idx += 1;
+            Some(core::mem::take(&mut self.vector.array[self.idx - 1]))
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+impl<'v, T: Default, S: Storage, const CAP: usize> DoubleEndedIterator
+    for VectorDrain<'v, T, S, CAP>
+{
+    fn next_back(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(core::mem::take(&mut self.vector.array[self.end]))
+        }
+    }
+}
+impl<'v, T: Default, S: Storage, const CAP: usize> ExactSizeIterator
+    for VectorDrain<'v, T, S, CAP>
+{
+}
+impl<'v, T: Default, S: Storage, const CAP: usize> FusedIterator for VectorDrain<'v, T, S, CAP> {}
+impl<'v, T: Default, S: Storage, const CAP: usize> Drop for VectorDrain<'v, T, S, CAP> {
+    /// Drops the drained elements that were not consumed,
+    /// and shifts the following elements to close the gap.
+    fn drop(&mut self) {
+        self.for_each(drop);
+        self.vector.close_gap(self.start, self.count);
+    }
+}
@@ -0,0 +1,456 @@
+// ladata::schema::methods
+//
+//! Methods for the schemas and records.
+//
+
+use super::{DataSchema, SchemaArray};
+use crate::{
+    error::{LadataError as Error, LadataResult as Result},
+    unit::DataTypeAccepts,
+};
+
+#[cfg(feature = "alloc")]
+use {
+    super::{Record, Schema},
+    alloc::{string::String, vec::Vec},
+};
+
+/* Schema */
+
+/// # Constructors
+#[cfg(feature = "alloc")]
+impl<T: DataTypeAccepts + PartialEq> Schema<T> {
+    /// Returns a new empty schema.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { fields: Vec::new() }
+    }
+
+    /// Returns a new schema with the given `fields`.
+    ///
+    /// # Errors
+    /// Returns [`KeyAlreadyExists`][Error::KeyAlreadyExists] if a name is repeated.
+    pub fn from_fields<N, I>(fields: I) -> Result<Self>
+    where
+        N: Into<String>,
+        I: IntoIterator<Item = (N, T)>,
+    {
+        let mut schema = Self::new();
+        for (name, data_type) in fields {
+            schema.push(name, data_type)?;
+        }
+        Ok(schema)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DataTypeAccepts + PartialEq> Default for Schema<T> {
+    /// Returns a new empty schema.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # Fields
+#[cfg(feature = "alloc")]
+impl<T: DataTypeAccepts + PartialEq> Schema<T> {
+    /// Appends a new field at the end.
+    ///
+    /// # Errors
+    /// Returns [`KeyAlreadyExists`][Error::KeyAlreadyExists]
+    /// if there's already a field with the same `name`.
+    pub fn push<N: Into<String>>(&mut self, name: N, data_type: T) -> Result<()> {
+        let name = name.into();
+        if self.index_of(&name).is_some() {
+            return Err(Error::KeyAlreadyExists);
+        }
+        self.fields.push((name, data_type));
+        Ok(())
+    }
+
+    /// Returns the number of fields.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if there are no fields.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns the name and the *data type* of the field at `index`.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= len()`.
+    #[inline]
+    pub fn field(&self, index: usize) -> Result<(&str, T)> {
+        self.schema_field(index)
+            .ok_or(Error::IndexOutOfBounds(index))
+    }
+
+    /// Returns the index of the field with the given `name`.
+    #[inline]
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|(n, _)| n == name)
+    }
+
+    /// Returns the *data type* of the field with the given `name`.
+    #[inline]
+    pub fn type_of(&self, name: &str) -> Option<T> {
+        self.schema_type_of(name)
+    }
+
+    /// Returns an iterator over the names and *data types* of the fields.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, T)> {
+        self.fields.iter().map(|(n, t)| (n.as_str(), *t))
+    }
+
+    /// Returns `true` if every field of `other` is also a field of this schema,
+    /// with the same *data type*, in any order.
+    #[inline]
+    pub fn is_compatible<S: DataSchema<Type = T>>(&self, other: &S) -> bool {
+        self.schema_is_compatible(other)
+    }
+
+    /// Returns a new schema with only the fields with the given `names`, in that order.
+    ///
+    /// # Errors
+    /// Returns [`KeyNotFound`][Error::KeyNotFound] if any name is missing, or
+    /// [`KeyAlreadyExists`][Error::KeyAlreadyExists] if any name is repeated.
+    pub fn project(&self, names: &[&str]) -> Result<Self> {
+        let mut schema = Self::new();
+        for name in names {
+            let data_type = self.type_of(name).ok_or(Error::KeyNotFound)?;
+            schema.push(*name, data_type)?;
+        }
+        Ok(schema)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DataTypeAccepts + PartialEq> DataSchema for Schema<T> {
+    type Type = T;
+
+    #[inline]
+    fn schema_len(&self) -> usize {
+        self.fields.len()
+    }
+    #[inline]
+    fn schema_field(&self, index: usize) -> Option<(&str, T)> {
+        self.fields.get(index).map(|(n, t)| (n.as_str(), *t))
+    }
+    #[inline]
+    fn schema_index_of(&self, name: &str) -> Option<usize> {
+        self.index_of(name)
+    }
+}
+
+/* SchemaArray */
+
+/// # Constructors
+impl<'a, T: DataTypeAccepts, const CAP: usize> SchemaArray<'a, T, CAP> {
+    /// Returns a new empty schema.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            fields: [None; CAP],
+            len: 0,
+        }
+    }
+}
+
+impl<'a, T: DataTypeAccepts + PartialEq, const CAP: usize> SchemaArray<'a, T, CAP> {
+    /// Returns a new schema with the given `fields`.
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughSpace`][Error::NotEnoughSpace] if there are more
+    /// than `CAP` fields, or [`KeyAlreadyExists`][Error::KeyAlreadyExists]
+    /// if a name is repeated.
+    pub fn from_fields(fields: &[(&'a str, T)]) -> Result<Self> {
+        let mut schema = Self::new();
+        for (name, data_type) in fields {
+            schema.push(name, *data_type)?;
+        }
+        Ok(schema)
+    }
+}
+
+impl<'a, T: DataTypeAccepts, const CAP: usize> Default for SchemaArray<'a, T, CAP> {
+    /// Returns a new empty schema.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # Fields
+impl<'a, T: DataTypeAccepts + PartialEq, const CAP: usize> SchemaArray<'a, T, CAP> {
+    /// Appends a new field at the end.
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughSpace`][Error::NotEnoughSpace] if the schema is full,
+    /// or [`KeyAlreadyExists`][Error::KeyAlreadyExists]
+    /// if there's already a field with the same `name`.
+    pub fn push(&mut self, name: &'a str, data_type: T) -> Result<()> {
+        if self.len == CAP {
+            return Err(Error::NotEnoughSpace(Some(1)));
+        }
+        if self.index_of(name).is_some() {
+            return Err(Error::KeyAlreadyExists);
+        }
+        self.fields[self.len] = Some((name, data_type));
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the number of fields.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no fields.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the schema is full.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    /// Returns the maximum number of fields.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Returns the name and the *data type* of the field at `index`.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= len()`.
+    #[inline]
+    pub fn field(&self, index: usize) -> Result<(&'a str, T)> {
+        self.fields[..self.len]
+            .get(index)
+            .copied()
+            .flatten()
+            .ok_or(Error::IndexOutOfBounds(index))
+    }
+
+    /// Returns the index of the field with the given `name`.
+    #[inline]
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.iter().position(|(n, _)| n == name)
+    }
+
+    /// Returns the *data type* of the field with the given `name`.
+    #[inline]
+    pub fn type_of(&self, name: &str) -> Option<T> {
+        self.schema_type_of(name)
+    }
+
+    /// Returns an iterator over the names and *data types* of the fields.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, T)> + '_ {
+        self.fields[..self.len].iter().flatten().copied()
+    }
+
+    /// Returns `true` if every field of `other` is also a field of this schema,
+    /// with the same *data type*, in any order.
+    #[inline]
+    pub fn is_compatible<S: DataSchema<Type = T>>(&self, other: &S) -> bool {
+        self.schema_is_compatible(other)
+    }
+
+    /// Returns a new schema with only the fields with the given `names`, in that order.
+    ///
+    /// # Errors
+    /// Returns [`KeyNotFound`][Error::KeyNotFound] if any name is missing, or
+    /// [`KeyAlreadyExists`][Error::KeyAlreadyExists] if any name is repeated.
+    pub fn project(&self, names: &[&str]) -> Result<Self> {
+        let mut schema = Self::new();
+        for name in names {
+            let index = self.index_of(name).ok_or(Error::KeyNotFound)?;
+            let (name, data_type) = self.field(index)?;
+            schema.push(name, data_type)?;
+        }
+        Ok(schema)
+    }
+}
+
+impl<'a, T: DataTypeAccepts + PartialEq, const CAP: usize> DataSchema for SchemaArray<'a, T, CAP> {
+    type Type = T;
+
+    #[inline]
+    fn schema_len(&self) -> usize {
+        self.len
+    }
+    #[inline]
+    fn schema_field(&self, index: usize) -> Option<(&str, T)> {
+        self.field(index).ok()
+    }
+    #[inline]
+    fn schema_index_of(&self, name: &str) -> Option<usize> {
+        self.index_of(name)
+    }
+}
+
+/* Record */
+
+#[cfg(feature = "alloc")]
+type Unit<S> = <<S as DataSchema>::Type as DataTypeAccepts>::Unit;
+
+/// # Constructors
+#[cfg(feature = "alloc")]
+impl<'s, S: DataSchema> Record<'s, S> {
+    /// Returns a new record of the given `schema`, with the given `units`.
+    ///
+    /// # Errors
+    /// Returns [`DimensionMismatch`][Error::DimensionMismatch] if the number
+    /// of units is different than the number of fields, or
+    /// [`TypeMismatch`][Error::TypeMismatch] if any unit doesn't match
+    /// the type of its field.
+    pub fn new<I: IntoIterator<Item = Unit<S>>>(schema: &'s S, units: I) -> Result<Self> {
+        let units: Vec<Unit<S>> = units.into_iter().collect();
+        if units.len() != schema.schema_len() {
+            return Err(Error::DimensionMismatch);
+        }
+        for (index, unit) in units.iter().enumerate() {
+            Self::check(schema, index, unit)?;
+        }
+        Ok(Self { schema, units })
+    }
+
+    /// Returns the units of the record, in the order of the fields.
+    #[inline]
+    pub fn into_units(self) -> Vec<Unit<S>> {
+        self.units
+    }
+
+    /// Returns a new record of the given sub-`schema`,
+    /// keeping only the units of its fields.
+    ///
+    /// # Errors
+    /// Returns [`KeyNotFound`][Error::KeyNotFound] if a field of `schema`
+    /// is missing from the current schema,
+    /// [`TypeMismatch`][Error::TypeMismatch] if it has a different type, or
+    /// [`KeyAlreadyExists`][Error::KeyAlreadyExists] if it's repeated.
+    pub fn into_projection<'t, P: DataSchema<Type = S::Type>>(
+        self,
+        schema: &'t P,
+    ) -> Result<Record<'t, P>> {
+        let mut units: Vec<Option<Unit<S>>> = self.units.into_iter().map(Some).collect();
+        let mut projected = Vec::with_capacity(schema.schema_len());
+        for index in 0..schema.schema_len() {
+            let (name, data_type) = schema
+                .schema_field(index)
+                .ok_or(Error::IndexOutOfBounds(index))?;
+            let from = self
+                .schema
+                .schema_index_of(name)
+                .ok_or(Error::KeyNotFound)?;
+            if self.schema.schema_field(from).map(|(_, t)| t) != Some(data_type) {
+                return Err(Error::TypeMismatch);
+            }
+            projected.push(units[from].take().ok_or(Error::KeyAlreadyExists)?);
+        }
+        Ok(Record {
+            schema,
+            units: projected,
+        })
+    }
+
+    /// Returns `Ok` if the `unit` matches the type of the field at `index`.
+    fn check(schema: &S, index: usize, unit: &Unit<S>) -> Result<()> {
+        match schema.schema_field(index) {
+            Some((_, data_type)) if data_type.accepts(unit) => Ok(()),
+            Some(_) => Err(Error::TypeMismatch),
+            None => Err(Error::IndexOutOfBounds(index)),
+        }
+    }
+}
+
+/// # Queries
+#[cfg(feature = "alloc")]
+impl<'s, S: DataSchema> Record<'s, S> {
+    /// Returns the schema of the record.
+    #[inline]
+    pub fn schema(&self) -> &'s S {
+        self.schema
+    }
+
+    /// Returns the number of units.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    /// Returns `true` if the record has no units.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// Returns a shared reference to the unit at `index`.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= len()`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Result<&Unit<S>> {
+        self.units.get(index).ok_or(Error::IndexOutOfBounds(index))
+    }
+
+    /// Returns a shared reference to the unit of the field with the given `name`.
+    ///
+    /// # Errors
+    /// Returns [`KeyNotFound`][Error::KeyNotFound] if there's no such field.
+    #[inline]
+    pub fn get_by_name(&self, name: &str) -> Result<&Unit<S>> {
+        let index = self
+            .schema
+            .schema_index_of(name)
+            .ok_or(Error::KeyNotFound)?;
+        self.get(index)
+    }
+
+    /// Returns an iterator over the names of the fields and their units.
+    pub fn iter(&self) -> impl Iterator<Item = (&'s str, &Unit<S>)> {
+        let schema = self.schema;
+        self.units
+            .iter()
+            .enumerate()
+            .filter_map(move |(i, unit)| schema.schema_field(i).map(|(name, _)| (name, unit)))
+    }
+}
+
+/// # Mutation
+#[cfg(feature = "alloc")]
+impl<'s, S: DataSchema> Record<'s, S> {
+    /// Replaces the unit at `index`, returning the old one.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `index >= len()`, or
+    /// [`TypeMismatch`][Error::TypeMismatch] if the `unit` doesn't match
+    /// the type of its field.
+    pub fn set(&mut self, index: usize, unit: Unit<S>) -> Result<Unit<S>> {
+        Self::check(self.schema, index, &unit)?;
+        Ok(core::mem::replace(&mut self.units[index], unit))
+    }
+
+    /// Replaces the unit of the field with the given `name`, returning the old one.
+    ///
+    /// # Errors
+    /// Returns [`KeyNotFound`][Error::KeyNotFound] if there's no such field, or
+    /// [`TypeMismatch`][Error::TypeMismatch] if the `unit` doesn't match
+    /// the type of its field.
+    pub fn set_by_name(&mut self, name: &str, unit: Unit<S>) -> Result<Unit<S>> {
+        let index = self
+            .schema
+            .schema_index_of(name)
+            .ok_or(Error::KeyNotFound)?;
+        self.set(index, unit)
+    }
+}
@@ -0,0 +1,139 @@
+// ladata::schema
+//
+//! Schemas describe the shape of a record as an ordered list of named fields,
+//! each one with its own *data type*.
+//!
+//! A [`Record`] stores one *data unit* per field, validated against its schema,
+//! so that heterogeneous messages can be checked without defining a struct
+//! for each shape.
+//
+
+use crate::unit::DataTypeAccepts;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+mod methods;
+
+#[cfg(test)]
+mod tests;
+
+/// Common trait for *data schemas*.
+///
+/// A schema is an ordered list of fields with unique names.
+pub trait DataSchema {
+    /// The *data type* of the fields.
+    type Type: DataTypeAccepts + PartialEq;
+
+    /// Returns the number of fields.
+    fn schema_len(&self) -> usize;
+
+    /// Returns the name and the *data type* of the field at `index`.
+    fn schema_field(&self, index: usize) -> Option<(&str, Self::Type)>;
+
+    /// Returns the index of the field with the given `name`.
+    fn schema_index_of(&self, name: &str) -> Option<usize> {
+        (0..self.schema_len()).find(|i| matches![self.schema_field(*i), Some((n, _)) if n == name])
+    }
+
+    /// Returns the *data type* of the field with the given `name`.
+    fn schema_type_of(&self, name: &str) -> Option<Self::Type> {
+        self.schema_field(self.schema_index_of(name)?)
+            .map(|(_, t)| t)
+    }
+
+    /// Returns `true` if both schemas have the same fields in the same order.
+    fn schema_eq<S: DataSchema<Type = Self::Type>>(&self, other: &S) -> bool {
+        self.schema_len() == other.schema_len()
+            && (0..self.schema_len()).all(|i| self.schema_field(i) == other.schema_field(i))
+    }
+
+    /// Returns `true` if every field of `other` is also a field of this schema,
+    /// with the same *data type*, in any order.
+    ///
+    /// In that case a record of this schema can be projected onto `other`.
+    fn schema_is_compatible<S: DataSchema<Type = Self::Type>>(&self, other: &S) -> bool {
+        (0..other.schema_len()).all(|i| {
+            matches![other.schema_field(i),
+                Some((name, t)) if self.schema_type_of(name) == Some(t)]
+        })
+    }
+}
+
+/// A schema of named fields, backed by a [`Vec`].
+///
+/// # Examples
+/// ```
+/// use ladata::all::{DataSchema, DataType32bit as T, Schema};
+/// # fn main() -> ladata::error::LadataResult<()> {
+///
+/// let s = Schema::from_fields([("id", T::U32), ("name", T::ByteArray4), ("ok", T::Bool)])?;
+/// assert_eq![Some(1), s.index_of("name")];
+/// assert_eq![Some(T::Bool), s.type_of("ok")];
+///
+/// let p = s.project(&["ok", "id"])?;
+/// assert![s.is_compatible(&p)];
+/// assert![!p.is_compatible(&s)];
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct Schema<T: DataTypeAccepts> {
+    fields: Vec<(String, T)>,
+}
+
+/// A schema of named fields, backed by an array with a fixed capacity.
+///
+/// # Examples
+/// ```
+/// use ladata::all::{DataType8bitCopy as T, SchemaArray};
+/// # fn main() -> ladata::error::LadataResult<()> {
+///
+/// let mut s = SchemaArray::<T, 2>::new();
+/// s.push("level", T::U8)?;
+/// s.push("on", T::Bool)?;
+/// assert![s.push("extra", T::I8).is_err()];
+/// assert_eq![Some(("on", T::Bool)), s.iter().last()];
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SchemaArray<'a, T: DataTypeAccepts, const CAP: usize> {
+    fields: [Option<(&'a str, T)>; CAP],
+    len: usize,
+}
+
+/// A record of *data units*, validated against a borrowed schema.
+///
+/// # Examples
+/// ```
+/// use ladata::all::{DataType64bit as T, DataUnit64bit as U, Record, Schema};
+/// # fn main() -> ladata::error::LadataResult<()> {
+///
+/// let s = Schema::from_fields([("x", T::F32), ("y", T::F32), ("id", T::U16)])?;
+/// let mut r = Record::new(&s, [U::F32(1.0), U::F32(-2.0), U::U16(7)])?;
+///
+/// assert![matches![r.get_by_name("y")?, U::F32(y) if *y == -2.0]];
+/// assert![r.set_by_name("id", U::U8(8)).is_err()];
+///
+/// let p = Schema::from_fields([("id", T::U16)])?;
+/// let r = r.into_projection(&p)?;
+/// assert![matches![r.into_units()[..], [U::U16(7)]]];
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct Record<'s, S: DataSchema> {
+    schema: &'s S,
+    units: Vec<<S::Type as DataTypeAccepts>::Unit>,
+}
+
+pub(crate) mod all {
+    #[doc(inline)]
+    pub use super::{DataSchema, SchemaArray};
+
+    #[doc(inline)]
+    #[cfg(feature = "alloc")]
+    pub use super::{Record, Schema};
+}
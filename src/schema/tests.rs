@@ -0,0 +1,93 @@
+// ladata::schema::tests
+
+use super::*;
+use crate::{
+    error::LadataError as Error,
+    unit::{DataType16bitCopy as TC, DataType256bit as T, DataUnit256bit as U},
+};
+
+#[test]
+fn schema_array() {
+    let mut s = SchemaArray::<TC, 3>::from_fields(&[("a", TC::U16), ("b", TC::I8)]).unwrap();
+    assert_eq![(2, 3), (s.len(), s.capacity())];
+    assert_eq![Err(Error::KeyAlreadyExists), s.push("a", TC::Bool)];
+    s.push("c", TC::Bool).unwrap();
+    assert![s.is_full()];
+    assert_eq![Err(Error::NotEnoughSpace(Some(1))), s.push("d", TC::Bool)];
+
+    assert_eq![Ok(("b", TC::I8)), s.field(1)];
+    assert_eq![Err(Error::IndexOutOfBounds(3)), s.field(3)];
+    assert_eq![Some(2), s.index_of("c")];
+    assert_eq![None, s.type_of("d")];
+
+    let p = s.project(&["c", "a"]).unwrap();
+    assert_eq![
+        [("c", TC::Bool), ("a", TC::U16)],
+        p.iter().collect::<Vec<_>>()[..]
+    ];
+    assert_eq![Err(Error::KeyNotFound), s.project(&["d"])];
+    assert_eq![Err(Error::KeyAlreadyExists), s.project(&["a", "a"])];
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn schema_equality() {
+    let s = Schema::from_fields([("a", T::U8), ("b", T::F64)]).unwrap();
+    let a = SchemaArray::<T, 4>::from_fields(&[("a", T::U8), ("b", T::F64)]).unwrap();
+    assert![s.schema_eq(&a)];
+    assert![a.schema_eq(&s)];
+    assert_eq![s, s.project(&["a", "b"]).unwrap()];
+
+    // the order matters for equality, but not for compatibility
+    let r = s.project(&["b", "a"]).unwrap();
+    assert![!s.schema_eq(&r)];
+    assert![s.is_compatible(&r) && r.is_compatible(&s)];
+
+    // the types must be the same
+    let t = Schema::from_fields([("a", T::U16)]).unwrap();
+    assert![!s.is_compatible(&t)];
+    assert![s.is_compatible(&Schema::new())];
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn record() {
+    let s = Schema::from_fields([("id", T::U32), ("name", T::String), ("ok", T::Bool)]).unwrap();
+    let name = || U::String("ladata".into());
+
+    assert_eq![
+        Err(Error::DimensionMismatch),
+        Record::new(&s, [U::U32(1), name()]).map(|_| ())
+    ];
+    assert_eq![
+        Err(Error::TypeMismatch),
+        Record::new(&s, [U::U64(1), name(), U::Bool(true)]).map(|_| ())
+    ];
+
+    let mut r = Record::new(&s, [U::U32(1), name(), U::Bool(true)]).unwrap();
+    assert![matches![r.get(0), Ok(U::U32(1))]];
+    assert![matches![r.get_by_name("ok"), Ok(U::Bool(true))]];
+    assert_eq![Err(Error::KeyNotFound), r.get_by_name("none").map(|_| ())];
+    assert_eq![Err(Error::IndexOutOfBounds(3)), r.get(3).map(|_| ())];
+
+    // mutation is validated too
+    assert![matches![r.set(2, U::Bool(false)), Ok(U::Bool(true))]];
+    assert_eq![Err(Error::TypeMismatch), r.set(2, U::U8(0)).map(|_| ())];
+    assert_eq![
+        Err(Error::IndexOutOfBounds(3)),
+        r.set(3, U::Bool(false)).map(|_| ())
+    ];
+    assert![matches![r.set_by_name("id", U::U32(2)), Ok(U::U32(1))]];
+
+    let names: Vec<_> = r.iter().map(|(name, _)| name).collect();
+    assert_eq![["id", "name", "ok"], names[..]];
+
+    // projection onto a sub-schema
+    let p = SchemaArray::<T, 2>::from_fields(&[("ok", T::Bool), ("id", T::U32)]).unwrap();
+    let r = r.into_projection(&p).unwrap();
+    assert![matches![r.into_units()[..], [U::Bool(false), U::U32(2)]]];
+
+    let r = Record::new(&s, [U::U32(1), name(), U::Bool(true)]).unwrap();
+    let p = Schema::from_fields([("id", T::U64)]).unwrap();
+    assert_eq![Err(Error::TypeMismatch), r.into_projection(&p).map(|_| ())];
+}
@@ -0,0 +1,326 @@
+// ladata::table::methods
+//
+//! Methods for the data table.
+//
+
+use super::DataTable;
+use crate::{
+    error::{LadataError as Error, LadataResult as Result},
+    mem::Storage,
+    unit::DataColumn,
+};
+use alloc::vec::Vec;
+
+/// # constructors
+impl<C: DataColumn, S: Storage> DataTable<C, S> {
+    /// Returns a new empty table, without columns.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            columns: S::Stored::from(Vec::new()),
+            rows: 0,
+        }
+    }
+
+    /// Returns a new empty table with the given column `types`.
+    pub fn with_types(types: &[C::Type]) -> Self {
+        let columns: Vec<C> = types.iter().map(|t| C::column_new(*t)).collect();
+        Self {
+            columns: S::Stored::from(columns),
+            rows: 0,
+        }
+    }
+
+    /// Returns a new table with the given column `types`, filled with `rows` of units.
+    ///
+    /// # Errors
+    /// Returns [`DimensionMismatch`][Error::DimensionMismatch] if any row
+    /// has a different number of units than `types`, or
+    /// [`TypeMismatch`][Error::TypeMismatch] if any unit doesn't match
+    /// the type of its column.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::all::{DataType32bit as T, DataUnit32bit as U, DataColumn32bit, DirectDataTable};
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let rows = vec![vec![U::Char('a'), U::I32(-1)], vec![U::Char('b'), U::I32(-2)]];
+    /// let t = DirectDataTable::<DataColumn32bit>::from_rows(&[T::Char, T::I32], rows)?;
+    /// assert_eq![(2, 2), (t.num_cols(), t.num_rows())];
+    ///
+    /// let rows = t.into_rows();
+    /// assert![matches![rows[1][0], U::Char('b')]];
+    /// # Ok(()) }
+    /// ```
+    pub fn from_rows<R, I>(types: &[C::Type], rows: R) -> Result<Self>
+    where
+        R: IntoIterator<Item = I>,
+        I: IntoIterator<Item = C::Unit>,
+    {
+        let mut table = Self::with_types(types);
+        for row in rows {
+            table.push_row(row)?;
+        }
+        Ok(table)
+    }
+}
+
+impl<C: DataColumn, S: Storage> Default for DataTable<C, S> {
+    /// Returns a new empty table, without columns.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # deconstructors
+impl<C: DataColumn, S: Storage> DataTable<C, S> {
+    /// Returns the units of the table as a vector of rows.
+    pub fn into_rows(mut self) -> Vec<Vec<C::Unit>> {
+        let columns = core::mem::take(&mut *self.columns);
+        let mut columns: Vec<_> = columns
+            .into_iter()
+            .map(|c| c.column_into_units().into_iter())
+            .collect();
+        (0..self.rows)
+            .map(|_| columns.iter_mut().filter_map(Iterator::next).collect())
+            .collect()
+    }
+
+    /// Returns a copy of the units of the table as a vector of rows.
+    pub fn to_rows(&self) -> Vec<Vec<C::Unit>> {
+        (0..self.rows)
+            .map(|row| {
+                self.columns
+                    .iter()
+                    .filter_map(|c| c.column_get(row))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the columns of the table.
+    pub fn into_columns(mut self) -> Vec<C> {
+        core::mem::take(&mut *self.columns)
+    }
+}
+
+/// # queries
+impl<C: DataColumn, S: Storage> DataTable<C, S> {
+    /// Returns the number of rows.
+    #[inline]
+    pub fn num_rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn num_cols(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns `true` if the table has no rows.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0
+    }
+
+    /// Returns the *data type* of the column at index `col`.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `col >= num_cols()`.
+    #[inline]
+    pub fn column_type(&self, col: usize) -> Result<C::Type> {
+        self.column(col).map(C::column_type)
+    }
+
+    /// Returns an iterator over the *data types* of the columns.
+    pub fn column_types(&self) -> impl Iterator<Item = C::Type> + '_ {
+        self.columns.iter().map(C::column_type)
+    }
+
+    /// Returns a shared reference to the column at index `col`.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `col >= num_cols()`.
+    #[inline]
+    pub fn column(&self, col: usize) -> Result<&C> {
+        self.columns.get(col).ok_or(Error::IndexOutOfBounds(col))
+    }
+
+    /// Returns an iterator over copies of the units in the column at index `col`.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `col >= num_cols()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::all::{DataType8bit as T, DataUnit8bit as U, DataColumn8bit, BoxedDataTable};
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut t = BoxedDataTable::<DataColumn8bit>::with_types(&[T::Bool, T::I8]);
+    /// t.push_row([U::Bool(true), U::I8(-4)])?;
+    /// t.push_row([U::Bool(false), U::I8(5)])?;
+    ///
+    /// let sum: i8 = t.col_iter(1)?.filter_map(|u| i8::try_from(u).ok()).sum();
+    /// assert_eq![1, sum];
+    /// # Ok(()) }
+    /// ```
+    pub fn col_iter(&self, col: usize) -> Result<impl Iterator<Item = C::Unit> + '_> {
+        let column = self.column(col)?;
+        Ok((0..self.rows).filter_map(move |row| column.column_get(row)))
+    }
+}
+
+/// # rows
+impl<C: DataColumn, S: Storage> DataTable<C, S> {
+    /// Appends a new `row` of units at the end of the table.
+    ///
+    /// The table is left unchanged if there's an error.
+    ///
+    /// # Errors
+    /// Returns [`DimensionMismatch`][Error::DimensionMismatch] if the number
+    /// of units is different than the number of columns, or
+    /// [`TypeMismatch`][Error::TypeMismatch] if any unit doesn't match
+    /// the type of its column.
+    pub fn push_row<I: IntoIterator<Item = C::Unit>>(&mut self, row: I) -> Result<()> {
+        let row: Vec<C::Unit> = row.into_iter().collect();
+        if row.len() != self.columns.len() {
+            return Err(Error::DimensionMismatch);
+        }
+        if !self
+            .columns
+            .iter()
+            .zip(&row)
+            .all(|(c, u)| c.column_accepts(u))
+        {
+            return Err(Error::TypeMismatch);
+        }
+        for (column, unit) in self.columns.iter_mut().zip(row) {
+            column.column_push(unit)?;
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Removes the last row from the table and returns it,
+    /// or `None` if it's empty.
+    pub fn pop_row(&mut self) -> Option<Vec<C::Unit>> {
+        if self.rows == 0 {
+            None
+        } else {
+            self.rows -= 1;
+            Some(self.columns.iter_mut().filter_map(C::column_pop).collect())
+        }
+    }
+
+    /// Returns a copy of the units in the row at index `row`.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `row >= num_rows()`.
+    pub fn get_row(&self, row: usize) -> Result<Vec<C::Unit>> {
+        if row < self.rows {
+            Ok(self
+                .columns
+                .iter()
+                .filter_map(|c| c.column_get(row))
+                .collect())
+        } else {
+            Err(Error::IndexOutOfBounds(row))
+        }
+    }
+}
+
+/// # single unit get/set
+impl<C: DataColumn, S: Storage> DataTable<C, S> {
+    /// Returns a copy of the unit at `col`, `row`.
+    ///
+    /// # Errors
+    /// Returns [`Indices2dOutOfBounds`][Error::Indices2dOutOfBounds] if out of bounds.
+    pub fn get(&self, col: usize, row: usize) -> Result<C::Unit> {
+        self.columns
+            .get(col)
+            .and_then(|c| c.column_get(row))
+            .ok_or(Error::Indices2dOutOfBounds(col, row))
+    }
+
+    /// Returns the value of the unit at `col`, `row`, converted to `V`.
+    ///
+    /// # Errors
+    /// Returns [`Indices2dOutOfBounds`][Error::Indices2dOutOfBounds] if out of bounds,
+    /// or [`TypeMismatch`][Error::TypeMismatch] if the unit can't be converted to `V`.
+    pub fn get_as<V: TryFrom<C::Unit>>(&self, col: usize, row: usize) -> Result<V> {
+        V::try_from(self.get(col, row)?).map_err(|_| Error::TypeMismatch)
+    }
+
+    /// Replaces the unit at `col`, `row` with the given `unit`, returning the old one.
+    ///
+    /// # Errors
+    /// Returns [`Indices2dOutOfBounds`][Error::Indices2dOutOfBounds] if out of bounds,
+    /// or [`TypeMismatch`][Error::TypeMismatch] if the unit doesn't match
+    /// the type of the column.
+    pub fn set(&mut self, col: usize, row: usize, unit: C::Unit) -> Result<C::Unit> {
+        if row >= self.rows {
+            return Err(Error::Indices2dOutOfBounds(col, row));
+        }
+        self.columns
+            .get_mut(col)
+            .ok_or(Error::Indices2dOutOfBounds(col, row))?
+            .column_set(row, unit)
+    }
+}
+
+/// # columns
+impl<C: DataColumn, S: Storage> DataTable<C, S> {
+    /// Appends a new column of the given `data_type`, filled with `units`.
+    ///
+    /// The table is left unchanged if there's an error.
+    ///
+    /// # Errors
+    /// Returns [`DimensionMismatch`][Error::DimensionMismatch] if the number
+    /// of units is different than the number of rows, or
+    /// [`TypeMismatch`][Error::TypeMismatch] if any unit doesn't match `data_type`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ladata::all::{DataType16bit as T, DataUnit16bit as U, DataColumn16bit, DirectDataTable};
+    /// # fn main() -> ladata::error::LadataResult<()> {
+    ///
+    /// let mut t = DirectDataTable::<DataColumn16bit>::with_types(&[T::U16]);
+    /// t.push_row([U::U16(1)])?;
+    /// t.push_row([U::U16(2)])?;
+    ///
+    /// t.push_column(T::I8, [U::I8(-1), U::I8(-2)])?;
+    /// assert![matches![t.get(1, 1)?, U::I8(-2)]];
+    ///
+    /// let removed = t.remove_column(0)?;
+    /// assert_eq![2, removed.len()];
+    /// assert_eq![1, t.num_cols()];
+    /// # Ok(()) }
+    /// ```
+    pub fn push_column<I>(&mut self, data_type: C::Type, units: I) -> Result<()>
+    where
+        I: IntoIterator<Item = C::Unit>,
+    {
+        let mut column = C::column_new(data_type);
+        for unit in units {
+            column.column_push(unit)?;
+        }
+        if column.column_len() != self.rows {
+            return Err(Error::DimensionMismatch);
+        }
+        self.columns.push(column);
+        Ok(())
+    }
+
+    /// Removes the column at index `col` and returns it.
+    ///
+    /// # Errors
+    /// Returns [`IndexOutOfBounds`][Error::IndexOutOfBounds] if `col >= num_cols()`.
+    pub fn remove_column(&mut self, col: usize) -> Result<C> {
+        if col < self.columns.len() {
+            Ok(self.columns.remove(col))
+        } else {
+            Err(Error::IndexOutOfBounds(col))
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// ladata::table
+//
+//! Tables are column-oriented, dynamically sized,
+//! heterogeneous data structures.
+//!
+//! Each column is described once by a *data type*, and stores its data
+//! packed in a [`DataColumn`], instead of storing a tagged *data unit* per cell.
+//
+
+use crate::{
+    mem::{Boxed, Storage},
+    unit::DataColumn,
+};
+use alloc::vec::Vec;
+
+mod methods;
+
+#[cfg(test)]
+mod tests;
+
+/// A table of typed columns, backed by a [`Vec`] of [`DataColumn`]s.
+///
+/// Every column has the same number of rows.
+///
+/// # Examples
+/// ```
+/// use ladata::all::{DataType64bit as T, DataUnit64bit as U, DataColumn64bit, DirectDataTable};
+/// # fn main() -> ladata::error::LadataResult<()> {
+///
+/// let mut t = DirectDataTable::<DataColumn64bit>::with_types(&[T::U8, T::F64]);
+/// t.push_row([U::U8(1), U::F64(0.5)])?;
+/// t.push_row([U::U8(2), U::F64(1.5)])?;
+///
+/// assert_eq![2, t.num_rows()];
+/// assert_eq![2_u8, t.get_as(0, 1)?];
+/// assert![t.push_row([U::F64(2.5), U::U8(3)]).is_err()];
+/// # Ok(()) }
+/// ```
+pub struct DataTable<C: DataColumn, S: Storage> {
+    columns: S::Stored<Vec<C>>,
+    rows: usize,
+}
+
+/// A [`DataTable`] with its columns stored directly.
+pub type DirectDataTable<C> = DataTable<C, ()>;
+
+/// A [`DataTable`] with its columns stored in a [`Box`][alloc::boxed::Box].
+pub type BoxedDataTable<C> = DataTable<C, Boxed>;
+
+pub(crate) mod all {
+    #[doc(inline)]
+    pub use super::{BoxedDataTable, DataTable, DirectDataTable};
+}
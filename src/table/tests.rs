@@ -0,0 +1,117 @@
+// ladata::table::tests
+
+use super::{BoxedDataTable, DirectDataTable};
+use crate::{
+    error::LadataError as Error,
+    unit::{DataColumn64bit, DataType64bit as T, DataUnit64bit as U},
+};
+use alloc::vec;
+
+#[test]
+fn rows() {
+    let mut t = DirectDataTable::<DataColumn64bit>::with_types(&[T::U64, T::Bool, T::None]);
+    assert![t.is_empty()];
+
+    t.push_row([U::U64(1), U::Bool(true), U::None]).unwrap();
+    t.push_row([U::U64(2), U::Bool(false), U::None]).unwrap();
+    assert_eq![(3, 2), (t.num_cols(), t.num_rows())];
+
+    // the table is left unchanged on error
+    assert_eq![Err(Error::DimensionMismatch), t.push_row([U::U64(3)])];
+    assert_eq![
+        Err(Error::TypeMismatch),
+        t.push_row([U::U64(3), U::U8(0), U::None])
+    ];
+    assert_eq![2, t.num_rows()];
+    assert_eq![2, t.column(0).unwrap().len()];
+
+    let last = t.pop_row().unwrap();
+    assert![matches![last[..], [U::U64(2), U::Bool(false), U::None]]];
+    assert_eq![1, t.num_rows()];
+    assert![matches![
+        t.get_row(0).unwrap()[..],
+        [U::U64(1), U::Bool(true), U::None]
+    ]];
+    assert_eq![Err(Error::IndexOutOfBounds(1)), t.get_row(1).map(|_| ())];
+}
+
+#[test]
+fn units() {
+    let mut t = BoxedDataTable::<DataColumn64bit>::with_types(&[T::I16, T::Char]);
+    t.push_row([U::I16(-3), U::Char('a')]).unwrap();
+
+    assert_eq![Ok(-3_i16), t.get_as(0, 0)];
+    assert_eq![Err(Error::TypeMismatch), t.get_as::<char>(0, 0)];
+    assert_eq![
+        Err(Error::Indices2dOutOfBounds(2, 0)),
+        t.get_as::<char>(2, 0)
+    ];
+
+    assert![matches![t.set(1, 0, U::Char('b')), Ok(U::Char('a'))]];
+    assert![matches![t.set(1, 0, U::I16(0)), Err(Error::TypeMismatch)]];
+    assert![matches![
+        t.set(1, 1, U::Char('c')),
+        Err(Error::Indices2dOutOfBounds(1, 1))
+    ]];
+    assert_eq![Ok('b'), t.get_as(1, 0)];
+}
+
+#[test]
+fn columns() {
+    let mut t = DirectDataTable::<DataColumn64bit>::new();
+    assert_eq![Err(Error::DimensionMismatch), t.push_row([U::U8(1)])];
+
+    t.push_column(T::U8, []).unwrap();
+    t.push_row([U::U8(1)]).unwrap();
+    t.push_row([U::U8(2)]).unwrap();
+
+    assert_eq![
+        Err(Error::DimensionMismatch),
+        t.push_column(T::F32, [U::F32(0.)])
+    ];
+    assert_eq![
+        Err(Error::TypeMismatch),
+        t.push_column(T::F32, [U::F32(0.), U::U8(0)])
+    ];
+    t.push_column(T::F32, [U::F32(0.5), U::F32(1.5)]).unwrap();
+
+    let types: vec::Vec<_> = t.column_types().collect();
+    assert![matches![types[..], [T::U8, T::F32]]];
+
+    let sum: f32 = t
+        .col_iter(1)
+        .unwrap()
+        .filter_map(|u| f32::try_from(u).ok())
+        .sum();
+    assert_eq![2., sum];
+
+    let c = t.remove_column(0).unwrap();
+    assert![matches![c.data_type(), T::U8]];
+    assert_eq![
+        Err(Error::IndexOutOfBounds(1)),
+        t.remove_column(1).map(|_| ())
+    ];
+    assert_eq![1, t.num_cols()];
+}
+
+#[test]
+fn conversions() {
+    let rows = vec![
+        vec![U::I64(-1), U::ByteArray2([1, 2])],
+        vec![U::I64(-2), U::ByteArray2([3, 4])],
+    ];
+    let t = DirectDataTable::<DataColumn64bit>::from_rows(&[T::I64, T::ByteArray2], rows).unwrap();
+    assert_eq![2, t.num_rows()];
+
+    let copied = t.to_rows();
+    let rows = t.into_rows();
+    assert_eq![2, rows.len()];
+    assert![matches![rows[1][..], [U::I64(-2), U::ByteArray2([3, 4])]]];
+    assert![matches![copied[0][..], [U::I64(-1), U::ByteArray2([1, 2])]]];
+
+    let bad = vec![vec![U::I64(-1), U::I64(0)]];
+    assert_eq![
+        Err(Error::TypeMismatch),
+        DirectDataTable::<DataColumn64bit>::from_rows(&[T::I64, T::ByteArray2], bad).map(|_| ())
+    ];
+}
@@ -0,0 +1,560 @@
+// ladata::unit::arith
+//
+//! Checked arithmetic between the numeric data units, across all sizes.
+//!
+//! The operands are first converted into a size-agnostic [`Number`],
+//! then promoted to a common numeric type following the promotion table
+//! documented in the [`unit`][crate::unit#arithmetic] module,
+//! and the result is converted back into a unit of the original size.
+//
+
+use crate::error::{LadataError as Error, LadataResult as Result};
+use core::ops::{Add, Div, Mul, Rem, Sub};
+use devela::iif;
+
+/// An arithmetic binary operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+/// The width of an integer type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Width {
+    Bits(u32),
+    /// The width of a pointer.
+    Pointer,
+}
+
+impl Width {
+    #[inline]
+    const fn bits(self) -> u32 {
+        match self {
+            Width::Bits(b) => b,
+            Width::Pointer => usize::BITS,
+        }
+    }
+}
+
+/// A size-agnostic numeric value, keeping the properties of its original type.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Number {
+    Unsigned(u128, Width),
+    Signed(i128, Width),
+    #[cfg(feature = "half")]
+    F16(half::f16),
+    #[cfg(feature = "half")]
+    BF16(half::bf16),
+    F32(f32),
+    F64(f64),
+    #[cfg(all(feature = "std", feature = "twofloat"))]
+    F128(twofloat::TwoFloat),
+    #[cfg(feature = "rust_decimal")]
+    Decimal(rust_decimal::Decimal),
+}
+
+/// The type of a [`Number`], used for promotion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Unsigned(Width),
+    Signed(Width),
+    #[cfg(feature = "half")]
+    F16,
+    #[cfg(feature = "half")]
+    BF16,
+    F32,
+    F64,
+    F128,
+    Decimal,
+}
+
+impl Kind {
+    /// Returns the kind with pointer-sized widths replaced by fixed ones.
+    #[inline]
+    const fn fixed(self) -> Self {
+        match self {
+            Kind::Unsigned(w) => Kind::Unsigned(Width::Bits(w.bits())),
+            Kind::Signed(w) => Kind::Signed(Width::Bits(w.bits())),
+            k => k,
+        }
+    }
+
+    /// Returns the number of significant bits of a float kind,
+    /// or the number of bits needed by an integer kind to be exact in a float.
+    const fn mantissa(self) -> u32 {
+        match self {
+            Kind::Unsigned(w) => w.bits(),
+            Kind::Signed(w) => w.bits() - 1,
+            #[cfg(feature = "half")]
+            Kind::F16 => 11,
+            #[cfg(feature = "half")]
+            Kind::BF16 => 8,
+            Kind::F32 => 24,
+            Kind::F64 => 53,
+            Kind::F128 => 106,
+            Kind::Decimal => 96,
+        }
+    }
+
+    /// Returns the rank of a float kind.
+    const fn float_rank(self) -> u8 {
+        match self {
+            Kind::F32 => 1,
+            Kind::F64 => 2,
+            Kind::F128 => 3,
+            _ => 0,
+        }
+    }
+
+    #[inline]
+    const fn is_int(self) -> bool {
+        matches![self, Kind::Unsigned(_) | Kind::Signed(_)]
+    }
+}
+
+/// Returns the common kind both kinds are promoted to.
+fn promote(a: Kind, b: Kind) -> Result<Kind> {
+    use {Kind::*, Width::Bits};
+    if a == b {
+        return Ok(a);
+    }
+    Ok(match (a.fixed(), b.fixed()) {
+        (a, b) if a == b => a,
+        (Unsigned(x), Unsigned(y)) => Unsigned(Bits(x.bits().max(y.bits()))),
+        (Signed(x), Signed(y)) => Signed(Bits(x.bits().max(y.bits()))),
+        (Unsigned(u), Signed(s)) | (Signed(s), Unsigned(u)) => {
+            let bits = (u.bits() * 2).max(s.bits());
+            if bits > 128 {
+                return Err(Error::TypeMismatch);
+            }
+            Signed(Bits(bits))
+        }
+        (Decimal, k) | (k, Decimal) if k.is_int() => Decimal,
+        (Decimal, _) | (_, Decimal) => return Err(Error::TypeMismatch),
+        (F128, _) | (_, F128) => F128,
+        (int, float) | (float, int) if int.is_int() => {
+            if float.mantissa() >= int.mantissa() {
+                float
+            } else if int.mantissa() <= F32.mantissa() {
+                F32
+            } else {
+                F64
+            }
+        }
+        (a, b) if a.float_rank() == b.float_rank() => F32,
+        (a, b) if a.float_rank() > b.float_rank() => a,
+        (_, b) => b,
+    })
+}
+
+impl Number {
+    fn kind(&self) -> Kind {
+        match self {
+            Number::Unsigned(_, w) => Kind::Unsigned(*w),
+            Number::Signed(_, w) => Kind::Signed(*w),
+            #[cfg(feature = "half")]
+            Number::F16(_) => Kind::F16,
+            #[cfg(feature = "half")]
+            Number::BF16(_) => Kind::BF16,
+            Number::F32(_) => Kind::F32,
+            Number::F64(_) => Kind::F64,
+            #[cfg(all(feature = "std", feature = "twofloat"))]
+            Number::F128(_) => Kind::F128,
+            #[cfg(feature = "rust_decimal")]
+            Number::Decimal(_) => Kind::Decimal,
+        }
+    }
+
+    /// Returns the value as the nearest `f64`, unless it's a decimal.
+    fn to_f64(self) -> Option<f64> {
+        match self {
+            Number::Unsigned(u, _) => Some(u as f64),
+            Number::Signed(i, _) => Some(i as f64),
+            #[cfg(feature = "half")]
+            Number::F16(f) => Some(f.to_f64()),
+            #[cfg(feature = "half")]
+            Number::BF16(f) => Some(f.to_f64()),
+            Number::F32(f) => Some(f as f64),
+            Number::F64(f) => Some(f),
+            #[cfg(all(feature = "std", feature = "twofloat"))]
+            Number::F128(f) => Some(f.hi()),
+            #[cfg(feature = "rust_decimal")]
+            Number::Decimal(_) => None,
+        }
+    }
+
+    /// Converts the number into a wider or equal `kind`, as returned by [`promote`].
+    #[allow(unreachable_patterns)]
+    fn convert(self, kind: Kind) -> Result<Number> {
+        Ok(match (self, kind) {
+            (n, k) if n.kind() == k => n,
+            (Number::Unsigned(u, _), Kind::Unsigned(w)) => Number::Unsigned(u, w),
+            (Number::Unsigned(u, _), Kind::Signed(w)) => Number::Signed(u as i128, w),
+            (Number::Signed(i, _), Kind::Signed(w)) => Number::Signed(i, w),
+
+            #[cfg(all(feature = "std", feature = "twofloat"))]
+            (Number::Unsigned(u, _), Kind::F128) => Number::F128(u.into()),
+            #[cfg(all(feature = "std", feature = "twofloat"))]
+            (Number::Signed(i, _), Kind::F128) => Number::F128(i.into()),
+
+            #[cfg(feature = "rust_decimal")]
+            (Number::Unsigned(u, _), Kind::Decimal) => {
+                let i = i128::try_from(u).map_err(|_| Error::Overflow)?;
+                Number::Decimal(
+                    rust_decimal::Decimal::try_from_i128_with_scale(i, 0)
+                        .map_err(|_| Error::Overflow)?,
+                )
+            }
+            #[cfg(feature = "rust_decimal")]
+            (Number::Signed(i, _), Kind::Decimal) => Number::Decimal(
+                rust_decimal::Decimal::try_from_i128_with_scale(i, 0)
+                    .map_err(|_| iif![i < 0; Error::Underflow; Error::Overflow])?,
+            ),
+
+            (n, kind) => {
+                let f = n.to_f64().ok_or(Error::TypeMismatch)?;
+                match kind {
+                    #[cfg(feature = "half")]
+                    Kind::F16 => Number::F16(half::f16::from_f64(f)),
+                    #[cfg(feature = "half")]
+                    Kind::BF16 => Number::BF16(half::bf16::from_f64(f)),
+                    Kind::F32 => Number::F32(match n {
+                        // avoids rounding twice
+                        Number::Unsigned(u, _) => u as f32,
+                        Number::Signed(i, _) => i as f32,
+                        _ => f as f32,
+                    }),
+                    Kind::F64 => Number::F64(f),
+                    #[cfg(all(feature = "std", feature = "twofloat"))]
+                    Kind::F128 => Number::F128(f.into()),
+                    _ => return Err(Error::TypeMismatch),
+                }
+            }
+        })
+    }
+}
+
+/// Returns the error of an overflowing operation, given the signs of its operands.
+const fn overflow(op: Op, a_negative: bool, b_negative: bool) -> Error {
+    let up = match op {
+        Op::Add => !a_negative,
+        Op::Sub => b_negative,
+        Op::Mul | Op::Div => a_negative == b_negative,
+        Op::Rem => true,
+    };
+    iif![up; Error::Overflow; Error::Underflow]
+}
+
+fn unsigned_op(a: u128, b: u128, bits: u32, op: Op) -> Result<u128> {
+    let max = u128::MAX >> (128 - bits);
+    let r = match op {
+        Op::Add => a.checked_add(b).ok_or(Error::Overflow)?,
+        Op::Sub => a.checked_sub(b).ok_or(Error::Underflow)?,
+        Op::Mul => a.checked_mul(b).ok_or(Error::Overflow)?,
+        Op::Div => a.checked_div(b).ok_or(Error::DivisionByZero)?,
+        Op::Rem => a.checked_rem(b).ok_or(Error::DivisionByZero)?,
+    };
+    iif![r > max; Err(Error::Overflow); Ok(r)]
+}
+
+fn signed_op(a: i128, b: i128, bits: u32, op: Op) -> Result<i128> {
+    let shift = 128 - bits;
+    let (max, min) = (i128::MAX >> shift, i128::MIN >> shift);
+    let r = match op {
+        Op::Div | Op::Rem if b == 0 => return Err(Error::DivisionByZero),
+        Op::Rem if b == -1 => Some(0),
+        Op::Add => a.checked_add(b),
+        Op::Sub => a.checked_sub(b),
+        Op::Mul => a.checked_mul(b),
+        Op::Div => a.checked_div(b),
+        Op::Rem => a.checked_rem(b),
+    }
+    .ok_or(overflow(op, a < 0, b < 0))?;
+    if r > max {
+        Err(Error::Overflow)
+    } else if r < min {
+        Err(Error::Underflow)
+    } else {
+        Ok(r)
+    }
+}
+
+/// Operates on two floats, failing if the result is infinite but the operands aren't.
+fn float_op<F>(a: F, b: F, op: Op, zero: F, finite: fn(&F) -> bool) -> Result<F>
+where
+    F: Copy + PartialEq + PartialOrd,
+    F: Add<Output = F> + Sub<Output = F> + Mul<Output = F> + Div<Output = F> + Rem<Output = F>,
+{
+    let r = match op {
+        Op::Div | Op::Rem if b == zero => return Err(Error::DivisionByZero),
+        Op::Add => a + b,
+        Op::Sub => a - b,
+        Op::Mul => a * b,
+        Op::Div => a / b,
+        Op::Rem => a % b,
+    };
+    if finite(&a) && finite(&b) && !finite(&r) {
+        Err(iif![r < zero; Error::Underflow; Error::Overflow])
+    } else {
+        Ok(r)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+fn decimal_op(
+    a: rust_decimal::Decimal,
+    b: rust_decimal::Decimal,
+    op: Op,
+) -> Result<rust_decimal::Decimal> {
+    if matches![op, Op::Div | Op::Rem] && b.is_zero() {
+        return Err(Error::DivisionByZero);
+    }
+    match op {
+        Op::Add => a.checked_add(b),
+        Op::Sub => a.checked_sub(b),
+        Op::Mul => a.checked_mul(b),
+        Op::Div => a.checked_div(b),
+        Op::Rem => a.checked_rem(b),
+    }
+    .ok_or(overflow(op, a.is_sign_negative(), b.is_sign_negative()))
+}
+
+/// Applies the binary operation `op` after promoting both numbers to a common type.
+pub(crate) fn binary(a: Number, b: Number, op: Op) -> Result<Number> {
+    let kind = promote(a.kind(), b.kind())?;
+    match (a.convert(kind)?, b.convert(kind)?) {
+        (Number::Unsigned(a, w), Number::Unsigned(b, _)) => {
+            unsigned_op(a, b, w.bits(), op).map(|r| Number::Unsigned(r, w))
+        }
+        (Number::Signed(a, w), Number::Signed(b, _)) => {
+            signed_op(a, b, w.bits(), op).map(|r| Number::Signed(r, w))
+        }
+        #[cfg(feature = "half")]
+        (Number::F16(a), Number::F16(b)) => {
+            float_op(a, b, op, half::f16::ZERO, |f| f.is_finite()).map(Number::F16)
+        }
+        #[cfg(feature = "half")]
+        (Number::BF16(a), Number::BF16(b)) => {
+            float_op(a, b, op, half::bf16::ZERO, |f| f.is_finite()).map(Number::BF16)
+        }
+        (Number::F32(a), Number::F32(b)) => {
+            float_op(a, b, op, 0.0, |f| f.is_finite()).map(Number::F32)
+        }
+        (Number::F64(a), Number::F64(b)) => {
+            float_op(a, b, op, 0.0, |f| f.is_finite()).map(Number::F64)
+        }
+        #[cfg(all(feature = "std", feature = "twofloat"))]
+        (Number::F128(a), Number::F128(b)) => {
+            float_op(a, b, op, 0.0.into(), |f| f.hi().is_finite()).map(Number::F128)
+        }
+        #[cfg(feature = "rust_decimal")]
+        (Number::Decimal(a), Number::Decimal(b)) => decimal_op(a, b, op).map(Number::Decimal),
+        _ => Err(Error::TypeMismatch),
+    }
+}
+
+/// Negates the number.
+pub(crate) fn neg(n: Number) -> Result<Number> {
+    Ok(match n {
+        Number::Unsigned(0, w) => Number::Unsigned(0, w),
+        Number::Unsigned(_, _) => return Err(Error::Underflow),
+        Number::Signed(i, w) => {
+            let max = i128::MAX >> (128 - w.bits());
+            let r = i.checked_neg().ok_or(Error::Overflow)?;
+            iif![r > max; return Err(Error::Overflow); Number::Signed(r, w)]
+        }
+        #[cfg(feature = "half")]
+        Number::F16(f) => Number::F16(-f),
+        #[cfg(feature = "half")]
+        Number::BF16(f) => Number::BF16(-f),
+        Number::F32(f) => Number::F32(-f),
+        Number::F64(f) => Number::F64(-f),
+        #[cfg(all(feature = "std", feature = "twofloat"))]
+        Number::F128(f) => Number::F128(-f),
+        #[cfg(feature = "rust_decimal")]
+        Number::Decimal(d) => Number::Decimal(-d),
+    })
+}
+
+/// Converts the data contained in a unit variant to and from a [`Number`].
+pub(crate) trait Numeric: Sized {
+    /// Returns the numeric value of the data, or `None` if it's not numeric.
+    fn to_number(&self) -> Option<Number>;
+
+    /// Returns the data if the `number` is of this exact type.
+    fn from_number(number: Number) -> Option<Self>;
+}
+
+/* integers */
+
+macro_rules! impl_numeric_int {
+    ($( $variant:ident, $width:expr => $( $type:ty ),+ );+ $(;)?) => {
+        $( $(
+            impl Numeric for $type {
+                #[inline]
+                fn to_number(&self) -> Option<Number> {
+                    Some(Number::$variant(*self as _, $width))
+                }
+                #[inline]
+                fn from_number(number: Number) -> Option<Self> {
+                    match number {
+                        Number::$variant(v, w) if w == $width => Some(v as Self),
+                        _ => None,
+                    }
+                }
+            }
+        )+ )+
+    };
+}
+impl_numeric_int![
+    Unsigned, Width::Bits(Self::BITS) => u8, u16, u32, u64, u128;
+    Signed, Width::Bits(Self::BITS) => i8, i16, i32, i64, i128;
+    Unsigned, Width::Pointer => usize;
+    Signed, Width::Pointer => isize;
+];
+
+/* floats & decimals */
+
+macro_rules! impl_numeric {
+    ($( $(#[$attr:meta])* $type:ty: $variant:ident ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl Numeric for $type {
+                #[inline]
+                fn to_number(&self) -> Option<Number> {
+                    Some(Number::$variant(*self))
+                }
+                #[inline]
+                #[allow(unreachable_patterns)]
+                fn from_number(number: Number) -> Option<Self> {
+                    match number {
+                        Number::$variant(v) => Some(v),
+                        _ => None,
+                    }
+                }
+            }
+        )+
+    };
+}
+impl_numeric![
+    f32: F32,
+    f64: F64,
+    #[cfg(feature = "half")]
+    half::f16: F16,
+    #[cfg(feature = "half")]
+    half::bf16: BF16,
+    #[cfg(all(feature = "std", feature = "twofloat"))]
+    twofloat::TwoFloat: F128,
+    #[cfg(feature = "rust_decimal")]
+    rust_decimal::Decimal: Decimal,
+];
+
+/* non-numeric types */
+
+macro_rules! impl_non_numeric {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl Numeric for $type {
+                #[inline]
+                fn to_number(&self) -> Option<Number> {
+                    None
+                }
+                #[inline]
+                fn from_number(_number: Number) -> Option<Self> {
+                    None
+                }
+            }
+        )+
+    };
+}
+impl_non_numeric![
+    bool,
+    char,
+    core::time::Duration,
+    #[cfg(feature = "std")]
+    std::time::Instant,
+    #[cfg(feature = "std")]
+    std::time::SystemTime,
+    #[cfg(feature = "num-rational")]
+    num_rational::Ratio<i32>,
+    #[cfg(feature = "num-rational")]
+    num_rational::Ratio<i64>,
+    #[cfg(feature = "num-rational")]
+    num_rational::Ratio<i128>,
+    #[cfg(feature = "time")]
+    time::Date,
+    #[cfg(feature = "time")]
+    time::Time,
+    #[cfg(feature = "time")]
+    time::UtcOffset,
+    #[cfg(feature = "time")]
+    time::Duration,
+    #[cfg(feature = "time")]
+    time::PrimitiveDateTime,
+    #[cfg(feature = "time")]
+    time::OffsetDateTime,
+    #[cfg(all(feature = "std", feature = "time"))]
+    #[allow(deprecated)]
+    time::Instant,
+];
+
+impl<const N: usize> Numeric for [u8; N] {
+    #[inline]
+    fn to_number(&self) -> Option<Number> {
+        None
+    }
+    #[inline]
+    fn from_number(_number: Number) -> Option<Self> {
+        None
+    }
+}
+
+impl<const BITLEN: usize, const BYTECAP: usize> Numeric
+    for crate::list::BitArray<(), BITLEN, BYTECAP>
+{
+    #[inline]
+    fn to_number(&self) -> Option<Number> {
+        None
+    }
+    #[inline]
+    fn from_number(_number: Number) -> Option<Self> {
+        None
+    }
+}
+
+#[cfg(feature = "arraystring")]
+impl<SIZE: arraystring::prelude::Capacity> Numeric for arraystring::ArrayString<SIZE> {
+    #[inline]
+    fn to_number(&self) -> Option<Number> {
+        None
+    }
+    #[inline]
+    fn from_number(_number: Number) -> Option<Self> {
+        None
+    }
+}
+
+#[cfg(feature = "fugit")]
+macro_rules! impl_non_numeric_fugit {
+    ($( $type:ident<$prim:ty> ),+) => {
+        $(
+            impl<const NOM: u32, const DENOM: u32> Numeric for fugit::$type<$prim, NOM, DENOM> {
+                #[inline]
+                fn to_number(&self) -> Option<Number> {
+                    None
+                }
+                #[inline]
+                fn from_number(_number: Number) -> Option<Self> {
+                    None
+                }
+            }
+        )+
+    };
+}
+#[cfg(feature = "fugit")]
+impl_non_numeric_fugit![Duration<u32>, Duration<u64>, Instant<u32>, Instant<u64>];
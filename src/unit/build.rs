@@ -11,13 +11,16 @@
 //
 
 use super::macros::*;
-use super::{DataType, DataTypeCopy, DataUnit, DataUnitCopy};
+use super::{DataType, DataTypeCopy, DataTypeRegistry, DataUnit, DataUnitCopy, DataUnitPayload};
 
 use crate::all::BitArray;
 
 #[cfg(feature = "unsafe_unit")]
 use super::DataRaw;
 
+#[cfg(feature = "alloc")]
+use super::DataColumn;
+
 // 1. Mockups for substituting unused dependencies
 // -----------------------------------------------------------------------------
 
@@ -101,7 +104,7 @@ mod time {
 // removing commas as needed. See for example the `NOTE:missing-commas` tags.
 define_all_sizes! {
     // NOTE: order matters
-    DataType, DataUnit, DataRaw,
+    DataType, DataUnit, DataRaw, DataColumn, Tagged,
 
     // -------------------------------------------------------- 1-B / 8-b
     copy_variants_1B:
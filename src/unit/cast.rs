@@ -0,0 +1,632 @@
+// ladata::unit::cast
+//
+//! Casting between the numeric, `bool`, `char`, byte array and bit array
+//! data units, across all sizes.
+//!
+//! The source data is first converted into a size-agnostic [`CastValue`],
+//! which remembers the value and the properties of its original type,
+//! and is then converted into the target type following a [`CastMode`].
+//!
+//! Byte arrays and bit arrays are treated as little-endian unsigned integers.
+//! A `bool` is treated as a 1-bit unsigned integer, and a `char` as a 21-bit
+//! unsigned integer whose valid values are the unicode scalar values.
+//
+
+use crate::error::{LadataError as Error, LadataResult as Result};
+use core::cmp::Ordering;
+
+/// The rules to follow when casting a *data unit* into another *data type*.
+///
+/// See the `cast` method of the `DataUnit*` enums.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CastMode {
+    /// Only allows casting between types where every possible value of the
+    /// source type is exactly representable in the target type.
+    ///
+    /// Returns [`TypeMismatch`][Error::TypeMismatch] otherwise,
+    /// regardless of the actual value.
+    Lossless,
+
+    /// Allows the cast if the value fits in the range of the target type.
+    ///
+    /// Returns [`Overflow`][Error::Overflow] if the value is too big, or `NaN`,
+    /// and [`Underflow`][Error::Underflow] if it's too small.
+    ///
+    /// Floating-point values are truncated towards zero when cast
+    /// into an integer type, and rounded when cast into a smaller float.
+    Checked,
+
+    /// Clamps the value to the range of the target type.
+    ///
+    /// `NaN` is cast to zero, and invalid `char`s to
+    /// [`REPLACEMENT_CHARACTER`][char::REPLACEMENT_CHARACTER].
+    Saturating,
+
+    /// Wraps the value around the range of the target integer type,
+    /// keeping its least significant bits.
+    ///
+    /// Floats follow the semantics of the `as` operator instead: they saturate
+    /// when cast into an integer, with `NaN` cast to zero, and become infinite
+    /// when too big for a smaller float. Invalid `char`s are cast to
+    /// [`REPLACEMENT_CHARACTER`][char::REPLACEMENT_CHARACTER].
+    Wrapping,
+}
+
+/// A size-agnostic value in the middle of a cast.
+///
+/// It can only be obtained from a *data unit* and consumed by a *data type*,
+/// through the `DataUnit*::cast` methods.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct CastValue(Value);
+
+#[derive(Clone, Copy, Debug)]
+enum Value {
+    Bool(bool),
+    Char(char),
+    /// The value, and the bit width of its type.
+    Unsigned(u128, u32),
+    /// The value, and the bit width of its type.
+    Signed(i128, u32),
+    Float(f64, FloatFormat),
+    /// The bytes in little-endian order, and their number.
+    Bytes([u8; MAX_BYTES], usize),
+}
+
+/// The maximum number of bytes of a byte or bit array.
+const MAX_BYTES: usize = 128;
+
+/// The number of bits needed to represent any `char`.
+const CHAR_BITS: u32 = 21;
+
+/// The precision and range of a floating-point type.
+#[derive(Clone, Copy, Debug)]
+struct FloatFormat {
+    /// The number of significant bits, including the implicit one.
+    mantissa: u32,
+    exponent: u32,
+}
+
+/// An integer value, normalized for casting.
+enum Int {
+    U(u128),
+    I(i128),
+    /// The least significant bits of an unsigned value bigger than `u128::MAX`.
+    Big(u128),
+}
+
+impl Value {
+    /// Whether every value of the source type is representable
+    /// as an unsigned integer of `bits`.
+    fn lossless_unsigned(&self, bits: u32) -> bool {
+        match *self {
+            Value::Bool(_) => true,
+            Value::Char(_) => CHAR_BITS <= bits,
+            Value::Unsigned(_, b) => b <= bits,
+            Value::Bytes(_, len) => len as u32 * 8 <= bits,
+            Value::Signed(..) | Value::Float(..) => false,
+        }
+    }
+
+    /// Whether every value of the source type is representable
+    /// as a signed integer of `bits`.
+    fn lossless_signed(&self, bits: u32) -> bool {
+        match *self {
+            Value::Signed(_, b) => b <= bits,
+            Value::Float(..) => false,
+            _ => self.lossless_unsigned(bits - 1),
+        }
+    }
+
+    /// Whether every value of the source type is representable as a `format` float.
+    fn lossless_float(&self, format: FloatFormat) -> bool {
+        match *self {
+            Value::Float(_, f) => f.mantissa <= format.mantissa && f.exponent <= format.exponent,
+            _ => {
+                self.lossless_unsigned(format.mantissa) || self.lossless_signed(format.mantissa + 1)
+            }
+        }
+    }
+
+    /// Returns the mode for casting the value into an integer,
+    /// where floats saturate like the `as` operator, even when wrapping.
+    fn int_mode(&self, mode: CastMode) -> CastMode {
+        match (self, mode) {
+            (Value::Float(..), CastMode::Wrapping) => CastMode::Saturating,
+            _ => mode,
+        }
+    }
+
+    /// Returns the value as an integer, truncating floats towards zero.
+    fn integer(&self, mode: CastMode) -> Result<Int> {
+        Ok(match *self {
+            Value::Bool(b) => Int::U(b as u128),
+            Value::Char(c) => Int::U(c as u128),
+            Value::Unsigned(u, _) => Int::U(u),
+            Value::Signed(i, _) => Int::I(i),
+            Value::Bytes(bytes, len) => {
+                let mut low = [0; 16];
+                let n = len.min(16);
+                low[..n].copy_from_slice(&bytes[..n]);
+                let low = u128::from_le_bytes(low);
+                if bytes[n..len].iter().any(|b| *b != 0) {
+                    Int::Big(low)
+                } else {
+                    Int::U(low)
+                }
+            }
+            Value::Float(f, _) => {
+                if matches![mode, CastMode::Lossless | CastMode::Checked] {
+                    if f.is_nan() || f >= u128::MAX as f64 {
+                        return Err(Error::Overflow);
+                    } else if f < i128::MIN as f64 {
+                        return Err(Error::Underflow);
+                    }
+                }
+                // `as` saturates, and casts NaN to 0
+                if f >= 0.0 || f.is_nan() {
+                    Int::U(f as u128)
+                } else {
+                    Int::I(f as i128)
+                }
+            }
+        })
+    }
+
+    /// Returns the value as the nearest `f64`.
+    fn float(&self) -> f64 {
+        match *self {
+            Value::Bool(b) => b as u8 as f64,
+            Value::Char(c) => c as u32 as f64,
+            Value::Unsigned(u, _) => u as f64,
+            Value::Signed(i, _) => i as f64,
+            Value::Float(f, _) => f,
+            Value::Bytes(bytes, len) => bytes[..len]
+                .iter()
+                .rev()
+                .fold(0.0, |acc, b| acc * 256.0 + *b as f64),
+        }
+    }
+}
+
+impl CastValue {
+    /// Compares two integer or float values numerically, regardless of their types.
+    ///
+    /// Returns `None` if any of them is not an integer or a float, or is `NaN`.
+    pub(crate) fn numeric_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.0, other.0) {
+            (Value::Float(a, _), Value::Float(b, _)) => a.partial_cmp(&b),
+            (Value::Float(f, _), _) => {
+                cmp_int_float(other.0.numeric_int()?, f).map(Ordering::reverse)
+            }
+            (_, Value::Float(f, _)) => cmp_int_float(self.0.numeric_int()?, f),
+            _ => Some(cmp_ints(self.0.numeric_int()?, other.0.numeric_int()?)),
+        }
+    }
+}
+
+impl Value {
+    /// Returns the value of an integer type.
+    fn numeric_int(&self) -> Option<Int> {
+        match *self {
+            Value::Unsigned(u, _) => Some(Int::U(u)),
+            Value::Signed(i, _) => Some(Int::I(i)),
+            _ => None,
+        }
+    }
+}
+
+/// Compares two integers.
+fn cmp_ints(a: Int, b: Int) -> Ordering {
+    match (a, b) {
+        (Int::I(a), Int::I(b)) => a.cmp(&b),
+        (Int::I(i), Int::U(u) | Int::Big(u)) if i >= 0 => (i as u128).cmp(&u),
+        (Int::I(_), _) => Ordering::Less,
+        (Int::U(a) | Int::Big(a), Int::U(b) | Int::Big(b)) => a.cmp(&b),
+        (a, b) => cmp_ints(b, a).reverse(),
+    }
+}
+
+/// Truncates a float towards zero, without depending on `std`.
+fn trunc(f: f64) -> f64 {
+    // floats with this magnitude have no fractional part
+    const LIMIT: f64 = (1_u64 << 52) as f64;
+    if -LIMIT < f && f < LIMIT {
+        f as i64 as f64
+    } else {
+        f
+    }
+}
+
+/// Compares an integer with a float, exactly.
+fn cmp_int_float(int: Int, f: f64) -> Option<Ordering> {
+    let t = trunc(f);
+    if f.is_nan() {
+        None
+    } else if t >= u128::MAX as f64 {
+        Some(Ordering::Less)
+    } else if t < i128::MIN as f64 {
+        Some(Ordering::Greater)
+    } else {
+        let trunc = if t >= 0.0 {
+            Int::U(t as u128)
+        } else {
+            Int::I(t as i128)
+        };
+        Some(cmp_ints(int, trunc).then(t.partial_cmp(&f)?))
+    }
+}
+
+/// Casts `value` into an unsigned integer of `bits`.
+fn cast_unsigned(value: &Value, bits: u32, mode: CastMode) -> Result<u128> {
+    if mode == CastMode::Lossless && !value.lossless_unsigned(bits) {
+        return Err(Error::TypeMismatch);
+    }
+    let mode = value.int_mode(mode);
+    let max = u128::MAX >> (128 - bits);
+    let overflow = |low: u128| match mode {
+        CastMode::Saturating => Ok(max),
+        CastMode::Wrapping => Ok(low & max),
+        _ => Err(Error::Overflow),
+    };
+    match value.integer(mode)? {
+        Int::U(u) if u <= max => Ok(u),
+        Int::U(u) | Int::Big(u) => overflow(u),
+        Int::I(i) if i >= 0 && i as u128 <= max => Ok(i as u128),
+        Int::I(i) if i >= 0 => overflow(i as u128),
+        Int::I(i) => match mode {
+            CastMode::Saturating => Ok(0),
+            CastMode::Wrapping => Ok(i as u128 & max),
+            _ => Err(Error::Underflow),
+        },
+    }
+}
+
+/// Casts `value` into a signed integer of `bits`.
+fn cast_signed(value: &Value, bits: u32, mode: CastMode) -> Result<i128> {
+    if mode == CastMode::Lossless && !value.lossless_signed(bits) {
+        return Err(Error::TypeMismatch);
+    }
+    let mode = value.int_mode(mode);
+    let shift = 128 - bits;
+    let (max, min) = (i128::MAX >> shift, i128::MIN >> shift);
+    let wrap = |x: i128| (x << shift) >> shift;
+    let overflow = |low: i128| match mode {
+        CastMode::Saturating => Ok(max),
+        CastMode::Wrapping => Ok(wrap(low)),
+        _ => Err(Error::Overflow),
+    };
+    match value.integer(mode)? {
+        Int::I(i) if i > max => overflow(i),
+        Int::I(i) if i < min => match mode {
+            CastMode::Saturating => Ok(min),
+            CastMode::Wrapping => Ok(wrap(i)),
+            _ => Err(Error::Underflow),
+        },
+        Int::I(i) => Ok(i),
+        Int::U(u) if u <= max as u128 => Ok(u as i128),
+        Int::U(u) | Int::Big(u) => overflow(u as i128),
+    }
+}
+
+/// Casts `value` into a little-endian unsigned integer of `N` bytes.
+fn cast_bytes<const N: usize>(value: &Value, mode: CastMode) -> Result<[u8; N]> {
+    let mut out = [0; N];
+    if let Value::Bytes(bytes, len) = *value {
+        if mode == CastMode::Lossless && len > N {
+            return Err(Error::TypeMismatch);
+        }
+        let n = len.min(N);
+        out[..n].copy_from_slice(&bytes[..n]);
+        if bytes[n..len].iter().any(|b| *b != 0) {
+            match mode {
+                CastMode::Saturating => out = [u8::MAX; N],
+                CastMode::Wrapping => (),
+                _ => return Err(Error::Overflow),
+            }
+        }
+    } else if N < 16 {
+        let u = cast_unsigned(value, N as u32 * 8, mode)?;
+        out.copy_from_slice(&u.to_le_bytes()[..N]);
+    } else {
+        if mode == CastMode::Lossless && !value.lossless_unsigned(N as u32 * 8) {
+            return Err(Error::TypeMismatch);
+        }
+        let mode = value.int_mode(mode);
+        match value.integer(mode)? {
+            Int::U(u) | Int::Big(u) => out[..16].copy_from_slice(&u.to_le_bytes()),
+            Int::I(i) if i >= 0 => out[..16].copy_from_slice(&i.to_le_bytes()),
+            Int::I(i) => match mode {
+                CastMode::Saturating => (),
+                CastMode::Wrapping => {
+                    out.fill(u8::MAX);
+                    out[..16].copy_from_slice(&i.to_le_bytes());
+                }
+                _ => return Err(Error::Underflow),
+            },
+        }
+    }
+    Ok(out)
+}
+
+/// Casts `value` into a float.
+fn cast_float<F: Float>(value: &Value, mode: CastMode) -> Result<F> {
+    if mode == CastMode::Lossless && !value.lossless_float(F::FORMAT) {
+        return Err(Error::TypeMismatch);
+    }
+    let x = value.float();
+    let y = F::from_f64(x);
+    if x.is_finite() && y.is_infinite() {
+        match (mode, x > 0.0) {
+            (CastMode::Saturating, true) => Ok(F::MAX),
+            (CastMode::Saturating, false) => Ok(F::MIN),
+            (CastMode::Wrapping, _) => Ok(y),
+            (_, true) => Err(Error::Overflow),
+            (_, false) => Err(Error::Underflow),
+        }
+    } else {
+        Ok(y)
+    }
+}
+
+/// Casts the data contained in a unit variant.
+pub(crate) trait Castable: Sized {
+    /// Returns the size-agnostic value of the data.
+    fn to_cast_value(&self) -> Result<CastValue>;
+
+    /// Casts the size-agnostic `value` into this type.
+    fn from_cast_value(value: &CastValue, mode: CastMode) -> Result<Self>;
+}
+
+/* integers */
+
+macro_rules! impl_castable_int {
+    (unsigned: $( $type:ty ),+ ; signed: $( $stype:ty ),+ ) => {
+        $(
+            impl Castable for $type {
+                #[inline]
+                fn to_cast_value(&self) -> Result<CastValue> {
+                    Ok(CastValue(Value::Unsigned(*self as u128, Self::BITS)))
+                }
+                #[inline]
+                fn from_cast_value(value: &CastValue, mode: CastMode) -> Result<Self> {
+                    cast_unsigned(&value.0, Self::BITS, mode).map(|u| u as Self)
+                }
+            }
+        )+
+        $(
+            impl Castable for $stype {
+                #[inline]
+                fn to_cast_value(&self) -> Result<CastValue> {
+                    Ok(CastValue(Value::Signed(*self as i128, Self::BITS)))
+                }
+                #[inline]
+                fn from_cast_value(value: &CastValue, mode: CastMode) -> Result<Self> {
+                    cast_signed(&value.0, Self::BITS, mode).map(|i| i as Self)
+                }
+            }
+        )+
+    };
+}
+impl_castable_int![
+    unsigned: u8, u16, u32, u64, u128, usize;
+    signed: i8, i16, i32, i64, i128, isize
+];
+
+/* floats */
+
+/// The floating-point types that can be cast.
+trait Float: Copy {
+    const FORMAT: FloatFormat;
+    const MAX: Self;
+    const MIN: Self;
+    fn from_f64(f: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn is_infinite(self) -> bool;
+}
+
+macro_rules! impl_castable_float {
+    ($( $(#[$attr:meta])* $type:ty: $mantissa:literal, $exponent:literal,
+        |$f:ident| $from_f64:expr, |$s:ident| $to_f64:expr );+ $(;)? ) => {
+        $(
+            $(#[$attr])*
+            impl Float for $type {
+                const FORMAT: FloatFormat = FloatFormat {
+                    mantissa: $mantissa,
+                    exponent: $exponent,
+                };
+                const MAX: Self = <$type>::MAX;
+                const MIN: Self = <$type>::MIN;
+                #[inline]
+                fn from_f64($f: f64) -> Self {
+                    $from_f64
+                }
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    let $s = self;
+                    $to_f64
+                }
+                #[inline]
+                fn is_infinite(self) -> bool {
+                    <$type>::is_infinite(self)
+                }
+            }
+
+            $(#[$attr])*
+            impl Castable for $type {
+                #[inline]
+                fn to_cast_value(&self) -> Result<CastValue> {
+                    Ok(CastValue(Value::Float(Float::to_f64(*self), <Self as Float>::FORMAT)))
+                }
+                #[inline]
+                fn from_cast_value(value: &CastValue, mode: CastMode) -> Result<Self> {
+                    cast_float(&value.0, mode)
+                }
+            }
+        )+
+    };
+}
+impl_castable_float![
+    f32: 24, 8, |f| f as f32, |s| s as f64;
+    f64: 53, 11, |f| f, |s| s;
+    #[cfg(feature = "half")]
+    half::f16: 11, 5, |f| half::f16::from_f64(f), |s| s.to_f64();
+    #[cfg(feature = "half")]
+    half::bf16: 8, 8, |f| half::bf16::from_f64(f), |s| s.to_f64();
+];
+
+/* bool & char */
+
+impl Castable for bool {
+    #[inline]
+    fn to_cast_value(&self) -> Result<CastValue> {
+        Ok(CastValue(Value::Bool(*self)))
+    }
+    #[inline]
+    fn from_cast_value(value: &CastValue, mode: CastMode) -> Result<Self> {
+        cast_unsigned(&value.0, 1, mode).map(|u| u == 1)
+    }
+}
+
+impl Castable for char {
+    #[inline]
+    fn to_cast_value(&self) -> Result<CastValue> {
+        Ok(CastValue(Value::Char(*self)))
+    }
+    /// Only `u8`s (and smaller) can be losslessly cast into a `char`.
+    ///
+    /// The surrogate code points are considered out of range.
+    fn from_cast_value(value: &CastValue, mode: CastMode) -> Result<Self> {
+        if let Value::Char(c) = value.0 {
+            return Ok(c);
+        } else if mode == CastMode::Lossless && !value.0.lossless_unsigned(8) {
+            return Err(Error::TypeMismatch);
+        }
+        let u = cast_unsigned(&value.0, 32, mode)? as u32;
+        match (char::from_u32(u), mode) {
+            (Some(c), _) => Ok(c),
+            (None, CastMode::Saturating) if u > char::MAX as u32 => Ok(char::MAX),
+            (None, CastMode::Saturating) => Ok(char::REPLACEMENT_CHARACTER),
+            (None, CastMode::Wrapping) => {
+                Ok(char::from_u32(u % (char::MAX as u32 + 1))
+                    .unwrap_or(char::REPLACEMENT_CHARACTER))
+            }
+            (None, _) => Err(Error::Overflow),
+        }
+    }
+}
+
+/* byte arrays & bit arrays */
+
+impl<const N: usize> Castable for [u8; N] {
+    fn to_cast_value(&self) -> Result<CastValue> {
+        let mut bytes = [0; MAX_BYTES];
+        bytes[..N].copy_from_slice(self);
+        Ok(CastValue(Value::Bytes(bytes, N)))
+    }
+    #[inline]
+    fn from_cast_value(value: &CastValue, mode: CastMode) -> Result<Self> {
+        cast_bytes(&value.0, mode)
+    }
+}
+
+impl<const BITLEN: usize, const BYTECAP: usize> Castable
+    for crate::list::BitArray<(), BITLEN, BYTECAP>
+{
+    fn to_cast_value(&self) -> Result<CastValue> {
+        let mut bytes = [0; MAX_BYTES];
+        bytes[..BYTECAP].copy_from_slice(self.as_byte_slice());
+        Ok(CastValue(Value::Bytes(bytes, BYTECAP)))
+    }
+    fn from_cast_value(value: &CastValue, mode: CastMode) -> Result<Self> {
+        let bytes: [u8; BYTECAP] = cast_bytes(&value.0, mode)?;
+        let mut bits = Self::new_zeroed_unchecked();
+        bits.as_mut_byte_slice().copy_from_slice(&bytes);
+        bits.clear_unused_bits();
+        Ok(bits)
+    }
+}
+
+/* types that can't be cast */
+
+macro_rules! impl_uncastable {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl Castable for $type {
+                #[inline]
+                fn to_cast_value(&self) -> Result<CastValue> {
+                    Err(Error::TypeMismatch)
+                }
+                #[inline]
+                fn from_cast_value(_value: &CastValue, _mode: CastMode) -> Result<Self> {
+                    Err(Error::TypeMismatch)
+                }
+            }
+        )+
+    };
+}
+impl_uncastable![
+    core::time::Duration,
+    #[cfg(feature = "std")]
+    std::time::Instant,
+    #[cfg(feature = "std")]
+    std::time::SystemTime,
+    #[cfg(feature = "twofloat")]
+    twofloat::TwoFloat,
+    #[cfg(feature = "num-rational")]
+    num_rational::Ratio<i32>,
+    #[cfg(feature = "num-rational")]
+    num_rational::Ratio<i64>,
+    #[cfg(feature = "num-rational")]
+    num_rational::Ratio<i128>,
+    #[cfg(feature = "rust_decimal")]
+    rust_decimal::Decimal,
+    #[cfg(feature = "time")]
+    time::Date,
+    #[cfg(feature = "time")]
+    time::Time,
+    #[cfg(feature = "time")]
+    time::UtcOffset,
+    #[cfg(feature = "time")]
+    time::Duration,
+    #[cfg(feature = "time")]
+    time::PrimitiveDateTime,
+    #[cfg(feature = "time")]
+    time::OffsetDateTime,
+    #[cfg(all(feature = "std", feature = "time"))]
+    #[allow(deprecated)]
+    time::Instant,
+];
+
+#[cfg(feature = "arraystring")]
+impl<SIZE: arraystring::prelude::Capacity> Castable for arraystring::ArrayString<SIZE> {
+    #[inline]
+    fn to_cast_value(&self) -> Result<CastValue> {
+        Err(Error::TypeMismatch)
+    }
+    #[inline]
+    fn from_cast_value(_value: &CastValue, _mode: CastMode) -> Result<Self> {
+        Err(Error::TypeMismatch)
+    }
+}
+
+#[cfg(feature = "fugit")]
+macro_rules! impl_uncastable_fugit {
+    ($( $type:ident<$prim:ty> ),+) => {
+        $(
+            impl<const NOM: u32, const DENOM: u32> Castable for fugit::$type<$prim, NOM, DENOM> {
+                #[inline]
+                fn to_cast_value(&self) -> Result<CastValue> {
+                    Err(Error::TypeMismatch)
+                }
+                #[inline]
+                fn from_cast_value(_value: &CastValue, _mode: CastMode) -> Result<Self> {
+                    Err(Error::TypeMismatch)
+                }
+            }
+        )+
+    };
+}
+#[cfg(feature = "fugit")]
+impl_uncastable_fugit![Duration<u32>, Duration<u64>, Instant<u32>, Instant<u64>];
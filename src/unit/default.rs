@@ -0,0 +1,159 @@
+// ladata::unit::default
+//
+//! Default values for the data contained in the units.
+//!
+//! Every variant gets its default value through the [`DefaultField`] trait:
+//!
+//! - numbers are zero, `bool` is `false` and `char` is `'\0'`.
+//! - byte arrays, bit arrays and strings are empty or zeroed.
+//! - durations are zero, and points in time are the unix epoch.
+//! - instants have no meaningful default, so they don't have one.
+//
+
+/// Returns the default value of the data contained in a unit variant.
+pub(crate) trait DefaultField: Sized {
+    fn default_field() -> Option<Self>;
+}
+
+/* forwarding to the `Default` implementation */
+
+macro_rules! forward {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl DefaultField for $type {
+                #[inline]
+                fn default_field() -> Option<Self> {
+                    Some(Default::default())
+                }
+            }
+        )+
+    };
+}
+forward![
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64, bool, char,
+    core::time::Duration,
+    #[cfg(feature = "half")] half::f16,
+    #[cfg(feature = "half")] half::bf16,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i32>,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i64>,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i128>,
+    #[cfg(feature = "num-bigint")] num_bigint::BigInt,
+    #[cfg(feature = "rust_decimal")] rust_decimal::Decimal,
+    #[cfg(feature = "twofloat")] twofloat::TwoFloat,
+    #[cfg(feature = "time")] time::Duration,
+    #[cfg(feature = "std")] std::string::String,
+];
+
+/* byte arrays, bit arrays & strings */
+
+impl<const N: usize> DefaultField for [u8; N] {
+    #[inline]
+    fn default_field() -> Option<Self> {
+        Some([0; N])
+    }
+}
+
+impl<const BITLEN: usize, const BYTECAP: usize> DefaultField
+    for crate::list::BitArray<(), BITLEN, BYTECAP>
+{
+    #[inline]
+    fn default_field() -> Option<Self> {
+        Some(Default::default())
+    }
+}
+
+#[cfg(feature = "arraystring")]
+impl<SIZE: arraystring::prelude::Capacity> DefaultField for arraystring::ArrayString<SIZE> {
+    #[inline]
+    fn default_field() -> Option<Self> {
+        Some(Default::default())
+    }
+}
+
+/* time */
+
+#[cfg(feature = "std")]
+impl DefaultField for std::time::SystemTime {
+    #[inline]
+    fn default_field() -> Option<Self> {
+        Some(std::time::UNIX_EPOCH)
+    }
+}
+
+#[cfg(feature = "fugit")]
+macro_rules! impl_fugit {
+    ($( $type:ident<$prim:ty> ),+) => {
+        $(
+            impl<const NOM: u32, const DENOM: u32> DefaultField for fugit::$type<$prim, NOM, DENOM> {
+                #[inline]
+                fn default_field() -> Option<Self> {
+                    Some(Self::from_ticks(0))
+                }
+            }
+        )+
+    };
+}
+#[cfg(feature = "fugit")]
+impl_fugit![Duration<u32>, Duration<u64>, Instant<u32>, Instant<u64>];
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::DefaultField;
+    use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    impl DefaultField for Date {
+        #[inline]
+        fn default_field() -> Option<Self> {
+            Some(OffsetDateTime::UNIX_EPOCH.date())
+        }
+    }
+    impl DefaultField for Time {
+        #[inline]
+        fn default_field() -> Option<Self> {
+            Some(Time::MIDNIGHT)
+        }
+    }
+    impl DefaultField for UtcOffset {
+        #[inline]
+        fn default_field() -> Option<Self> {
+            Some(UtcOffset::UTC)
+        }
+    }
+    impl DefaultField for PrimitiveDateTime {
+        #[inline]
+        fn default_field() -> Option<Self> {
+            let epoch = OffsetDateTime::UNIX_EPOCH;
+            Some(PrimitiveDateTime::new(epoch.date(), epoch.time()))
+        }
+    }
+    impl DefaultField for OffsetDateTime {
+        #[inline]
+        fn default_field() -> Option<Self> {
+            Some(OffsetDateTime::UNIX_EPOCH)
+        }
+    }
+}
+
+/* without a default */
+
+macro_rules! no_default {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl DefaultField for $type {
+                #[inline]
+                fn default_field() -> Option<Self> {
+                    None
+                }
+            }
+        )+
+    };
+}
+no_default![
+    #[cfg(feature = "std")]
+    std::time::Instant,
+    #[cfg(all(feature = "std", feature = "time"))]
+    #[allow(deprecated)]
+    time::Instant,
+];
@@ -0,0 +1,444 @@
+// ladata::unit::encoding
+//
+//! Compact binary encoding of the `Copy` data units.
+//!
+//! A unit is encoded as a one-byte discriminant, taken from its matching
+//! *data type*, followed by a little-endian payload of exactly
+//! [`data_size`][super::DataType::data_size] bytes, zero-padded if needed,
+//! except for `usize` and `isize`, which are always encoded in 8 bytes,
+//! so that they can be decoded on platforms of a different pointer width.
+//!
+//! A `With` unit can only be encoded through a [`DataTypeRegistry`], as its
+//! discriminant followed by the little-endian `u32` id of its registered
+//! *data type*, and the payload written by [`DataUnitPayload`].
+//
+
+use super::{DataTypeRegistry, DataUnitPayload};
+use crate::error::{LadataError as Error, LadataResult as Result};
+use core::mem::size_of;
+
+/// Encodes and decodes the data contained in a unit variant.
+///
+/// The `payload` is always exactly [`LEN`][Self::LEN] bytes long,
+/// and it's zeroed before encoding.
+pub(crate) trait EncodeField: Sized {
+    /// The length of the encoded payload.
+    const LEN: usize = size_of::<Self>();
+
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()>;
+    fn decode_field(payload: &[u8]) -> Result<Self>;
+}
+
+/// Encodes a unit without data, returning the number of bytes written.
+#[inline]
+pub(crate) fn encode_tag(tag: u8, bytes: &mut [u8]) -> Result<usize> {
+    *bytes.first_mut().ok_or(Error::NotEnoughSpace(Some(1)))? = tag;
+    Ok(1)
+}
+
+/// Encodes a unit with data, returning the number of bytes written.
+pub(crate) fn encode<T: EncodeField>(tag: u8, value: &T, bytes: &mut [u8]) -> Result<usize> {
+    let len = 1 + T::LEN;
+    let bytes = bytes
+        .get_mut(..len)
+        .ok_or(Error::NotEnoughSpace(Some(len)))?;
+    bytes[1..].fill(0);
+    value.encode_field(&mut bytes[1..])?;
+    bytes[0] = tag;
+    Ok(len)
+}
+
+/// Decodes the data of a unit, returning it with the number of bytes read.
+pub(crate) fn decode<T: EncodeField>(bytes: &[u8]) -> Result<(T, usize)> {
+    let len = 1 + T::LEN;
+    let payload = bytes.get(1..len).ok_or(Error::NotEnoughSpace(Some(len)))?;
+    Ok((T::decode_field(payload)?, len))
+}
+
+/// Encodes a `With` unit, returning the number of bytes written.
+pub(crate) fn encode_with<C, R>(tag: u8, unit: &C, registry: &R, bytes: &mut [u8]) -> Result<usize>
+where
+    C: DataUnitPayload,
+    R: DataTypeRegistry<Type = C::Type>,
+{
+    let (id, _) = registry
+        .registry_lookup_by_type(&unit.unit_type())
+        .ok_or(Error::KeyNotFound)?;
+    let header = bytes.get_mut(..5).ok_or(Error::NotEnoughSpace(Some(5)))?;
+    header[0] = tag;
+    header[1..].copy_from_slice(&id.to_le_bytes());
+    Ok(5 + unit.encode_payload(&mut bytes[5..])?)
+}
+
+/// Decodes a `With` unit, returning it with the number of bytes read.
+pub(crate) fn decode_with<C, R>(registry: &R, bytes: &[u8]) -> Result<(C, usize)>
+where
+    C: DataUnitPayload,
+    R: DataTypeRegistry<Type = C::Type>,
+{
+    let id = bytes.get(1..5).ok_or(Error::NotEnoughSpace(Some(5)))?;
+    let (_, data_type) = registry
+        .registry_lookup_by_id(u32::from_le_bytes(le_array(id)))
+        .ok_or(Error::KeyNotFound)?;
+    let (unit, len) = C::decode_payload(data_type, &bytes[5..])?;
+    Ok((unit, 5 + len))
+}
+
+/* primitives */
+
+macro_rules! impl_le_bytes {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl EncodeField for $type {
+                #[inline]
+                fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+                    payload.copy_from_slice(&self.to_le_bytes());
+                    Ok(())
+                }
+                #[inline]
+                fn decode_field(payload: &[u8]) -> Result<Self> {
+                    Ok(Self::from_le_bytes(le_array(payload)))
+                }
+            }
+        )+
+    };
+}
+impl_le_bytes![
+    u8,
+    i8,
+    u16,
+    i16,
+    u32,
+    i32,
+    u64,
+    i64,
+    u128,
+    i128,
+    f32,
+    f64,
+    #[cfg(feature = "half")]
+    half::f16,
+    #[cfg(feature = "half")]
+    half::bf16,
+];
+
+macro_rules! impl_psize {
+    ($( $psize:ty, $fixed:ty );+ $(;)?) => {
+        $(
+            /// Encoded as a
+            #[doc = concat!("`", stringify!($fixed), "`")]
+            /// in any platform, failing to decode if the value doesn't fit.
+            impl EncodeField for $psize {
+                const LEN: usize = size_of::<$fixed>();
+
+                #[inline]
+                fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+                    <$fixed>::try_from(*self)
+                        .map_err(|_| Error::InvalidEncoding)?
+                        .encode_field(payload)
+                }
+                #[inline]
+                fn decode_field(payload: &[u8]) -> Result<Self> {
+                    Self::try_from(<$fixed>::decode_field(payload)?)
+                        .map_err(|_| Error::InvalidEncoding)
+                }
+            }
+        )+
+    };
+}
+impl_psize![usize, u64; isize, i64];
+
+/// Returns the first `N` bytes of the slice as an array.
+#[inline]
+fn le_array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    let mut array = [0; N];
+    array.copy_from_slice(&bytes[..N]);
+    array
+}
+
+impl EncodeField for bool {
+    #[inline]
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        payload[0] = u8::from(*self);
+        Ok(())
+    }
+    #[inline]
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        match payload[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::InvalidEncoding),
+        }
+    }
+}
+
+impl EncodeField for char {
+    #[inline]
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        u32::from(*self).encode_field(payload)
+    }
+    #[inline]
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        char::from_u32(u32::decode_field(payload)?).ok_or(Error::InvalidEncoding)
+    }
+}
+
+impl<const N: usize> EncodeField for [u8; N] {
+    #[inline]
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        payload.copy_from_slice(self);
+        Ok(())
+    }
+    #[inline]
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        Ok(le_array(payload))
+    }
+}
+
+impl<const BITLEN: usize, const BYTECAP: usize> EncodeField
+    for crate::list::BitArray<(), BITLEN, BYTECAP>
+{
+    #[inline]
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        payload[..BYTECAP].copy_from_slice(self.as_byte_slice());
+        Ok(())
+    }
+    #[inline]
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        let mut array = Self::new_zeroed_unchecked();
+        array
+            .as_mut_byte_slice()
+            .copy_from_slice(&payload[..BYTECAP]);
+        array.clear_unused_bits();
+        Ok(array)
+    }
+}
+
+/// Encoded as the whole seconds (`u64`) followed by the nanoseconds (`u32`).
+impl EncodeField for core::time::Duration {
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        self.as_secs().encode_field(&mut payload[..8])?;
+        self.subsec_nanos().encode_field(&mut payload[8..12])
+    }
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        let nanos = u32::decode_field(&payload[8..12])?;
+        if nanos < 1_000_000_000 {
+            Ok(Self::new(u64::decode_field(&payload[..8])?, nanos))
+        } else {
+            Err(Error::InvalidEncoding)
+        }
+    }
+}
+
+/* dependencies */
+
+#[cfg(feature = "twofloat")]
+impl EncodeField for twofloat::TwoFloat {
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        self.hi().encode_field(&mut payload[..8])?;
+        self.lo().encode_field(&mut payload[8..16])
+    }
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        let (hi, lo) = (
+            f64::decode_field(&payload[..8])?,
+            f64::decode_field(&payload[8..16])?,
+        );
+        Self::try_from((hi, lo)).map_err(|_| Error::InvalidEncoding)
+    }
+}
+
+#[cfg(feature = "num-rational")]
+macro_rules! impl_ratio {
+    ($( $prim:ty ),+) => {
+        $(
+            /// Encoded as the numerator followed by the denominator.
+            impl EncodeField for num_rational::Ratio<$prim> {
+                fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+                    let (n, d) = payload.split_at_mut(size_of::<$prim>());
+                    self.numer().encode_field(n)?;
+                    self.denom().encode_field(d)
+                }
+                fn decode_field(payload: &[u8]) -> Result<Self> {
+                    let (n, d) = payload.split_at(size_of::<$prim>());
+                    let denom = <$prim>::decode_field(d)?;
+                    if denom == 0 {
+                        Err(Error::InvalidEncoding)
+                    } else {
+                        Ok(Self::new_raw(<$prim>::decode_field(n)?, denom))
+                    }
+                }
+            }
+        )+
+    };
+}
+#[cfg(feature = "num-rational")]
+impl_ratio![i32, i64, i128];
+
+#[cfg(feature = "rust_decimal")]
+impl EncodeField for rust_decimal::Decimal {
+    #[inline]
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        payload.copy_from_slice(&self.serialize());
+        Ok(())
+    }
+    #[inline]
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        Ok(Self::deserialize(le_array(payload)))
+    }
+}
+
+/// Encoded as the length in bytes followed by the UTF-8 bytes.
+#[cfg(feature = "arraystring")]
+impl<SIZE: arraystring::prelude::Capacity> EncodeField for arraystring::ArrayString<SIZE> {
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        let bytes = self.as_bytes();
+        payload[0] = self.len();
+        payload[1..=bytes.len()].copy_from_slice(bytes);
+        Ok(())
+    }
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        let bytes = payload
+            .get(1..=payload[0] as usize)
+            .ok_or(Error::InvalidEncoding)?;
+        Self::try_from_utf8(bytes).map_err(|_| Error::InvalidEncoding)
+    }
+}
+
+#[cfg(feature = "fugit")]
+macro_rules! impl_fugit {
+    ($( $type:ident<$prim:ty> ),+) => {
+        $(
+            /// Encoded as the number of ticks.
+            impl<const NOM: u32, const DENOM: u32> EncodeField for fugit::$type<$prim, NOM, DENOM> {
+                #[inline]
+                fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+                    self.ticks().encode_field(payload)
+                }
+                #[inline]
+                fn decode_field(payload: &[u8]) -> Result<Self> {
+                    Ok(Self::from_ticks(<$prim>::decode_field(payload)?))
+                }
+            }
+        )+
+    };
+}
+#[cfg(feature = "fugit")]
+impl_fugit![Duration<u32>, Duration<u64>, Instant<u32>, Instant<u64>];
+
+/// Encoded as the julian day number (`i32`).
+#[cfg(feature = "time")]
+impl EncodeField for time::Date {
+    #[inline]
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        self.to_julian_day().encode_field(&mut payload[..4])
+    }
+    #[inline]
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        Self::from_julian_day(i32::decode_field(&payload[..4])?).map_err(|_| Error::InvalidEncoding)
+    }
+}
+
+/// Encoded as the hour, minute, second (`u8`s) and nanosecond (`u32`).
+#[cfg(feature = "time")]
+impl EncodeField for time::Time {
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        let (h, m, s, n) = self.as_hms_nano();
+        payload[..3].copy_from_slice(&[h, m, s]);
+        n.encode_field(&mut payload[3..7])
+    }
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        let n = u32::decode_field(&payload[3..7])?;
+        Self::from_hms_nano(payload[0], payload[1], payload[2], n)
+            .map_err(|_| Error::InvalidEncoding)
+    }
+}
+
+/// Encoded as the hours, minutes and seconds (`i8`s).
+#[cfg(feature = "time")]
+impl EncodeField for time::UtcOffset {
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        let (h, m, s) = self.as_hms();
+        payload[..3].copy_from_slice(&[h as u8, m as u8, s as u8]);
+        Ok(())
+    }
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        let [h, m, s] = [payload[0] as i8, payload[1] as i8, payload[2] as i8];
+        Self::from_hms(h, m, s).map_err(|_| Error::InvalidEncoding)
+    }
+}
+
+/// Encoded as the whole seconds (`i64`) followed by the nanoseconds (`i32`).
+#[cfg(feature = "time")]
+impl EncodeField for time::Duration {
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        self.whole_seconds().encode_field(&mut payload[..8])?;
+        self.subsec_nanoseconds().encode_field(&mut payload[8..12])
+    }
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        let secs = i64::decode_field(&payload[..8])?;
+        let nanos = i32::decode_field(&payload[8..12])?;
+        let mixed_signs = (secs > 0 && nanos < 0) || (secs < 0 && nanos > 0);
+        if nanos.unsigned_abs() >= 1_000_000_000 || mixed_signs {
+            return Err(Error::InvalidEncoding);
+        }
+        Self::seconds(secs)
+            .checked_add(Self::nanoseconds(nanos.into()))
+            .ok_or(Error::InvalidEncoding)
+    }
+}
+
+/// Encoded as the date (4 bytes) followed by the time (7 bytes).
+#[cfg(feature = "time")]
+impl EncodeField for time::PrimitiveDateTime {
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        self.date().encode_field(&mut payload[..4])?;
+        self.time().encode_field(&mut payload[4..11])
+    }
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        Ok(Self::new(
+            time::Date::decode_field(&payload[..4])?,
+            time::Time::decode_field(&payload[4..11])?,
+        ))
+    }
+}
+
+/// Encoded as the local date and time (11 bytes) followed by the offset (3 bytes).
+#[cfg(feature = "time")]
+impl EncodeField for time::OffsetDateTime {
+    fn encode_field(&self, payload: &mut [u8]) -> Result<()> {
+        time::PrimitiveDateTime::new(self.date(), self.time()).encode_field(&mut payload[..11])?;
+        self.offset().encode_field(&mut payload[11..14])
+    }
+    fn decode_field(payload: &[u8]) -> Result<Self> {
+        let datetime = time::PrimitiveDateTime::decode_field(&payload[..11])?;
+        Ok(datetime.assume_offset(time::UtcOffset::decode_field(&payload[11..14])?))
+    }
+}
+
+/* types without a portable representation */
+
+macro_rules! impl_unencodable {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl EncodeField for $type {
+                fn encode_field(&self, _payload: &mut [u8]) -> Result<()> {
+                    Err(Error::InvalidEncoding)
+                }
+                fn decode_field(_payload: &[u8]) -> Result<Self> {
+                    Err(Error::InvalidEncoding)
+                }
+            }
+        )+
+    };
+}
+impl_unencodable![
+    #[cfg(feature = "std")]
+    std::time::Instant,
+    #[cfg(feature = "std")]
+    std::time::SystemTime,
+    #[cfg(all(feature = "std", feature = "time"))]
+    #[allow(deprecated)]
+    time::Instant,
+];
@@ -10,12 +10,23 @@
 // - define_type
 // - define_unit
 // - define_raw
+// - define_column
+// - define_tagged
 //
 // - type_aliases
 //
 // - impl_data_type
 // - impl_data_unit
 // - impl_data_raw
+// - impl_data_column
+// - impl_data_encoding
+// - impl_data_cast
+// - impl_data_text
+// - impl_data_ord
+// - impl_data_accepts
+// - impl_data_arith
+// - impl_data_link
+// - impl_data_resize
 //
 // - reexport
 
@@ -42,10 +53,11 @@
 /// - the `psize` suffix indicates a dependency on a specific pointer size.
 ///
 /// The `define_single_size!` macro is called making sure each size contains
-/// all variants with a size less than or equal to the current size.
+/// all variants with a size less than or equal to the current size, and
+/// receives the list of larger sizes, for converting between them.
 macro_rules! define_all_sizes {
     (
-        $tname:ident, $cname:ident, $bname:ident,
+        $tname:ident, $cname:ident, $bname:ident, $lname:ident, $gname:ident,
 
         // 1-Byte / 8-bit
         copy_variants_1B: $( $cvdoc_1B:literal, $cvname_1B:ident, $cvtype_1B:ty ),* ,
@@ -195,8 +207,9 @@ macro_rules! define_all_sizes {
     ) => {
         // 1-Byte / 8-bit
         define_single_size! {
-            $tname, $cname, $bname,
+            $tname, $cname, $bname, $lname, $gname,
             size: 1, 8,
+            larger: (2, 16) (4, 32) (8, 64) (16, 128) (32, 256) (64, 512) (128, 1024);
             copy_variants:
                 $( $cvdoc_1B, $cvname_1B, $cvtype_1B ),*
                 ;
@@ -230,8 +243,9 @@ macro_rules! define_all_sizes {
 
         // 2-Byte / 16-bit
         define_single_size! {
-            $tname, $cname, $bname,
+            $tname, $cname, $bname, $lname, $gname,
             size: 2, 16,
+            larger: (4, 32) (8, 64) (16, 128) (32, 256) (64, 512) (128, 1024);
             copy_variants:
                 $( $cvdoc_1B, $cvname_1B, $cvtype_1B ),* ,
                 $( $cvdoc_2B, $cvname_2B, $cvtype_2B ),*
@@ -277,8 +291,9 @@ macro_rules! define_all_sizes {
 
         // 4-Byte / 32-bit
         define_single_size! {
-            $tname, $cname, $bname,
+            $tname, $cname, $bname, $lname, $gname,
             size: 4, 32,
+            larger: (8, 64) (16, 128) (32, 256) (64, 512) (128, 1024);
             copy_variants:
                 $( $cvdoc_1B, $cvname_1B, $cvtype_1B ),* ,
                 $( $cvdoc_2B, $cvname_2B, $cvtype_2B ),* ,
@@ -335,8 +350,9 @@ macro_rules! define_all_sizes {
 
         // 8-Byte / 32-bit
         define_single_size! {
-            $tname, $cname, $bname,
+            $tname, $cname, $bname, $lname, $gname,
             size: 8, 64,
+            larger: (16, 128) (32, 256) (64, 512) (128, 1024);
             copy_variants:
                 $( $cvdoc_1B, $cvname_1B, $cvtype_1B ),* ,
                 $( $cvdoc_2B, $cvname_2B, $cvtype_2B ),* ,
@@ -406,8 +422,9 @@ macro_rules! define_all_sizes {
 
         // 16-Byte / 64-bit
         define_single_size! {
-            $tname, $cname, $bname,
+            $tname, $cname, $bname, $lname, $gname,
             size: 16, 128,
+            larger: (32, 256) (64, 512) (128, 1024);
             copy_variants:
                 $( $cvdoc_1B, $cvname_1B, $cvtype_1B ),* ,
                 $( $cvdoc_2B, $cvname_2B, $cvtype_2B ),* ,
@@ -489,8 +506,9 @@ macro_rules! define_all_sizes {
 
         // 32-Byte / 128-bit
         define_single_size! {
-            $tname, $cname, $bname,
+            $tname, $cname, $bname, $lname, $gname,
             size: 32, 256,
+            larger: (64, 512) (128, 1024);
             copy_variants:
                 $( $cvdoc_1B, $cvname_1B, $cvtype_1B ),* ,
                 $( $cvdoc_2B, $cvname_2B, $cvtype_2B ),* ,
@@ -584,8 +602,9 @@ macro_rules! define_all_sizes {
         }
 
         define_single_size! {
-            $tname, $cname, $bname,
+            $tname, $cname, $bname, $lname, $gname,
             size: 64, 512, // 64-Byte / 512-bit
+            larger: (128, 1024);
             copy_variants:
                 $( $cvdoc_1B, $cvname_1B, $cvtype_1B ),* ,
                 $( $cvdoc_2B, $cvname_2B, $cvtype_2B ),* ,
@@ -694,8 +713,9 @@ macro_rules! define_all_sizes {
         }
 
         define_single_size! {
-            $tname, $cname, $bname,
+            $tname, $cname, $bname, $lname, $gname,
             size: 128, 1024, // 128-Byte / 1024-bit
+            larger: ;
             copy_variants:
                 $( $cvdoc_1B, $cvname_1B, $cvtype_1B ),* ,
                 $( $cvdoc_2B, $cvname_2B, $cvtype_2B ),* ,
@@ -820,11 +840,12 @@ macro_rules! define_all_sizes {
 }
 pub(crate) use define_all_sizes;
 
-/// for defining in one pass: DataType*, DataUnit*, DataRaw*
+/// for defining in one pass: DataType*, DataUnit*, DataRaw*, DataColumn*
 macro_rules! define_single_size {
     (
-        $tname:ident, $cname:ident, $bname:ident,
+        $tname:ident, $cname:ident, $bname:ident, $lname:ident, $gname:ident,
         size: $B:literal, $b:literal,
+        larger: $( ($LB:literal, $lb:literal) )* ;
         copy_variants:
             $( $cvdoc:literal, $cvname:ident, $cvtype:ty ),*
             $(,)* ;
@@ -922,6 +943,166 @@ macro_rules! define_single_size {
                 $( $vdoc_psize_dep, $vname_psize_dep, $vtype_psize_dep, $vpsize_psize_dep,
                 $vdep1_psize_dep, $vdep2_psize_dep ),* ;
         }
+        #[cfg(feature = "unsafe_unit")]
+        define_tagged!{
+            g: $gname, c: $cname, t: $tname, b: $bname, size: $B, $b,
+            copy_variants:
+                $( $cvname, $cvtype, (all()); )*
+                $( $cvname_psize, $cvtype_psize, ($cvpsize_psize); )*
+                $( $cvname_dep, $cvtype_dep, (all(feature = $cvdep1_dep, feature = $cvdep2_dep)); )*
+                $( $cvname_psize_dep, $cvtype_psize_dep,
+                    (all($cvpsize_psize_dep,
+                        feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep)); )*
+                ;
+        }
+        impl_data_encoding!{
+            t: $tname, c: $cname, size: $B, $b,
+            copy_variants:
+                $( $cvname, $cvtype ),* ;
+            copy_variants_psize:
+                $( $cvname_psize, $cvtype_psize, ($cvpsize_psize); )*
+                ;
+            copy_variants_dep:
+                $( $cvname_dep, $cvtype_dep, (all(feature = $cvdep1_dep, feature = $cvdep2_dep)); )*
+                $( $cvname_psize_dep, $cvtype_psize_dep,
+                    (all($cvpsize_psize_dep,
+                        feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep)); )*
+                ;
+        }
+        impl_data_cast!{
+            t: $tname, c: $cname, size: $B, $b,
+            copy_variants:
+                $( $cvname, (all()); )*
+                $( $cvname_psize, ($cvpsize_psize); )*
+                $( $cvname_dep, (all(feature = $cvdep1_dep, feature = $cvdep2_dep)); )*
+                $( $cvname_psize_dep,
+                    (all($cvpsize_psize_dep,
+                        feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep)); )*
+                ;
+        }
+        impl_data_text!{
+            t: $tname, c: $cname, size: $B, $b,
+            copy_variants:
+                $( $cvname, (all()); )*
+                $( $cvname_psize, ($cvpsize_psize); )*
+                $( $cvname_dep, (all(feature = $cvdep1_dep, feature = $cvdep2_dep)); )*
+                $( $cvname_psize_dep,
+                    (all($cvpsize_psize_dep,
+                        feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep)); )*
+                ;
+            noncopy_variants:
+                $( $vname, (all()); )*
+                $( $vname_dep, (all(feature = $vdep1_dep, feature = $vdep2_dep)); )*
+                $( $vname_psize_dep,
+                    (all($vpsize_psize_dep,
+                        feature = $vdep1_psize_dep, feature = $vdep2_psize_dep)); )*
+                ;
+        }
+        impl_data_ord!{
+            t: $tname, c: $cname, size: $B, $b,
+            copy_variants:
+                $( $cvname, (all()); )*
+                $( $cvname_psize, ($cvpsize_psize); )*
+                $( $cvname_dep, (all(feature = $cvdep1_dep, feature = $cvdep2_dep)); )*
+                $( $cvname_psize_dep,
+                    (all($cvpsize_psize_dep,
+                        feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep)); )*
+                ;
+            noncopy_variants:
+                $( $vname, (all()); )*
+                $( $vname_dep, (all(feature = $vdep1_dep, feature = $vdep2_dep)); )*
+                $( $vname_psize_dep,
+                    (all($vpsize_psize_dep,
+                        feature = $vdep1_psize_dep, feature = $vdep2_psize_dep)); )*
+                ;
+        }
+        impl_data_accepts!{
+            t: $tname, c: $cname, size: $B, $b,
+            variants:
+                $( $cvname, (all()); )*
+                $( $cvname_psize, ($cvpsize_psize); )*
+                $( $cvname_dep, (all(feature = $cvdep1_dep, feature = $cvdep2_dep)); )*
+                $( $cvname_psize_dep,
+                    (all($cvpsize_psize_dep,
+                        feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep)); )*
+                ;
+            noncopy_variants:
+                $( $vname, (all()); )*
+                $( $vname_dep, (all(feature = $vdep1_dep, feature = $vdep2_dep)); )*
+                $( $vname_psize_dep,
+                    (all($vpsize_psize_dep,
+                        feature = $vdep1_psize_dep, feature = $vdep2_psize_dep)); )*
+                ;
+        }
+        impl_data_arith!{
+            t: $tname, c: $cname, size: $B, $b,
+            copy_variants:
+                $( $cvname, (all()); )*
+                $( $cvname_psize, ($cvpsize_psize); )*
+                $( $cvname_dep, (all(feature = $cvdep1_dep, feature = $cvdep2_dep)); )*
+                $( $cvname_psize_dep,
+                    (all($cvpsize_psize_dep,
+                        feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep)); )*
+                ;
+        }
+        impl_data_link!{
+            t: $tname, c: $cname, size: $B, $b,
+            copy_variants:
+                $( $cvname, (all()); )*
+                $( $cvname_psize, ($cvpsize_psize); )*
+                $( $cvname_dep, (all(feature = $cvdep1_dep, feature = $cvdep2_dep)); )*
+                $( $cvname_psize_dep,
+                    (all($cvpsize_psize_dep,
+                        feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep)); )*
+                ;
+            noncopy_variants:
+                $( $vname, (all()); )*
+                $( $vname_dep, (all(feature = $vdep1_dep, feature = $vdep2_dep)); )*
+                $( $vname_psize_dep,
+                    (all($vpsize_psize_dep,
+                        feature = $vdep1_psize_dep, feature = $vdep2_psize_dep)); )*
+                ;
+        }
+        impl_data_resize!{
+            t: $tname, c: $cname, b: $bname, g: $gname, size: $B, $b,
+            larger: $( ($LB, $lb) )* ;
+            copy_variants: [
+                $( $cvname, (all()); )*
+                $( $cvname_psize, ($cvpsize_psize); )*
+                $( $cvname_dep, (all(feature = $cvdep1_dep, feature = $cvdep2_dep)); )*
+                $( $cvname_psize_dep,
+                    (all($cvpsize_psize_dep,
+                        feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep)); )*
+            ];
+            noncopy_variants: [
+                $( $vname, (all()); )*
+                $( $vname_dep, (all(feature = $vdep1_dep, feature = $vdep2_dep)); )*
+                $( $vname_psize_dep,
+                    (all($vpsize_psize_dep,
+                        feature = $vdep1_psize_dep, feature = $vdep2_psize_dep)); )*
+            ];
+        }
+        #[cfg(feature = "alloc")]
+        define_column!{
+            l: $lname, c: $cname, t: $tname, size: $B, $b,
+            copy_variants:
+                $( $cvdoc, $cvname, $cvtype, (all()); )*
+                $( $cvdoc_psize, $cvname_psize, $cvtype_psize, ($cvpsize_psize); )*
+                $( $cvdoc_dep, $cvname_dep, $cvtype_dep,
+                    (all(feature = $cvdep1_dep, feature = $cvdep2_dep)); )*
+                $( $cvdoc_psize_dep, $cvname_psize_dep, $cvtype_psize_dep,
+                    (all($cvpsize_psize_dep,
+                        feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep)); )*
+                ;
+            noncopy_variants:
+                $( $vdoc, $vname, $vtype, (all()); )*
+                $( $vdoc_dep, $vname_dep, $vtype_dep,
+                    (all(feature = $vdep1_dep, feature = $vdep2_dep)); )*
+                $( $vdoc_psize_dep, $vname_psize_dep, $vtype_psize_dep,
+                    (all($vpsize_psize_dep,
+                        feature = $vdep1_psize_dep, feature = $vdep2_psize_dep)); )*
+                ;
+        }
     };
 }
 pub(crate) use define_single_size;
@@ -971,7 +1152,8 @@ macro_rules! define_type {
             #[doc = "- [" [<$tname $b bit With>]  "][" [<$tname $b bit With>] "] -Copy" ]
             #[doc = "- [" [<$tname $b bit Copy>]  "][" [<$tname $b bit Copy>] "] -With" ]
             #[doc = "- [" [<$tname $b bit>]  "][" [<$tname $b bit>] "] -Copy -With" ]
-            #[derive(Clone, Copy, Debug)]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum [< $tname $b bit Copy With >]<T: DataTypeCopy> {
                 /// Represents the absence of *data type*.
                 None,
@@ -1041,7 +1223,8 @@ macro_rules! define_type {
             #[doc = "- [" [<$tname $b bit Copy With>] "][" [<$tname $b bit Copy With>] "] +Copy" ]
             #[doc = "- [" [<$tname $b bit>] "][" [<$tname $b bit>] "] -With" ]
             #[doc = "- [" [<$tname $b bit Copy>] "][" [<$tname $b bit Copy>] "] +Copy -With" ]
-            #[derive(Clone, Copy, Debug)]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum [< $tname $b bit With >]<T: DataType> {
                 /// Represents the absence of *data type*.
                 None,
@@ -1178,6 +1361,7 @@ macro_rules! define_unit {
             #[doc = "- [" [<$cname $b bit Copy>] "][" [<$cname $b bit Copy>] "] -With" ]
             #[doc = "- [" [<$cname $b bit>] "][" [<$cname $b bit>] "] -Copy -With" ]
             #[derive(Clone, Copy, Debug)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             // pub enum [<$cname $b bit Copy With>]<C: DataUnitCopy, T: DataTypeCopy> { // MAYBE
             pub enum [<$cname $b bit Copy With>]<C: DataUnitCopy> {
                 /// Represents the absence of *data*.
@@ -1188,12 +1372,14 @@ macro_rules! define_unit {
 
                 $( // fundamental types
                     #[doc = $cvdoc]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $cvname($cvtype),
                 )*
 
                 $( // pointer-size dependant
                     #[cfg($cvpsize_psize)]
                     #[doc = $cvdoc_psize]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $cvname_psize($cvtype_psize),
                 )*
 
@@ -1201,6 +1387,7 @@ macro_rules! define_unit {
                     #[cfg(all(feature = $cvdep1_dep, feature = $cvdep2_dep))]
                     #[cfg_attr(feature = "nightly", doc(cfg(all(feature = $cvdep1_dep, feature = $cvdep2_dep))))]
                     #[doc = $cvdoc_dep]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $cvname_dep($cvtype_dep),
                 )*
 
@@ -1211,6 +1398,7 @@ macro_rules! define_unit {
                     #[cfg_attr(feature = "nightly",
                         doc(cfg(all(feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep))))]
                     #[doc = $cvdoc_psize_dep]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $cvname_psize_dep($cvtype_psize_dep),
                 )*
             }
@@ -1222,7 +1410,7 @@ macro_rules! define_unit {
             #[doc = "- [" [<$cname $b bit>] "][" [<$cname $b bit>] "] -Copy" ]
             #[doc = "- [" [<$cname $b bit Copy With>] "][" [<$cname $b bit Copy With>] "] +With" ]
             #[doc = "- [" [<$cname $b bit With>] "][" [<$cname $b bit With>] "] -Copy +With" ]
-            pub type [<$cname $b bit Copy>] = [< $cname $b bit With>]<()>;
+            pub type [<$cname $b bit Copy>] = [< $cname $b bit Copy With>]<()>;
 
             impl_data_unit![
                 c: [< $cname $b bit Copy With >], DataUnitCopy,
@@ -1243,7 +1431,8 @@ macro_rules! define_unit {
             ];
             // MAYBE
             // impl<C: DataUnitCopy, T: DataTypeCopy> DataUnitCopy for [< $cname $b bit Copy With >]<C, T> { }
-            impl<C: DataUnitCopy> DataUnitCopy for [< $cname $b bit Copy With >]<C> { }
+            impl<C: DataUnitCopy> DataUnitCopy for [< $cname $b bit Copy With >]<C>
+                where C::Type: DataTypeCopy { }
 
             // ## non-copy version (DataUnit)
             // -----------------------------------------------------------------
@@ -1254,6 +1443,7 @@ macro_rules! define_unit {
             #[doc = "- [" [<$cname $b bit>] "][" [<$cname $b bit>] "] -Width" ]
             #[doc = "- [" [<$cname $b bit Copy>] "][" [<$cname $b bit Copy>] "] +Copy -Width" ]
             #[derive(Debug)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             // pub enum [<$cname $b bit With>]<C: DataUnit, T: DataType> { // MAYBE
             pub enum [<$cname $b bit With>]<C: DataUnit> {
                 /// Represents the absence of *data*.
@@ -1264,16 +1454,19 @@ macro_rules! define_unit {
 
                 $( // fundamental types
                     #[doc = $cvdoc]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $cvname($cvtype),
                 )*
                 $(
                     #[doc = $vdoc]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $vname($vtype),
                 )*
 
                 $( // pointer-size dependant
                     #[cfg($cvpsize_psize)]
                     #[doc = $cvdoc_psize]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $cvname_psize($cvtype_psize),
                 )*
 
@@ -1281,6 +1474,7 @@ macro_rules! define_unit {
                     #[cfg(all(feature = $cvdep1_dep, feature = $cvdep2_dep))]
                     #[cfg_attr(feature = "nightly", doc(cfg(all(feature = $cvdep1_dep, feature = $cvdep2_dep))))]
                     #[doc = $cvdoc_dep]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $cvname_dep($cvtype_dep),
                 )*
                 $(
@@ -1288,6 +1482,7 @@ macro_rules! define_unit {
                     #[cfg_attr(feature = "nightly",
                         doc(cfg(all(feature = $vdep1_dep, feature = $vdep2_dep))))]
                     #[doc = $vdoc_dep]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $vname_dep($vtype_dep),
                 )*
 
@@ -1298,6 +1493,7 @@ macro_rules! define_unit {
                     #[cfg_attr(feature = "nightly",
                         doc(cfg(all(feature = $cvdep1_psize_dep, feature = $cvdep2_psize_dep))))]
                     #[doc = $cvdoc_psize_dep]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $cvname_psize_dep($cvtype_psize_dep),
                 )*
                 $(
@@ -1307,6 +1503,7 @@ macro_rules! define_unit {
                     #[cfg_attr(feature = "nightly",
                         doc(cfg(all(feature = $vdep1_psize_dep, feature = $vdep2_psize_dep))))]
                     #[doc = $vdoc_psize_dep]
+                    #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
                     $vname_psize_dep($vtype_psize_dep),
                 )*
             }
@@ -1370,6 +1567,44 @@ macro_rules! define_unit {
 
                 }
             )*
+            $( // Copy feature-bound, from Copy
+                #[cfg(all(feature = $cvdep1_dep, feature = $cvdep2_dep ))]
+                impl<C: DataUnitCopy> TryFrom<[<$cname $b bit Copy With>]<C>> for $cvtype_dep {
+                    type Error = ();
+                    fn try_from(c: [<$cname $b bit Copy With>]<C>) -> Result<Self, Self::Error> {
+                        match c {
+                            [<$cname $b bit Copy With>]::$cvname_dep(c) => Ok(c),
+                            _ => Err(()),
+                        }
+                    }
+
+                }
+            )*
+            $( // Copy pointer-size dependant
+                #[cfg($cvpsize_psize)]
+                impl<C: DataUnitCopy> TryFrom<[<$cname $b bit Copy With>]<C>> for $cvtype_psize {
+                    type Error = ();
+                    fn try_from(c: [<$cname $b bit Copy With>]<C>) -> Result<Self, Self::Error> {
+                        match c {
+                            [<$cname $b bit Copy With>]::$cvname_psize(c) => Ok(c),
+                            _ => Err(()),
+                        }
+                    }
+
+                }
+            )*
+            $( // Copy, from non-Copy
+                impl<C: DataUnit> TryFrom<[<$cname $b bit With>]<C>> for $cvtype {
+                    type Error = ();
+                    fn try_from(c: [<$cname $b bit With>]<C>) -> Result<Self, Self::Error> {
+                        match c {
+                            [<$cname $b bit With>]::$cvname(c) => Ok(c),
+                            _ => Err(()),
+                        }
+                    }
+
+                }
+            )*
             $( // non-Copy
                 impl<C: DataUnit> TryFrom<[<$cname $b bit With>]<C>> for $vtype {
                     type Error = ();
@@ -1415,6 +1650,23 @@ macro_rules! define_unit {
 
                 }
             )*
+            $( // Copy, into non-Copy
+                impl<C: DataUnit> From<$cvtype> for [<$cname $b bit With>]<C> {
+                    fn from(v: $cvtype) -> Self {
+                        [<$cname $b bit With>]::$cvname(v)
+                    }
+
+                }
+            )*
+            $( // Copy feature-bound, into non-Copy
+                #[cfg(all(feature = $cvdep1_dep, feature = $cvdep2_dep ))]
+                impl<C: DataUnit> From<$cvtype_dep> for [<$cname $b bit With>]<C> {
+                    fn from(v: $cvtype_dep) -> Self {
+                        [<$cname $b bit With>]::$cvname_dep(v)
+                    }
+
+                }
+            )*
             $( // non-Copy
                 impl<C: DataUnit> From<$vtype> for [<$cname $b bit With>]<C> {
                     fn from(v: $vtype) -> Self {
@@ -1567,6 +1819,255 @@ macro_rules! define_raw {
 }
 pub(crate) use define_raw;
 
+/// for defining enum DataColumn*
+///
+/// Receives the variants already flattened, each one followed by
+/// the `cfg` predicate it depends on.
+#[cfg(feature = "alloc")]
+macro_rules! define_column {
+    (
+        l: $lname:ident, c: $cname:ident, t: $tname:ident,
+        size: $B:literal, $b:literal,
+        copy_variants:
+            $( $cvdoc:literal, $cvname:ident, $cvtype:ty, ( $($cvcfg:tt)* ); )*
+            ;
+        noncopy_variants:
+            $( $vdoc:literal, $vname:ident, $vtype:ty, ( $($vcfg:tt)* ); )*
+            ;
+    ) => {
+        devela::paste!{
+            // ## copy version (DataColumn)
+            // -----------------------------------------------------------------
+            impl_data_column![
+                #[doc = $b "-bit/" $B "-Byte " "data **Column** (extendable) (Copy)"]
+                ///
+                /// See also:
+                #[doc = "- [" [<$lname $b bit With>] "][" [<$lname $b bit With>] "] -Copy" ]
+                #[doc = "- [" [<$lname $b bit Copy>] "][" [<$lname $b bit Copy>] "] -With" ]
+                #[doc = "- [" [<$lname $b bit>] "][" [<$lname $b bit>] "] -Copy -With" ]
+                l: [<$lname $b bit Copy With>],
+                c: [<$cname $b bit Copy With>], DataUnitCopy,
+                t: [<$tname $b bit Copy With>], DataTypeCopy,
+                variants:
+                    $( $cvdoc, $cvname, $cvtype, ( $($cvcfg)* ); )*
+            ];
+
+            // alias DataColumn Copy
+            #[doc = $b "-bit/" $B "-Byte " "data **Column** (Copy)"]
+            ///
+            /// See also:
+            #[doc = "- [" [<$lname $b bit>] "][" [<$lname $b bit>] "] -Copy" ]
+            #[doc = "- [" [<$lname $b bit Copy With>] "][" [<$lname $b bit Copy With>] "] +With" ]
+            #[doc = "- [" [<$lname $b bit With>] "][" [<$lname $b bit With>] "] -Copy +With" ]
+            pub type [<$lname $b bit Copy>] = [<$lname $b bit Copy With>]<(), ()>;
+
+            // ## non-copy version (DataColumn)
+            // -----------------------------------------------------------------
+            impl_data_column![
+                #[doc = $b "-bit/" $B "-Byte " "data **Column** (extendable)"]
+                ///
+                /// See also:
+                #[doc = "- [" [<$lname $b bit Copy With>] "][" [<$lname $b bit Copy With>] "] +Copy" ]
+                #[doc = "- [" [<$lname $b bit>] "][" [<$lname $b bit>] "] -With" ]
+                #[doc = "- [" [<$lname $b bit Copy>] "][" [<$lname $b bit Copy>] "] +Copy -With" ]
+                l: [<$lname $b bit With>],
+                c: [<$cname $b bit With>], DataUnit,
+                t: [<$tname $b bit With>], DataType,
+                variants:
+                    $( $cvdoc, $cvname, $cvtype, ( $($cvcfg)* ); )*
+                    $( $vdoc, $vname, $vtype, ( $($vcfg)* ); )*
+            ];
+
+            // alias DataColumn
+            #[doc = $b "-bit/" $B "-Byte " "data **Column**"]
+            ///
+            /// See also:
+            #[doc = "- [" [<$lname $b bit Copy>] "][" [<$lname $b bit Copy>] "] +Copy" ]
+            #[doc = "- [" [<$lname $b bit With>] "][" [<$lname $b bit With>] "] +With" ]
+            #[doc = "- [" [<$lname $b bit Copy With>] "][" [<$lname $b bit Copy With>] "] +Copy +With" ]
+            pub type [<$lname $b bit>] = [<$lname $b bit With>]<(), ()>;
+        }
+    };
+}
+#[cfg(feature = "alloc")]
+pub(crate) use define_column;
+
+/// for defining struct Tagged*
+///
+/// Receives the `Copy` variants already flattened, each one followed by
+/// the `cfg` predicate it depends on.
+#[cfg(feature = "unsafe_unit")]
+macro_rules! define_tagged {
+    (
+        g: $gname:ident, c: $cname:ident, t: $tname:ident, b: $bname:ident,
+        size: $B:literal, $b:literal,
+        copy_variants:
+            $( $cvname:ident, $cvtype:ty, ( $($cvcfg:tt)* ); )*
+            ;
+    ) => {
+        devela::paste!{
+            #[doc = $b "-bit/" $B "-Byte " "*raw* data **Tagged** with its *data type* (Copy)"]
+            ///
+            #[doc = "Safely pairs a [`" [<$bname $b bit Copy>] "`] union with the [`"
+                [<$tname $b bit Copy>] "`] of its active field, so that it can be read"]
+            /// without `unsafe` code.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = "use ladata::all::{" [<$gname $b bit>] " as G, " [<$cname $b bit Copy>]
+                " as U, " [<$tname $b bit Copy>] " as T};"]
+            ///
+            /// let tagged = G::from(U::Bool(true));
+            /// assert![matches![tagged.data_type(), T::Bool]];
+            /// assert_eq![Some(true), tagged.get::<bool>()];
+            /// assert_eq![None, tagged.get::<u8>()];
+            /// assert![matches![U::from(tagged), U::Bool(true)]];
+            /// ```
+            #[derive(Clone, Copy)]
+            #[cfg_attr(feature = "nightly", doc(cfg(feature = "unsafe_unit")))]
+            pub struct [<$gname $b bit>] {
+                // INVARIANT: `tag` always describes the active field of `raw`.
+                raw: [<$bname $b bit Copy>],
+                tag: [<$tname $b bit Copy>],
+            }
+
+            impl [<$gname $b bit>] {
+                /// Returns a new tagged value from the given `unit`.
+                #[inline]
+                pub fn new(unit: [<$cname $b bit Copy>]) -> Self {
+                    Self::from(unit)
+                }
+
+                /// Returns a new tagged value from its `raw` data and its `tag`.
+                ///
+                /// # Safety
+                /// The active field of `raw` must be the one described by `tag`.
+                #[inline]
+                pub const unsafe fn from_parts_unchecked(
+                    raw: [<$bname $b bit Copy>],
+                    tag: [<$tname $b bit Copy>],
+                ) -> Self {
+                    Self { raw, tag }
+                }
+
+                /// Returns the *raw* data and its *data type*.
+                #[inline]
+                pub const fn into_parts(self) -> ([<$bname $b bit Copy>], [<$tname $b bit Copy>]) {
+                    (self.raw, self.tag)
+                }
+
+                /// Returns the *raw* data.
+                #[inline]
+                pub const fn raw(&self) -> [<$bname $b bit Copy>] {
+                    self.raw
+                }
+
+                /// Returns the *data type*.
+                #[inline]
+                pub const fn data_type(&self) -> [<$tname $b bit Copy>] {
+                    self.tag
+                }
+
+                /// Returns the *data unit*.
+                #[inline]
+                pub fn to_unit(&self) -> [<$cname $b bit Copy>] {
+                    (*self).into()
+                }
+
+                /// Returns a copy of the data if its type is `T`, or `None` otherwise.
+                #[inline]
+                pub fn get<T: TryFrom<[<$cname $b bit Copy>]>>(&self) -> Option<T> {
+                    T::try_from(self.to_unit()).ok()
+                }
+
+                /// Splits a slice of `tagged` values into parallel slices
+                /// of `raws` and `tags`.
+                ///
+                /// # Errors
+                /// Returns [`DimensionMismatch`][crate::error::LadataError::DimensionMismatch]
+                /// if the three slices don't have the same length.
+                pub fn split_slice(
+                    tagged: &[Self],
+                    raws: &mut [[<$bname $b bit Copy>]],
+                    tags: &mut [[<$tname $b bit Copy>]],
+                ) -> crate::error::LadataResult<()> {
+                    if tagged.len() != raws.len() || tagged.len() != tags.len() {
+                        return Err(crate::error::LadataError::DimensionMismatch);
+                    }
+                    for ((t, raw), tag) in tagged.iter().zip(raws).zip(tags) {
+                        (*raw, *tag) = t.into_parts();
+                    }
+                    Ok(())
+                }
+
+                /// Joins parallel slices of `raws` and `tags` into a slice of `tagged` values.
+                ///
+                /// # Errors
+                /// Returns [`DimensionMismatch`][crate::error::LadataError::DimensionMismatch]
+                /// if the three slices don't have the same length.
+                ///
+                /// # Safety
+                /// The active field of each raw union must be the one described
+                /// by the tag at the same index, like the ones obtained from
+                #[doc = "[`split_slice`][" [<$gname $b bit>] "::split_slice]."]
+                pub unsafe fn join_slices_unchecked(
+                    raws: &[[<$bname $b bit Copy>]],
+                    tags: &[[<$tname $b bit Copy>]],
+                    tagged: &mut [Self],
+                ) -> crate::error::LadataResult<()> {
+                    if tagged.len() != raws.len() || tagged.len() != tags.len() {
+                        return Err(crate::error::LadataError::DimensionMismatch);
+                    }
+                    for ((t, raw), tag) in tagged.iter_mut().zip(raws).zip(tags) {
+                        *t = Self::from_parts_unchecked(*raw, *tag);
+                    }
+                    Ok(())
+                }
+            }
+
+            impl From<[<$cname $b bit Copy>]> for [<$gname $b bit>] {
+                fn from(unit: [<$cname $b bit Copy>]) -> Self {
+                    use {[<$cname $b bit Copy With>] as U, [<$tname $b bit Copy With>] as T};
+                    let (raw, tag) = match unit {
+                        U::None => ([<$bname $b bit Copy>] { None: () }, T::None),
+                        U::With(()) => ([<$bname $b bit Copy>] { None: () }, T::With(())),
+                        $(
+                            #[cfg($($cvcfg)*)]
+                            U::$cvname(v) => ([<$bname $b bit Copy>] { $cvname: v }, T::$cvname),
+                        )*
+                    };
+                    Self { raw, tag }
+                }
+            }
+
+            impl From<[<$gname $b bit>]> for [<$cname $b bit Copy>] {
+                fn from(tagged: [<$gname $b bit>]) -> Self {
+                    use {[<$cname $b bit Copy With>] as U, [<$tname $b bit Copy With>] as T};
+                    // SAFETY: the tag always describes the active field
+                    unsafe {
+                        match tagged.tag {
+                            T::None => U::None,
+                            T::With(()) => U::With(()),
+                            $(
+                                #[cfg($($cvcfg)*)]
+                                T::$cvname => U::$cvname(tagged.raw.$cvname),
+                            )*
+                        }
+                    }
+                }
+            }
+
+            impl core::fmt::Debug for [<$gname $b bit>] {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_tuple(stringify!{[<$gname $b bit>]}).field(&self.to_unit()).finish()
+                }
+            }
+        }
+    };
+}
+#[cfg(feature = "unsafe_unit")]
+pub(crate) use define_tagged;
+
 // -----------------------------------------------------------------------------
 
 /// implement: DataType trait
@@ -1714,8 +2215,16 @@ macro_rules! impl_data_unit {
             $vpsize_psize_dep:meta, $vdep1_psize_dep:literal, $vdep2_psize_dep:literal ),* ;
     ) => {
         devela::paste! {
-            impl<C: $cbound> DataUnit for $cname<C> {
+            impl<C: $cbound> DataUnit for $cname<C> where C::Type: $tbound {
+                type Type = $tname<C::Type>;
+
                 fn is_copy(&self) -> bool { $is_copy }
+                fn unit_type(&self) -> Self::Type { self.data_type() }
+            }
+
+            /// Returns the `None` variant.
+            impl<C: $cbound> Default for $cname<C> {
+                fn default() -> Self { Self::None }
             }
         }
     };
@@ -1739,58 +2248,1350 @@ macro_rules! impl_data_raw {
 #[cfg(feature = "unsafe_unit")]
 pub(crate) use impl_data_raw;
 
-/// re-exports types from public modules.
-macro_rules! reexport {
-    // external branches, multi-type re-export
-    // -------------------------------------------------------------------------
+/// defines a DataColumn enum, and implements: `DataColumn` trait
+#[cfg(feature = "alloc")]
+macro_rules! impl_data_column {
+    (
+        $( #[$attr:meta] )*
+        l: $lname:ident,
+        c: $cname:ident, $cbound:ident,
+        t: $tname:ident, $tbound:ident,
+        variants:
+            $( $vdoc:literal, $vname:ident, $vtype:ty, ( $($vcfg:tt)* ); )*
+    ) => {
+        $( #[$attr] )*
+        #[derive(Clone, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $lname<T: $tbound, C: $cbound> {
+            /// A column of *units* with absence of *data*, only keeping their count.
+            None(usize),
+            /// A column of custom *data units*, all sharing the same custom *data type*.
+            With(T, alloc::vec::Vec<C>),
+
+            $(
+                #[cfg($($vcfg)*)]
+                #[doc = $vdoc]
+                #[cfg_attr(feature = "serde", serde(with = "crate::unit::serde_field"))]
+                $vname(alloc::vec::Vec<$vtype>),
+            )*
+        }
 
-    // reexports all the sizes
-    ($mod:ident, $path:path; all_sizes) => {
-        use $crate::unit::macros::reexport;
-        reexport![$mod $path; 1, 8];
-        reexport![$mod $path; 2, 16];
-        reexport![$mod $path; 4, 32];
-        reexport![$mod $path; 8, 64];
-        reexport![$mod $path; 16, 128];
-        reexport![$mod $path; 32, 256];
-        reexport![$mod $path; 64, 512];
-        reexport![$mod $path; 128, 1024];
-    };
+        impl<T: $tbound, C: $cbound> $lname<T, C> {
+            /// Returns a new empty column of the given `data_type`.
+            #[inline]
+            pub fn new(data_type: $tname<T>) -> Self {
+                match data_type {
+                    $tname::None => Self::None(0),
+                    $tname::With(t) => Self::With(t, alloc::vec::Vec::new()),
+                    $(
+                        #[cfg($($vcfg)*)]
+                        $tname::$vname => Self::$vname(alloc::vec::Vec::new()),
+                    )*
+                }
+            }
 
-    // `::size::` sub-modules reexports, single size.
-    (mod_size $path:path; $B:literal, $b:literal ) => {
-        devela::paste!{
-            #[doc = $b " bit data (== " $B " Byte)" ]
-            pub mod [< b $b >] {
-                $crate::unit::macros::reexport![@UnitType $path; size: $b; bit bitWith bitCopy bitCopyWith ];
-                $crate::unit::macros::reexport![@Raw $path; size: $b; bitCopy ];
+            /// Returns the *data type* shared by all the units in the column.
+            #[inline]
+            pub fn data_type(&self) -> $tname<T> {
+                match self {
+                    Self::None(_) => $tname::None,
+                    Self::With(t, _) => $tname::With(*t),
+                    $(
+                        #[cfg($($vcfg)*)]
+                        Self::$vname(_) => $tname::$vname,
+                    )*
+                }
             }
-        }
-    };
 
-    // `::cells::` reexports, single size
-    (mod_unit $path:path; $B:literal, $b:literal ) => {
-        devela::paste!{
-            $crate::unit::macros::reexport![@Unit $path; size: $b; bit bitWith bitCopy bitCopyWith ];
+            /// Returns the number of units in the column.
+            #[inline]
+            pub fn len(&self) -> usize {
+                match self {
+                    Self::None(n) => *n,
+                    Self::With(_, v) => v.len(),
+                    $(
+                        #[cfg($($vcfg)*)]
+                        Self::$vname(v) => v.len(),
+                    )*
+                }
+            }
+
+            /// Returns `true` if the column has no units.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Returns `true` if the given `unit` has the *data type* of the column.
+            ///
+            /// Any custom unit is accepted by a custom column.
+            pub fn accepts(&self, unit: &$cname<C>) -> bool {
+                match (self, unit) {
+                    (Self::None(_), $cname::None) | (Self::With(..), $cname::With(_)) => true,
+                    $(
+                        #[cfg($($vcfg)*)]
+                        (Self::$vname(_), $cname::$vname(_)) => true,
+                    )*
+                    _ => false,
+                }
+            }
+
+            /// Appends a `unit` at the end of the column.
+            ///
+            /// # Errors
+            /// Returns [`TypeMismatch`][crate::error::LadataError::TypeMismatch]
+            /// if the `unit` doesn't have the *data type* of the column.
+            pub fn push(&mut self, unit: $cname<C>) -> crate::error::LadataResult<()> {
+                match (self, unit) {
+                    (Self::None(n), $cname::None) => *n += 1,
+                    (Self::With(_, v), $cname::With(c)) => v.push(c),
+                    $(
+                        #[cfg($($vcfg)*)]
+                        (Self::$vname(v), $cname::$vname(c)) => v.push(c),
+                    )*
+                    _ => return Err(crate::error::LadataError::TypeMismatch),
+                }
+                Ok(())
+            }
+
+            /// Removes the last unit from the column and returns it,
+            /// or `None` if it's empty.
+            pub fn pop(&mut self) -> Option<$cname<C>> {
+                match self {
+                    Self::None(n) => {
+                        if *n > 0 {
+                            *n -= 1;
+                            Some($cname::None)
+                        } else {
+                            None
+                        }
+                    }
+                    Self::With(_, v) => v.pop().map($cname::With),
+                    $(
+                        #[cfg($($vcfg)*)]
+                        Self::$vname(v) => v.pop().map($cname::$vname),
+                    )*
+                }
+            }
+
+            /// Replaces the unit at `index` with the given `unit`, returning the old one.
+            ///
+            /// # Errors
+            /// Returns [`TypeMismatch`][crate::error::LadataError::TypeMismatch]
+            /// if the `unit` doesn't have the *data type* of the column, or
+            /// [`IndexOutOfBounds`][crate::error::LadataError::IndexOutOfBounds]
+            /// if `index >= len()`.
+            pub fn set(&mut self, index: usize, unit: $cname<C>)
+                -> crate::error::LadataResult<$cname<C>> {
+                use crate::error::LadataError as Error;
+                match (self, unit) {
+                    (Self::None(n), $cname::None) => {
+                        if index < *n {
+                            Ok($cname::None)
+                        } else {
+                            Err(Error::IndexOutOfBounds(index))
+                        }
+                    }
+                    (Self::With(_, v), $cname::With(c)) => v
+                        .get_mut(index)
+                        .map(|old| $cname::With(core::mem::replace(old, c)))
+                        .ok_or(Error::IndexOutOfBounds(index)),
+                    $(
+                        #[cfg($($vcfg)*)]
+                        (Self::$vname(v), $cname::$vname(c)) => v
+                            .get_mut(index)
+                            .map(|old| $cname::$vname(core::mem::replace(old, c)))
+                            .ok_or(Error::IndexOutOfBounds(index)),
+                    )*
+                    _ => Err(Error::TypeMismatch),
+                }
+            }
+
+            /// Returns all the units of the column, in order.
+            pub fn into_units(self) -> alloc::vec::Vec<$cname<C>> {
+                match self {
+                    Self::None(n) => (0..n).map(|_| $cname::None).collect(),
+                    Self::With(_, v) => v.into_iter().map($cname::With).collect(),
+                    $(
+                        #[cfg($($vcfg)*)]
+                        Self::$vname(v) => v.into_iter().map($cname::$vname).collect(),
+                    )*
+                }
+            }
         }
-    };
-    // `::types::` reexports, single size
-    (mod_types $path:path; $B:literal, $b:literal ) => {
-        devela::paste!{
-            $crate::unit::macros::reexport![@Type $path; size: $b; bit bitWith bitCopy bitCopyWith ];
+
+        impl<T: $tbound, C: $cbound + Clone> $lname<T, C> {
+            /// Returns a copy of the unit at `index`, or `None` if out of bounds.
+            #[allow(clippy::clone_on_copy)]
+            pub fn get(&self, index: usize) -> Option<$cname<C>> {
+                match self {
+                    Self::None(n) => (index < *n).then_some($cname::None),
+                    Self::With(_, v) => v.get(index).map(|c| $cname::With(c.clone())),
+                    $(
+                        #[cfg($($vcfg)*)]
+                        Self::$vname(v) => v.get(index).map(|c| $cname::$vname(c.clone())),
+                    )*
+                }
+            }
         }
-    };
-    // `::raw::` reexports, single size
-    (mod_raw $path:path; $B:literal, $b:literal ) => {
-        devela::paste!{
-            // WIP
-            $crate::unit::macros::reexport![@Raw $path; size: $b; bitCopy ];
-            // $crate::unit::macros::reexport![@Raw $path; size: $b; bit bitWith bitCopy bitCopyWith ];
+
+        impl<T: $tbound, C: $cbound + Clone> DataColumn for $lname<T, C>
+            where C::Type: $tbound {
+            type Type = $tname<T>;
+            type Unit = $cname<C>;
+
+            fn column_new(data_type: Self::Type) -> Self {
+                Self::new(data_type)
+            }
+            fn column_type(&self) -> Self::Type {
+                self.data_type()
+            }
+            fn column_len(&self) -> usize {
+                self.len()
+            }
+            fn column_accepts(&self, unit: &Self::Unit) -> bool {
+                self.accepts(unit)
+            }
+            fn column_push(&mut self, unit: Self::Unit) -> crate::error::LadataResult<()> {
+                self.push(unit)
+            }
+            fn column_pop(&mut self) -> Option<Self::Unit> {
+                self.pop()
+            }
+            fn column_get(&self, index: usize) -> Option<Self::Unit> {
+                self.get(index)
+            }
+            fn column_set(&mut self, index: usize, unit: Self::Unit)
+                -> crate::error::LadataResult<Self::Unit> {
+                self.set(index, unit)
+            }
+            fn column_into_units(self) -> alloc::vec::Vec<Self::Unit> {
+                self.into_units()
+            }
         }
     };
+}
+#[cfg(feature = "alloc")]
+pub(crate) use impl_data_column;
 
-    // internal branches, single-type re-export
-    // -------------------------------------------------------------------------
+/// implement: discriminants for `DataType*Copy`, and the binary encoding for `DataUnit*Copy`
+///
+/// The discriminants are assigned from a private enum without conditional
+/// compilation, so that they are stable regardless of the enabled features
+/// and the target pointer width. Every `psize` variant shares one discriminant.
+macro_rules! impl_data_encoding {
+    (
+        t: $tname:ident, c: $cname:ident,
+        size: $B:literal, $b:literal,
+        copy_variants:
+            $( $cvname:ident, $cvtype:ty ),* ;
+        copy_variants_psize:
+            $( $cvname_psize:ident, $cvtype_psize:ty, ( $($cvcfg_psize:tt)* ); )*
+            ;
+        copy_variants_dep:
+            $( $cvname_dep:ident, $cvtype_dep:ty, ( $($cvcfg_dep:tt)* ); )*
+            ;
+    ) => {
+        devela::paste!{
+            #[allow(dead_code)]
+            #[repr(u8)]
+            enum [<$tname $b bit Discriminant>] {
+                None,
+                With,
+                $( $cvname, )*
+                Usize,
+                Isize,
+                $( $cvname_dep, )*
+            }
+
+            impl<T: DataTypeCopy> [<$tname $b bit Copy With>]<T> {
+                /// Returns the discriminant of the current variant, as used in the
+                /// binary encoding of its matching *data unit*.
+                ///
+                /// Discriminants are the same regardless of the enabled features.
+                pub const fn discriminant(&self) -> u8 {
+                    use [<$tname $b bit Discriminant>] as D;
+                    match self {
+                        Self::None => D::None as u8,
+                        Self::With(_) => D::With as u8,
+                        $( Self::$cvname => D::$cvname as u8, )*
+                        $(
+                            #[cfg($($cvcfg_psize)*)]
+                            Self::$cvname_psize => D::$cvname_psize as u8,
+                        )*
+                        $(
+                            #[cfg($($cvcfg_dep)*)]
+                            Self::$cvname_dep => D::$cvname_dep as u8,
+                        )*
+                    }
+                }
+
+                /// Returns the variant with the given `discriminant`.
+                ///
+                /// Returns `None` if the discriminant is unknown, if it belongs
+                /// to a variant that is not enabled, or to the `With` variant.
+                pub fn from_discriminant(discriminant: u8) -> Option<Self> {
+                    use [<$tname $b bit Discriminant>] as D;
+                    match discriminant {
+                        d if d == D::None as u8 => Some(Self::None),
+                        $( d if d == D::$cvname as u8 => Some(Self::$cvname), )*
+                        $(
+                            #[cfg($($cvcfg_psize)*)]
+                            d if d == D::$cvname_psize as u8 => Some(Self::$cvname_psize),
+                        )*
+                        $(
+                            #[cfg($($cvcfg_dep)*)]
+                            d if d == D::$cvname_dep as u8 => Some(Self::$cvname_dep),
+                        )*
+                        _ => None,
+                    }
+                }
+            }
+
+            impl<C: DataUnitCopy> [<$cname $b bit Copy With>]<C> {
+                /// Encodes the unit into `bytes`, returning the number of bytes written.
+                ///
+                /// The encoding consists of the one-byte
+                #[doc = "[`discriminant`][" [<$tname $b bit Copy With>] "::discriminant]"]
+                /// of its matching *data type*, followed by a little-endian payload
+                /// of exactly [`data_size`][DataType::data_size] bytes, except for
+                /// `Usize` and `Isize`, which are always encoded in 8 bytes.
+                ///
+                /// # Errors
+                /// Returns [`NotEnoughSpace`][crate::error::LadataError::NotEnoughSpace]
+                /// if `bytes` is too short, or
+                /// [`InvalidEncoding`][crate::error::LadataError::InvalidEncoding]
+                /// for the `With` variant and for the data without a portable
+                /// representation, like `Instant`s.
+                /// The contents of `bytes` are unspecified on error.
+                pub fn encode_into(&self, bytes: &mut [u8]) -> crate::error::LadataResult<usize> {
+                    use {crate::unit::encoding::*, [<$tname $b bit Discriminant>] as D};
+                    match self {
+                        Self::None => encode_tag(D::None as u8, bytes),
+                        Self::With(_) => Err(crate::error::LadataError::InvalidEncoding),
+                        $( Self::$cvname(v) => encode(D::$cvname as u8, v, bytes), )*
+                        $(
+                            #[cfg($($cvcfg_psize)*)]
+                            Self::$cvname_psize(v) => encode(D::$cvname_psize as u8, v, bytes),
+                        )*
+                        $(
+                            #[cfg($($cvcfg_dep)*)]
+                            Self::$cvname_dep(v) => encode(D::$cvname_dep as u8, v, bytes),
+                        )*
+                    }
+                }
+
+                /// Decodes a unit from the start of `bytes`,
+                /// returning it with the number of bytes read.
+                ///
+                /// # Errors
+                /// Returns [`NotEnoughSpace`][crate::error::LadataError::NotEnoughSpace]
+                /// if `bytes` is too short, or
+                /// [`InvalidEncoding`][crate::error::LadataError::InvalidEncoding]
+                /// if the discriminant is unknown or not enabled, or the payload is invalid.
+                ///
+                /// # Examples
+                /// ```
+                #[doc = "use ladata::all::" [<$cname $b bit Copy>] " as U;"]
+                /// # fn main() -> ladata::error::LadataResult<()> {
+                ///
+                /// let mut buf = [0; 8];
+                /// let written = U::Bool(true).encode_into(&mut buf)?;
+                /// assert_eq![2, written];
+                /// assert![matches![U::decode(&buf)?, (U::Bool(true), 2)]];
+                /// # Ok(()) }
+                /// ```
+                pub fn decode(bytes: &[u8]) -> crate::error::LadataResult<(Self, usize)> {
+                    use {
+                        crate::{error::LadataError as E, unit::encoding::decode},
+                        [<$tname $b bit Discriminant>] as D,
+                    };
+                    match *bytes.first().ok_or(E::NotEnoughSpace(Some(1)))? {
+                        d if d == D::None as u8 => Ok((Self::None, 1)),
+                        $(
+                            d if d == D::$cvname as u8 =>
+                                decode(bytes).map(|(v, n)| (Self::$cvname(v), n)),
+                        )*
+                        $(
+                            #[cfg($($cvcfg_psize)*)]
+                            d if d == D::$cvname_psize as u8 =>
+                                decode(bytes).map(|(v, n)| (Self::$cvname_psize(v), n)),
+                        )*
+                        $(
+                            #[cfg($($cvcfg_dep)*)]
+                            d if d == D::$cvname_dep as u8 =>
+                                decode(bytes).map(|(v, n)| (Self::$cvname_dep(v), n)),
+                        )*
+                        _ => Err(E::InvalidEncoding),
+                    }
+                }
+            }
+
+            impl<C: DataUnitCopy + DataUnitPayload> [<$cname $b bit Copy With>]<C> {
+                /// Encodes the unit into `bytes` like
+                #[doc = "[`encode_into`][" [<$cname $b bit Copy With>] "::encode_into],"]
+                /// returning the number of bytes written.
+                ///
+                /// The `With` variant is encoded as its discriminant, followed by
+                /// the little-endian `u32` id of its *data type* in the `registry`,
+                /// and the payload written by
+                /// [`encode_payload`][DataUnitPayload::encode_payload].
+                ///
+                /// # Errors
+                /// Returns the same errors as `encode_into`, except for the `With`
+                /// variant, which returns [`KeyNotFound`][crate::error::LadataError::KeyNotFound]
+                /// if its *data type* is not registered.
+                pub fn encode_into_with<R: DataTypeRegistry<Type = C::Type>>(
+                    &self,
+                    registry: &R,
+                    bytes: &mut [u8],
+                ) -> crate::error::LadataResult<usize> {
+                    use {crate::unit::encoding::encode_with, [<$tname $b bit Discriminant>] as D};
+                    match self {
+                        Self::With(c) => encode_with(D::With as u8, c, registry, bytes),
+                        _ => self.encode_into(bytes),
+                    }
+                }
+
+                /// Decodes a unit from the start of `bytes` like
+                #[doc = "[`decode`][" [<$cname $b bit Copy With>] "::decode],"]
+                /// returning it with the number of bytes read.
+                ///
+                /// The `With` variant is decoded with the *data type* registered
+                /// in the `registry` with the encoded id.
+                ///
+                /// # Errors
+                /// Returns the same errors as `decode`, except for the `With`
+                /// variant, which returns [`KeyNotFound`][crate::error::LadataError::KeyNotFound]
+                /// if the id is not registered.
+                pub fn decode_with<R: DataTypeRegistry<Type = C::Type>>(
+                    registry: &R,
+                    bytes: &[u8],
+                ) -> crate::error::LadataResult<(Self, usize)> {
+                    use {crate::unit::encoding::decode_with, [<$tname $b bit Discriminant>] as D};
+                    if bytes.first() == Some(&(D::With as u8)) {
+                        decode_with(registry, bytes).map(|(c, n)| (Self::With(c), n))
+                    } else {
+                        Self::decode(bytes)
+                    }
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_data_encoding;
+
+/// for casting DataUnit* into any DataType*
+macro_rules! impl_data_cast {
+    (
+        t: $tname:ident, c: $cname:ident,
+        size: $B:literal, $b:literal,
+        copy_variants:
+            $( $cvname:ident, ( $($cvcfg:tt)* ); )*
+            ;
+    ) => {
+        devela::paste!{
+            impl_data_cast![@unit [<$cname $b bit With>], [<$cname $b bit>], DataUnit;
+                $( $cvname, ( $($cvcfg)* ); )* ];
+            impl_data_cast![@unit [<$cname $b bit Copy With>], [<$cname $b bit Copy>], DataUnitCopy;
+                $( $cvname, ( $($cvcfg)* ); )* ];
+            impl_data_cast![@type [<$tname $b bit>], [<$cname $b bit>];
+                $( $cvname, ( $($cvcfg)* ); )* ];
+            impl_data_cast![@type [<$tname $b bit Copy>], [<$cname $b bit Copy>];
+                $( $cvname, ( $($cvcfg)* ); )* ];
+        }
+    };
+    (@unit $unit:ident, $alias:ident, $bound:ident;
+        $( $cvname:ident, ( $($cvcfg:tt)* ); )*
+    ) => {
+        devela::paste!{
+            impl<C: $bound> $unit<C> {
+                /// Casts the unit into a unit of the given *data type* `to`,
+                /// of any size, following the casting `mode`.
+                ///
+                /// Only the numeric, `bool`, `char`, byte array and bit array units
+                /// can be cast, into any of those types.
+                ///
+                /// # Errors
+                /// Returns [`TypeMismatch`][crate::error::LadataError::TypeMismatch]
+                /// if either type can't be cast, or if the cast would not be
+                /// lossless in [`Lossless`][crate::all::CastMode::Lossless] mode.
+                ///
+                /// Returns [`Overflow`][crate::error::LadataError::Overflow]
+                /// or [`Underflow`][crate::error::LadataError::Underflow]
+                /// if the value doesn't fit in [`Checked`][crate::all::CastMode::Checked] mode.
+                ///
+                /// # Examples
+                /// ```
+                #[doc = "use ladata::all::{CastMode, " $alias " as U, DataType64bit as T, DataUnit64bit};"]
+                /// # fn main() -> ladata::error::LadataResult<()> {
+                ///
+                /// assert![matches![U::I8(-3).cast(T::I64, CastMode::Lossless)?, DataUnit64bit::I64(-3)]];
+                /// assert![matches![U::I8(-3).cast(T::U32, CastMode::Saturating)?, DataUnit64bit::U32(0)]];
+                /// assert![U::I8(-3).cast(T::U32, CastMode::Checked).is_err()];
+                /// # Ok(()) }
+                /// ```
+                pub fn cast<T: crate::unit::DataTypeCast>(
+                    &self,
+                    to: T,
+                    mode: crate::unit::CastMode,
+                ) -> crate::error::LadataResult<T::Unit> {
+                    use crate::unit::cast::Castable;
+                    let value = match self {
+                        $(
+                            #[cfg($($cvcfg)*)]
+                            Self::$cvname(v) => v.to_cast_value()?,
+                        )*
+                        #[allow(unreachable_patterns)]
+                        _ => return Err(crate::error::LadataError::TypeMismatch),
+                    };
+                    to.cast_value(&value, mode)
+                }
+            }
+        }
+    };
+    (@type $type:ident, $unit:ident;
+        $( $cvname:ident, ( $($cvcfg:tt)* ); )*
+    ) => {
+        impl crate::unit::DataTypeCast for $type {
+            type Unit = $unit;
+
+            fn cast_value(
+                &self,
+                value: &crate::unit::cast::CastValue,
+                mode: crate::unit::CastMode,
+            ) -> crate::error::LadataResult<Self::Unit> {
+                use crate::unit::cast::Castable;
+                match self {
+                    $(
+                        #[cfg($($cvcfg)*)]
+                        Self::$cvname => Castable::from_cast_value(value, mode).map($unit::$cvname),
+                    )*
+                    _ => Err(crate::error::LadataError::TypeMismatch),
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_data_cast;
+
+/// for parsing and displaying DataUnit*
+macro_rules! impl_data_text {
+    (
+        t: $tname:ident, c: $cname:ident,
+        size: $B:literal, $b:literal,
+        copy_variants:
+            $( $cvname:ident, ( $($cvcfg:tt)* ); )*
+            ;
+        noncopy_variants:
+            $( $vname:ident, ( $($vcfg:tt)* ); )*
+            ;
+    ) => {
+        devela::paste!{
+            impl_data_text![@type [<$tname $b bit>], [<$cname $b bit>];
+                $( $cvname, ( $($cvcfg)* ); )*
+                $( $vname, ( $($vcfg)* ); )* ];
+            impl_data_text![@type [<$tname $b bit Copy>], [<$cname $b bit Copy>];
+                $( $cvname, ( $($cvcfg)* ); )* ];
+            impl_data_text![@unit [<$cname $b bit With>], DataUnit;
+                $( $cvname, ( $($cvcfg)* ); )*
+                $( $vname, ( $($vcfg)* ); )* ];
+            impl_data_text![@unit [<$cname $b bit Copy With>], DataUnitCopy;
+                $( $cvname, ( $($cvcfg)* ); )* ];
+            impl_data_text![@with [<$tname $b bit With>], [<$cname $b bit With>],
+                DataType, DataUnit;
+                $( $cvname, ( $($cvcfg)* ); )*
+                $( $vname, ( $($vcfg)* ); )* ];
+            impl_data_text![@with [<$tname $b bit Copy With>], [<$cname $b bit Copy With>],
+                DataTypeCopy, DataUnitCopy; $( $cvname, ( $($cvcfg)* ); )* ];
+        }
+    };
+    (@with $type:ident, $unit:ident, $tbound:ident, $bound:ident;
+        $( $name:ident, ( $($cfg:tt)* ); )*
+    ) => {
+        impl<T: $tbound> $type<T> {
+            /// Parses the textual representation of a unit of the current type,
+            /// as written by the `display_with` method of the unit.
+            ///
+            /// The `With` type expects the name of a *data type* in the `registry`,
+            /// followed by `:` and the text parsed by
+            /// [`parse_payload`][DataUnitPayload::parse_payload].
+            ///
+            /// # Errors
+            /// Returns [`InvalidText`][crate::error::LadataError::InvalidText]
+            /// if the text can't be parsed, or
+            /// [`KeyNotFound`][crate::error::LadataError::KeyNotFound]
+            /// if the name is not registered.
+            pub fn parse_with<C, R>(&self, registry: &R, text: &str)
+                -> crate::error::LadataResult<$unit<C>>
+            where
+                C: $bound + DataUnitPayload<Type = T>,
+                R: DataTypeRegistry<Type = T>,
+            {
+                use crate::{error::LadataError as E, unit::text::TextField};
+                match self {
+                    Self::None if text.is_empty() => Ok($unit::None),
+                    Self::None => Err(E::InvalidText),
+                    Self::With(_) => {
+                        let (name, payload) = text.split_once(':').ok_or(E::InvalidText)?;
+                        let (_, data_type) = registry.registry_lookup_by_name(name)
+                            .ok_or(E::KeyNotFound)?;
+                        C::parse_payload(data_type, payload).map($unit::With)
+                    }
+                    $(
+                        #[cfg($($cfg)*)]
+                        Self::$name => TextField::parse_field(text).map($unit::$name),
+                    )*
+                }
+            }
+        }
+
+        impl<C: $bound + DataUnitPayload> $unit<C> {
+            /// Returns a value that displays the unit like its `Display`
+            /// implementation, except for the `With` variant, which is written
+            /// as the name of its *data type* in the `registry`, followed by `:`
+            /// and the text written by [`fmt_payload`][DataUnitPayload::fmt_payload].
+            ///
+            /// A `With` unit of a *data type* that is not registered is written
+            /// as a `?` placeholder, which `parse_with` rejects as
+            /// [`InvalidText`][crate::error::LadataError::InvalidText].
+            pub fn display_with<'a, R: DataTypeRegistry<Type = C::Type>>(&'a self, registry: &'a R)
+                -> impl core::fmt::Display + 'a {
+                crate::unit::text::DisplayFn(move |f: &mut core::fmt::Formatter| match self {
+                    Self::With(c) => match registry.registry_lookup_by_type(&c.unit_type()) {
+                        Some((_, name)) => {
+                            write!(f, "{name}:")?;
+                            c.fmt_payload(f)
+                        }
+                        None => f.write_str("?"),
+                    },
+                    _ => core::fmt::Display::fmt(self, f),
+                })
+            }
+        }
+    };
+    (@type $type:ident, $unit:ident;
+        $( $name:ident, ( $($cfg:tt)* ); )*
+    ) => {
+        devela::paste!{
+            impl $type {
+                /// Parses the canonical textual representation of a unit of the current type,
+                /// as written by its `Display` implementation.
+                ///
+                /// The `None` type only accepts an empty text.
+                ///
+                /// # Errors
+                /// Returns [`InvalidText`][crate::error::LadataError::InvalidText]
+                /// if the text can't be parsed, or
+                /// [`TypeMismatch`][crate::error::LadataError::TypeMismatch]
+                /// for the `With` variant.
+                ///
+                /// # Examples
+                /// ```
+                #[doc = "use ladata::all::{" $type " as T, " $unit " as U};"]
+                /// # fn main() -> ladata::error::LadataResult<()> {
+                ///
+                /// assert![matches![T::I8.parse("-3")?, U::I8(-3)]];
+                /// assert![matches![T::Bool.parse("true")?, U::Bool(true)]];
+                /// assert![matches![T::ByteArray1.parse("0f")?, U::ByteArray1([15])]];
+                /// assert![T::Bool.parse("1").is_err()];
+                ///
+                /// assert_eq!["00000101", T::BitArray8.parse("101")?.to_string()];
+                /// # Ok(()) }
+                /// ```
+                pub fn parse(&self, text: &str) -> crate::error::LadataResult<$unit> {
+                    use crate::{error::LadataError as E, unit::text::TextField};
+                    match self {
+                        Self::None if text.is_empty() => Ok($unit::None),
+                        Self::None => Err(E::InvalidText),
+                        Self::With(_) => Err(E::TypeMismatch),
+                        $(
+                            #[cfg($($cfg)*)]
+                            Self::$name => TextField::parse_field(text).map($unit::$name),
+                        )*
+                    }
+                }
+            }
+        }
+    };
+    (@unit $unit:ident, $bound:ident;
+        $( $name:ident, ( $($cfg:tt)* ); )*
+    ) => {
+        /// Writes the data in its canonical textual representation,
+        /// which can be read back by the `parse` method of its *data type*.
+        ///
+        /// The `None` variant is written as an empty text,
+        /// and the `With` variant with its `Debug` representation.
+        impl<C: $bound> core::fmt::Display for $unit<C> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                use crate::unit::text::TextField;
+                match self {
+                    Self::None => Ok(()),
+                    Self::With(c) => core::fmt::Debug::fmt(c, f),
+                    $(
+                        #[cfg($($cfg)*)]
+                        Self::$name(v) => v.fmt_field(f),
+                    )*
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_data_text;
+
+/// for comparing and hashing DataUnit*
+macro_rules! impl_data_ord {
+    (
+        t: $tname:ident, c: $cname:ident,
+        size: $B:literal, $b:literal,
+        copy_variants:
+            $( $cvname:ident, ( $($cvcfg:tt)* ); )*
+            ;
+        noncopy_variants:
+            $( $vname:ident, ( $($vcfg:tt)* ); )*
+            ;
+    ) => {
+        devela::paste!{
+            /// The order of the non-`Copy` variants, after all the `Copy` ones.
+            #[allow(dead_code)]
+            enum [<$cname $b bit NonCopyOrder>] {
+                $( #[cfg($($vcfg)*)] $vname, )*
+            }
+
+            impl<C: DataUnit> [<$cname $b bit With>]<C> {
+                /// Returns the ordering key of the current variant.
+                fn order_key(&self) -> u16 {
+                    use [<$tname $b bit Discriminant>] as D;
+                    match self {
+                        Self::None => D::None as u16,
+                        Self::With(_) => D::With as u16,
+                        $(
+                            #[cfg($($cvcfg)*)]
+                            Self::$cvname(_) => D::$cvname as u16,
+                        )*
+                        $(
+                            #[cfg($($vcfg)*)]
+                            Self::$vname(_) => 256 + [<$cname $b bit NonCopyOrder>]::$vname as u16,
+                        )*
+                    }
+                }
+            }
+            impl<C: DataUnitCopy> [<$cname $b bit Copy With>]<C> {
+                /// Returns the ordering key of the current variant.
+                fn order_key(&self) -> u16 {
+                    use [<$tname $b bit Discriminant>] as D;
+                    match self {
+                        Self::None => D::None as u16,
+                        Self::With(_) => D::With as u16,
+                        $(
+                            #[cfg($($cvcfg)*)]
+                            Self::$cvname(_) => D::$cvname as u16,
+                        )*
+                    }
+                }
+            }
+
+            impl_data_ord![@unit [<$cname $b bit With>], [<$cname $b bit>], DataUnit;
+                copy: $( $cvname, ( $($cvcfg)* ); )* ;
+                noncopy: $( $vname, ( $($vcfg)* ); )* ];
+            impl_data_ord![@unit [<$cname $b bit Copy With>], [<$cname $b bit Copy>], DataUnitCopy;
+                copy: $( $cvname, ( $($cvcfg)* ); )* ;
+                noncopy: ];
+        }
+    };
+    (@unit $unit:ident, $alias:ident, $bound:ident;
+        copy: $( $cvname:ident, ( $($cvcfg:tt)* ); )* ;
+        noncopy: $( $vname:ident, ( $($vcfg:tt)* ); )*
+    ) => {
+        impl_data_ord![@impls $unit, $alias, $bound;
+            copy: $( $cvname, ( $($cvcfg)* ); )* ;
+            all: $( $cvname, ( $($cvcfg)* ); )* $( $vname, ( $($vcfg)* ); )* ];
+    };
+    (@impls $unit:ident, $alias:ident, $bound:ident;
+        copy: $( $cvname:ident, ( $($cvcfg:tt)* ); )* ;
+        all: $( $name:ident, ( $($cfg:tt)* ); )*
+    ) => {
+        devela::paste!{
+            impl<C: $bound> $unit<C> {
+                /// Compares the value of two integer or float units numerically,
+                /// regardless of their types.
+                ///
+                /// Returns `None` if any of them is not an integer or a float, or is `NaN`.
+                ///
+                /// # Examples
+                /// ```
+                #[doc = "use ladata::all::" $alias " as U;"]
+                /// use core::cmp::Ordering;
+                ///
+                /// assert_eq![Some(Ordering::Equal), U::I8(3).numeric_cmp(&U::U8(3))];
+                /// assert_eq![Some(Ordering::Less), U::I8(-3).numeric_cmp(&U::U8(3))];
+                /// assert_eq![None, U::I8(3).numeric_cmp(&U::Bool(true))];
+                /// ```
+                pub fn numeric_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    use crate::unit::cast::{Castable, CastValue};
+                    fn value<C: $bound>(unit: &$unit<C>) -> Option<CastValue> {
+                        match unit {
+                            $(
+                                #[cfg($($cvcfg)*)]
+                                $unit::$cvname(v) => v.to_cast_value().ok(),
+                            )*
+                            _ => None,
+                        }
+                    }
+                    value(self)?.numeric_cmp(&value(other)?)
+                }
+
+                /// Returns `true` if both units have the same numeric value,
+                /// regardless of their types.
+                ///
+                /// See [`numeric_cmp`][Self::numeric_cmp].
+                #[inline]
+                pub fn numeric_eq(&self, other: &Self) -> bool {
+                    self.numeric_cmp(other) == Some(core::cmp::Ordering::Equal)
+                }
+            }
+
+            impl<C: $bound + PartialEq> PartialEq for $unit<C> {
+                fn eq(&self, other: &Self) -> bool {
+                    use crate::unit::order::OrdField;
+                    match (self, other) {
+                        (Self::None, Self::None) => true,
+                        (Self::With(a), Self::With(b)) => a == b,
+                        $(
+                            #[cfg($($cfg)*)]
+                            (Self::$name(a), Self::$name(b)) => a.cmp_field(b).is_eq(),
+                        )*
+                        _ => false,
+                    }
+                }
+            }
+            impl<C: $bound + Eq> Eq for $unit<C> {}
+
+            /// Orders by the discriminant of the *data type* first, and then by value.
+            impl<C: $bound + PartialOrd> PartialOrd for $unit<C> {
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    use crate::unit::order::OrdField;
+                    match (self, other) {
+                        (Self::With(a), Self::With(b)) => a.partial_cmp(b),
+                        $(
+                            #[cfg($($cfg)*)]
+                            (Self::$name(a), Self::$name(b)) => Some(a.cmp_field(b)),
+                        )*
+                        _ => Some(self.order_key().cmp(&other.order_key())),
+                    }
+                }
+            }
+
+            /// Orders by the discriminant of the *data type* first, and then by value.
+            ///
+            /// Floats are ordered by their `total_cmp` method.
+            impl<C: $bound + Ord> Ord for $unit<C> {
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    use crate::unit::order::OrdField;
+                    match (self, other) {
+                        (Self::With(a), Self::With(b)) => a.cmp(b),
+                        $(
+                            #[cfg($($cfg)*)]
+                            (Self::$name(a), Self::$name(b)) => a.cmp_field(b),
+                        )*
+                        _ => self.order_key().cmp(&other.order_key()),
+                    }
+                }
+            }
+
+            impl<C: $bound + core::hash::Hash> core::hash::Hash for $unit<C> {
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    use crate::unit::order::OrdField;
+                    self.order_key().hash(state);
+                    match self {
+                        Self::None => (),
+                        Self::With(c) => c.hash(state),
+                        $(
+                            #[cfg($($cfg)*)]
+                            Self::$name(v) => v.hash_field(state),
+                        )*
+                    }
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_data_ord;
+
+/// for validating DataUnit* against DataType*
+macro_rules! impl_data_accepts {
+    (
+        t: $tname:ident, c: $cname:ident,
+        size: $B:literal, $b:literal,
+        variants:
+            $( $cvname:ident, ( $($cvcfg:tt)* ); )*
+            ;
+        noncopy_variants:
+            $( $vname:ident, ( $($vcfg:tt)* ); )*
+            ;
+    ) => {
+        devela::paste!{
+            impl_data_accepts![@type [<$tname $b bit>], [<$cname $b bit>];
+                $( $cvname, ( $($cvcfg)* ); )*
+                $( $vname, ( $($vcfg)* ); )* ];
+            impl_data_accepts![@type [<$tname $b bit Copy>], [<$cname $b bit Copy>];
+                $( $cvname, ( $($cvcfg)* ); )* ];
+        }
+    };
+    (@type $type:ident, $unit:ident;
+        $( $vname:ident, ( $($vcfg:tt)* ); )*
+    ) => {
+        impl crate::unit::DataTypeAccepts for $type {
+            type Unit = $unit;
+
+            fn accepts(&self, unit: &Self::Unit) -> bool {
+                match (self, unit) {
+                    (Self::None, $unit::None) | (Self::With(_), $unit::With(_)) => true,
+                    $(
+                        #[cfg($($vcfg)*)]
+                        (Self::$vname, $unit::$vname(_)) => true,
+                    )*
+                    _ => false,
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_data_accepts;
+
+/// for checked arithmetic between DataUnit*
+macro_rules! impl_data_arith {
+    (
+        t: $tname:ident, c: $cname:ident,
+        size: $B:literal, $b:literal,
+        copy_variants:
+            $( $cvname:ident, ( $($cvcfg:tt)* ); )*
+            ;
+    ) => {
+        devela::paste!{
+            impl_data_arith![@unit [<$cname $b bit With>], [<$cname $b bit>], DataUnit;
+                $( $cvname, ( $($cvcfg)* ); )* ];
+            impl_data_arith![@unit [<$cname $b bit Copy With>], [<$cname $b bit Copy>], DataUnitCopy;
+                $( $cvname, ( $($cvcfg)* ); )* ];
+        }
+    };
+    (@unit $unit:ident, $alias:ident, $bound:ident;
+        $( $cvname:ident, ( $($cvcfg:tt)* ); )*
+    ) => {
+        devela::paste!{
+            /// # Arithmetic
+            ///
+            /// The operands are promoted to a common type following the
+            /// [promotion table][crate::unit#arithmetic], and the result is
+            /// of that type.
+            ///
+            /// # Errors
+            /// All the methods return:
+            /// - [`TypeMismatch`][crate::error::LadataError::TypeMismatch]
+            ///   if any operand is not numeric, if they can't be promoted,
+            ///   or if the promoted type is not available at the current size.
+            /// - [`Overflow`][crate::error::LadataError::Overflow] or
+            ///   [`Underflow`][crate::error::LadataError::Underflow]
+            ///   if the result doesn't fit in the promoted type.
+            /// - [`DivisionByZero`][crate::error::LadataError::DivisionByZero]
+            ///   if the divisor of a division or a remainder is zero.
+            impl<C: $bound> $unit<C> {
+                /// Returns the checked sum of both units.
+                ///
+                /// # Examples
+                /// ```
+                #[doc = "use ladata::{all::" $alias " as U, error::LadataError};"]
+                /// # fn main() -> ladata::error::LadataResult<()> {
+                ///
+                /// assert_eq![U::U8(255), U::U8(200).checked_add(&U::U8(55))?];
+                /// assert_eq![Err(LadataError::Overflow), U::U8(200).checked_add(&U::U8(56))];
+                /// assert_eq![Err(LadataError::TypeMismatch), U::U8(2).checked_add(&U::Bool(true))];
+                /// # Ok(()) }
+                /// ```
+                #[inline]
+                pub fn checked_add(&self, rhs: &Self) -> crate::error::LadataResult<Self> {
+                    self.arith(rhs, crate::unit::arith::Op::Add)
+                }
+
+                /// Returns the checked difference of both units.
+                #[inline]
+                pub fn checked_sub(&self, rhs: &Self) -> crate::error::LadataResult<Self> {
+                    self.arith(rhs, crate::unit::arith::Op::Sub)
+                }
+
+                /// Returns the checked product of both units.
+                #[inline]
+                pub fn checked_mul(&self, rhs: &Self) -> crate::error::LadataResult<Self> {
+                    self.arith(rhs, crate::unit::arith::Op::Mul)
+                }
+
+                /// Returns the checked quotient of both units.
+                ///
+                /// Integer divisions are truncated towards zero.
+                #[inline]
+                pub fn checked_div(&self, rhs: &Self) -> crate::error::LadataResult<Self> {
+                    self.arith(rhs, crate::unit::arith::Op::Div)
+                }
+
+                /// Returns the checked remainder of both units.
+                ///
+                /// The result has the same sign as `self`.
+                #[inline]
+                pub fn checked_rem(&self, rhs: &Self) -> crate::error::LadataResult<Self> {
+                    self.arith(rhs, crate::unit::arith::Op::Rem)
+                }
+
+                /// Returns the checked negation of the unit.
+                ///
+                /// Only zero can be negated for unsigned integers.
+                pub fn checked_neg(&self) -> crate::error::LadataResult<Self> {
+                    crate::unit::arith::neg(self.number()?).and_then(Self::from_number)
+                }
+
+                fn arith(
+                    &self,
+                    rhs: &Self,
+                    op: crate::unit::arith::Op,
+                ) -> crate::error::LadataResult<Self> {
+                    crate::unit::arith::binary(self.number()?, rhs.number()?, op)
+                        .and_then(Self::from_number)
+                }
+
+                /// Returns the size-agnostic value of a numeric unit.
+                fn number(&self) -> crate::error::LadataResult<crate::unit::arith::Number> {
+                    use crate::unit::arith::Numeric;
+                    match self {
+                        $(
+                            #[cfg($($cvcfg)*)]
+                            Self::$cvname(v) => v.to_number(),
+                        )*
+                        _ => None,
+                    }
+                    .ok_or(crate::error::LadataError::TypeMismatch)
+                }
+
+                /// Returns the unit of the exact type of the `number`.
+                fn from_number(
+                    number: crate::unit::arith::Number,
+                ) -> crate::error::LadataResult<Self> {
+                    use crate::unit::arith::Numeric;
+                    $(
+                        #[cfg($($cvcfg)*)]
+                        if let Some(v) = Numeric::from_number(number) {
+                            return Ok(Self::$cvname(v));
+                        }
+                    )*
+                    Err(crate::error::LadataError::TypeMismatch)
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_data_arith;
+
+/// for linking each DataUnit* with its DataType*
+macro_rules! impl_data_link {
+    (
+        t: $tname:ident, c: $cname:ident,
+        size: $B:literal, $b:literal,
+        copy_variants:
+            $( $cvname:ident, ( $($cvcfg:tt)* ); )*
+            ;
+        noncopy_variants:
+            $( $vname:ident, ( $($vcfg:tt)* ); )*
+            ;
+    ) => {
+        devela::paste!{
+            impl_data_link![@link [<$tname $b bit With>], [<$cname $b bit With>],
+                [<$tname $b bit>], [<$cname $b bit>], DataType, DataUnit;
+                $( $cvname, ( $($cvcfg)* ); )*
+                $( $vname, ( $($vcfg)* ); )* ];
+            impl_data_link![@link [<$tname $b bit Copy With>], [<$cname $b bit Copy With>],
+                [<$tname $b bit Copy>], [<$cname $b bit Copy>], DataTypeCopy, DataUnitCopy;
+                $( $cvname, ( $($cvcfg)* ); )* ];
+        }
+    };
+    (@link $type:ident, $unit:ident, $talias:ident, $ualias:ident,
+        $tbound:ident, $cbound:ident;
+        $( $name:ident, ( $($cfg:tt)* ); )*
+    ) => {
+        devela::paste!{
+            impl<C: $cbound> $unit<C> where C::Type: $tbound {
+                /// Returns the *data type* of the current variant.
+                ///
+                /// The `With` variant returns the *data type* given by the
+                /// [`unit_type`][DataUnit::unit_type] method of its custom unit.
+                ///
+                /// # Examples
+                /// ```
+                #[doc = "use ladata::all::{" $talias " as T, " $ualias " as U};"]
+                ///
+                /// assert_eq![T::I8, U::I8(-3).data_type()];
+                /// assert_eq![T::Bool, U::Bool(true).data_type()];
+                /// assert_eq![T::None, U::None.data_type()];
+                /// ```
+                pub fn data_type(&self) -> $type<C::Type> {
+                    match self {
+                        Self::None => $type::None,
+                        Self::With(c) => $type::With(c.unit_type()),
+                        $(
+                            #[cfg($($cfg)*)]
+                            Self::$name(_) => $type::$name,
+                        )*
+                    }
+                }
+            }
+
+            impl<T: $tbound> $type<T> {
+                /// Returns a unit of the current type with its default value:
+                /// zero, `false`, or empty.
+                ///
+                /// Points in time default to the unix epoch. The `None` and `With`
+                /// types, and the instants, which have no meaningful default,
+                /// return the `None` unit.
+                ///
+                /// # Examples
+                /// ```
+                #[doc = "use ladata::all::{" $talias " as T, " $ualias " as U};"]
+                ///
+                /// assert![matches![T::U8.default_unit(), U::U8(0)]];
+                /// assert![matches![T::Bool.default_unit(), U::Bool(false)]];
+                /// assert![matches![T::ByteArray1.default_unit(), U::ByteArray1([0])]];
+                /// assert![matches![T::None.default_unit(), U::None]];
+                /// ```
+                pub fn default_unit(&self) -> $ualias {
+                    use crate::unit::default::DefaultField;
+                    match self {
+                        Self::None | Self::With(_) => $ualias::None,
+                        $(
+                            #[cfg($($cfg)*)]
+                            Self::$name => DefaultField::default_field().map_or($ualias::None, $ualias::$name),
+                        )*
+                    }
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_data_link;
+
+/// for converting DataType*, DataUnit*, DataRaw* and Tagged* between sizes
+///
+/// Each size converts into every larger size, and tries to convert back.
+macro_rules! impl_data_resize {
+    (
+        t: $tname:ident, c: $cname:ident, b: $bname:ident, g: $gname:ident,
+        size: $B:literal, $b:literal,
+        larger: $( ($LB:literal, $lb:literal) )* ;
+        copy_variants: $copy:tt ;
+        noncopy_variants: $noncopy:tt ;
+    ) => {
+        $(
+            impl_data_resize![@pair t: $tname, c: $cname, b: $bname, g: $gname,
+                small: $b, large: $LB, $lb; $copy $noncopy];
+        )*
+    };
+    (@pair t: $tname:ident, c: $cname:ident, b: $bname:ident, g: $gname:ident,
+        small: $b:literal, large: $LB:literal, $lb:literal;
+        [ $( $cvname:ident, ( $($cvcfg:tt)* ); )* ]
+        [ $( $vname:ident, ( $($vcfg:tt)* ); )* ]
+    ) => {
+        devela::paste!{
+            impl_data_resize![@type [<$tname $b bit With>], [<$tname $lb bit With>], DataType;
+                $( $cvname, ( $($cvcfg)* ); )*
+                $( $vname, ( $($vcfg)* ); )* ];
+            impl_data_resize![@type [<$tname $b bit Copy With>], [<$tname $lb bit Copy With>],
+                DataTypeCopy;
+                $( $cvname, ( $($cvcfg)* ); )* ];
+            impl_data_resize![@unit [<$cname $b bit With>], [<$cname $lb bit With>], DataUnit;
+                $( $cvname, ( $($cvcfg)* ); )*
+                $( $vname, ( $($vcfg)* ); )* ];
+            impl_data_resize![@unit [<$cname $b bit Copy With>], [<$cname $lb bit Copy With>],
+                DataUnitCopy; $( $cvname, ( $($cvcfg)* ); )* ];
+
+            #[cfg(feature = "unsafe_unit")]
+            impl_data_resize![@raw [<$bname $b bit Copy>], [<$bname $lb bit Copy>],
+                [<ByteArray $LB>], $LB];
+            #[cfg(feature = "unsafe_unit")]
+            impl_data_resize![@tagged [<$gname $b bit>], [<$gname $lb bit>], [<$cname $b bit Copy>]];
+        }
+    };
+    (@type $small:ident, $large:ident, $tbound:ident; $( $name:ident, ( $($cfg:tt)* ); )* ) => {
+        /// Promotes the *data type* to a larger size.
+        impl<T: $tbound> From<$small<T>> for $large<T> {
+            fn from(t: $small<T>) -> Self {
+                match t {
+                    $small::None => Self::None,
+                    $small::With(t) => Self::With(t),
+                    $(
+                        #[cfg($($cfg)*)]
+                        $small::$name => Self::$name,
+                    )*
+                }
+            }
+        }
+        /// Demotes the *data type* to a smaller size.
+        ///
+        /// Returns [`TypeMismatch`][crate::error::LadataError::TypeMismatch]
+        /// if the type doesn't exist at the smaller size.
+        impl<T: $tbound> TryFrom<$large<T>> for $small<T> {
+            type Error = crate::error::LadataError;
+            fn try_from(t: $large<T>) -> crate::error::LadataResult<Self> {
+                match t {
+                    $large::None => Ok(Self::None),
+                    $large::With(t) => Ok(Self::With(t)),
+                    $(
+                        #[cfg($($cfg)*)]
+                        $large::$name => Ok(Self::$name),
+                    )*
+                    _ => Err(crate::error::LadataError::TypeMismatch),
+                }
+            }
+        }
+    };
+    (@unit $small:ident, $large:ident, $bound:ident; $( $name:ident, ( $($cfg:tt)* ); )* ) => {
+        /// Promotes the *data unit* to a larger size.
+        impl<C: $bound> From<$small<C>> for $large<C> {
+            fn from(c: $small<C>) -> Self {
+                match c {
+                    $small::None => Self::None,
+                    $small::With(c) => Self::With(c),
+                    $(
+                        #[cfg($($cfg)*)]
+                        $small::$name(v) => Self::$name(v),
+                    )*
+                }
+            }
+        }
+        /// Demotes the *data unit* to a smaller size.
+        ///
+        /// Returns [`TypeMismatch`][crate::error::LadataError::TypeMismatch]
+        /// if the type of the unit doesn't exist at the smaller size.
+        impl<C: $bound> TryFrom<$large<C>> for $small<C> {
+            type Error = crate::error::LadataError;
+            fn try_from(c: $large<C>) -> crate::error::LadataResult<Self> {
+                match c {
+                    $large::None => Ok(Self::None),
+                    $large::With(c) => Ok(Self::With(c)),
+                    $(
+                        #[cfg($($cfg)*)]
+                        $large::$name(v) => Ok(Self::$name(v)),
+                    )*
+                    _ => Err(crate::error::LadataError::TypeMismatch),
+                }
+            }
+        }
+    };
+    (@raw $small:ident, $large:ident, $bytes:ident, $LB:literal) => {
+        /// Promotes the *raw data* to a larger size, keeping the value of its
+        /// active field, which is also the active field of the result.
+        ///
+        /// There's no demotion, since the active field is unknown.
+        /// Use the *tagged* data instead.
+        impl From<$small> for $large {
+            fn from(raw: $small) -> Self {
+                let mut large = Self { $bytes: [0; $LB] };
+                // SAFETY: all the fields of both unions start at offset 0,
+                // and the smaller union fits inside the larger one.
+                unsafe {
+                    core::ptr::copy_nonoverlapping(
+                        (&raw as *const $small).cast::<u8>(),
+                        (&mut large as *mut $large).cast::<u8>(),
+                        core::mem::size_of::<$small>(),
+                    );
+                }
+                large
+            }
+        }
+    };
+    (@tagged $small:ident, $large:ident, $unit:ident) => {
+        /// Promotes the *tagged data* to a larger size.
+        impl From<$small> for $large {
+            fn from(tagged: $small) -> Self {
+                Self::new(tagged.to_unit().into())
+            }
+        }
+        /// Demotes the *tagged data* to a smaller size.
+        ///
+        /// Returns [`TypeMismatch`][crate::error::LadataError::TypeMismatch]
+        /// if its type doesn't exist at the smaller size.
+        impl TryFrom<$large> for $small {
+            type Error = crate::error::LadataError;
+            fn try_from(tagged: $large) -> crate::error::LadataResult<Self> {
+                $unit::try_from(tagged.to_unit()).map(Self::new)
+            }
+        }
+    };
+}
+pub(crate) use impl_data_resize;
+
+/// re-exports types from public modules.
+macro_rules! reexport {
+    // external branches, multi-type re-export
+    // -------------------------------------------------------------------------
+
+    // reexports all the sizes
+    ($mod:ident, $path:path; all_sizes) => {
+        use $crate::unit::macros::reexport;
+        reexport![$mod $path; 1, 8];
+        reexport![$mod $path; 2, 16];
+        reexport![$mod $path; 4, 32];
+        reexport![$mod $path; 8, 64];
+        reexport![$mod $path; 16, 128];
+        reexport![$mod $path; 32, 256];
+        reexport![$mod $path; 64, 512];
+        reexport![$mod $path; 128, 1024];
+    };
+
+    // `::size::` sub-modules reexports, single size.
+    (mod_size $path:path; $B:literal, $b:literal ) => {
+        devela::paste!{
+            #[doc = $b " bit data (== " $B " Byte)" ]
+            pub mod [< b $b >] {
+                $crate::unit::macros::reexport![@UnitType $path; size: $b; bit bitWith bitCopy bitCopyWith ];
+                $crate::unit::macros::reexport![@Raw $path; size: $b; bitCopy ];
+                $crate::unit::macros::reexport![@Tagged $path; size: $b; bit ];
+                $crate::unit::macros::reexport![@Column $path; size: $b;
+                    bit bitWith bitCopy bitCopyWith ];
+            }
+        }
+    };
+
+    // `::cells::` reexports, single size
+    (mod_unit $path:path; $B:literal, $b:literal ) => {
+        devela::paste!{
+            $crate::unit::macros::reexport![@Unit $path; size: $b; bit bitWith bitCopy bitCopyWith ];
+        }
+    };
+    // `::types::` reexports, single size
+    (mod_types $path:path; $B:literal, $b:literal ) => {
+        devela::paste!{
+            $crate::unit::macros::reexport![@Type $path; size: $b; bit bitWith bitCopy bitCopyWith ];
+        }
+    };
+    // `::column::` reexports, single size
+    (mod_column $path:path; $B:literal, $b:literal ) => {
+        devela::paste!{
+            $crate::unit::macros::reexport![@Column $path; size: $b; bit bitWith bitCopy bitCopyWith ];
+        }
+    };
+    // `::tagged::` reexports, single size
+    (mod_tagged $path:path; $B:literal, $b:literal ) => {
+        devela::paste!{
+            $crate::unit::macros::reexport![@Tagged $path; size: $b; bit ];
+        }
+    };
+    // `::raw::` reexports, single size
+    (mod_raw $path:path; $B:literal, $b:literal ) => {
+        devela::paste!{
+            // WIP
+            $crate::unit::macros::reexport![@Raw $path; size: $b; bitCopy ];
+            // $crate::unit::macros::reexport![@Raw $path; size: $b; bit bitWith bitCopy bitCopyWith ];
+        }
+    };
+
+    // internal branches, single-type re-export
+    // -------------------------------------------------------------------------
 
     // re-exports DataUnit
     (@Unit $path:path; size: $size:literal; $( $suf:ident )+ ) => {
@@ -1808,6 +3609,18 @@ macro_rules! reexport {
         $crate::unit::macros::reexport![@ $path; DataRaw; size: $size ; $( $suf )+ ];
     };
 
+    // re-exports Tagged
+    (@Tagged $path:path; size: $size:literal; $( $suf:ident )+ ) => {
+        #[cfg(feature = "unsafe_unit")]
+        $crate::unit::macros::reexport![@ $path; Tagged; size: $size ; $( $suf )+ ];
+    };
+
+    // re-exports DataColumn
+    (@Column $path:path; size: $size:literal; $( $suf:ident )+ ) => {
+        #[cfg(feature = "alloc")]
+        $crate::unit::macros::reexport![@ $path; DataColumn; size: $size ; $( $suf )+ ];
+    };
+
     // re-exports both DataUnit & DataType
     (@UnitType $path:path; size: $size:literal; $( $suf:ident )+ ) => {
         $crate::unit::macros::reexport![@ $path; DataUnit; size: $size ; $( $suf )+ ];
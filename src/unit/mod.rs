@@ -4,12 +4,22 @@
 pub use all::*;
 pub(crate) mod all {
     #[doc(inline)]
-    pub use super::{traits::*, types::*, unit::*};
+    pub use super::{cast::CastMode, registry::*, traits::*, types::*, unit::*};
 
     #[doc(inline)]
     #[cfg(feature = "unsafe_unit")]
     #[cfg_attr(feature = "nightly", doc(cfg(feature = "unsafe_unit")))]
-    pub use super::raw::*;
+    pub use super::{raw::*, tagged::*};
+
+    #[doc(inline)]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    pub use super::column::*;
+
+    #[doc(inline)]
+    #[cfg(feature = "derive")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "derive")))]
+    pub use ladata_derive::DataUnit;
 }
 
 /// *Raw* Data (only the unsafe *raw* data).
@@ -19,6 +29,13 @@ pub mod raw {
     super::macros::reexport![mod_raw, crate::unit::build; all_sizes];
 }
 
+/// *Tagged* Data (the *raw* data safely paired with its *type*).
+#[cfg(feature = "unsafe_unit")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "unsafe_unit")))]
+pub mod tagged {
+    super::macros::reexport![mod_tagged, crate::unit::build; all_sizes];
+}
+
 /// Data *Type* (only the *type* of the data).
 pub mod types {
     super::macros::reexport![mod_types, crate::unit::build; all_sizes];
@@ -29,6 +46,13 @@ pub mod unit {
     super::macros::reexport![mod_unit, crate::unit::build; all_sizes];
 }
 
+/// Data *Column* (a sequence of *data* sharing the same *type*).
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod column {
+    super::macros::reexport![mod_column, crate::unit::build; all_sizes];
+}
+
 /// Every unitary type, organized by size.
 pub mod size {
     super::macros::reexport![mod_size, crate::unit::build; all_sizes];
@@ -36,9 +60,18 @@ pub mod size {
 
 pub mod traits;
 
+mod arith;
 mod build;
+mod cast;
+mod default;
+mod encoding;
 mod macros;
 mod nodata;
+mod order;
+mod registry;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_field;
+mod text;
 
 #[cfg(test)]
 mod tests;
@@ -26,10 +26,14 @@ impl DataType for () {
     }
 }
 impl DataUnit for () {
+    type Type = ();
+
     #[inline]
     fn is_copy(&self) -> bool {
         true
     }
+    #[inline]
+    fn unit_type(&self) {}
 }
 
 impl DataTypeCopy for () {}
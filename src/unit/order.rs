@@ -0,0 +1,169 @@
+// ladata::unit::order
+//
+//! Total ordering and hashing of the data contained in the units.
+//!
+//! Every variant is compared and hashed through the [`OrdField`] trait,
+//! so that types lacking a total order on their own, like floats,
+//! can still be used as keys in ordered and hashed collections.
+//!
+//! - floats are ordered by their [`total_cmp`][f64::total_cmp] method,
+//!   and hashed by their bits, so `-0.0 < 0.0` and `NaN == NaN`.
+//! - bit arrays are ordered as their byte slices.
+//! - `fugit` instants are ordered by their ticks, without wrapping around.
+//
+
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+/// Compares and hashes the data contained in a unit variant.
+///
+/// Both methods must be consistent: equal values must have equal hashes.
+pub(crate) trait OrdField {
+    fn cmp_field(&self, other: &Self) -> Ordering;
+    fn hash_field<H: Hasher>(&self, state: &mut H);
+}
+
+/* forwarding to the standard implementations */
+
+macro_rules! forward {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl OrdField for $type {
+                #[inline]
+                fn cmp_field(&self, other: &Self) -> Ordering {
+                    Ord::cmp(self, other)
+                }
+                #[inline]
+                fn hash_field<H: Hasher>(&self, state: &mut H) {
+                    Hash::hash(self, state)
+                }
+            }
+        )+
+    };
+}
+forward![
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, bool, char,
+    core::time::Duration,
+    #[cfg(feature = "std")] std::time::Instant,
+    #[cfg(feature = "std")] std::time::SystemTime,
+    #[cfg(feature = "std")] std::string::String,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i32>,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i64>,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i128>,
+    #[cfg(feature = "num-bigint")] num_bigint::BigInt,
+    #[cfg(feature = "rust_decimal")] rust_decimal::Decimal,
+    #[cfg(feature = "time")] time::Date,
+    #[cfg(feature = "time")] time::Time,
+    #[cfg(feature = "time")] time::UtcOffset,
+    #[cfg(feature = "time")] time::Duration,
+    #[cfg(feature = "time")] time::PrimitiveDateTime,
+    #[cfg(feature = "time")] time::OffsetDateTime,
+    #[cfg(all(feature = "std", feature = "time"))]
+    #[allow(deprecated)]
+    time::Instant,
+];
+
+impl<const N: usize> OrdField for [u8; N] {
+    #[inline]
+    fn cmp_field(&self, other: &Self) -> Ordering {
+        Ord::cmp(self, other)
+    }
+    #[inline]
+    fn hash_field<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self, state)
+    }
+}
+
+#[cfg(feature = "arraystring")]
+impl<SIZE: arraystring::prelude::Capacity> OrdField for arraystring::ArrayString<SIZE> {
+    #[inline]
+    fn cmp_field(&self, other: &Self) -> Ordering {
+        Ord::cmp(self, other)
+    }
+    #[inline]
+    fn hash_field<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self, state)
+    }
+}
+
+impl<const BITLEN: usize, const BYTECAP: usize> OrdField
+    for crate::list::BitArray<(), BITLEN, BYTECAP>
+{
+    #[inline]
+    fn cmp_field(&self, other: &Self) -> Ordering {
+        self.as_byte_slice().cmp(other.as_byte_slice())
+    }
+    #[inline]
+    fn hash_field<H: Hasher>(&self, state: &mut H) {
+        self.as_byte_slice().hash(state)
+    }
+}
+
+/* floats */
+
+macro_rules! impl_float {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl OrdField for $type {
+                #[inline]
+                fn cmp_field(&self, other: &Self) -> Ordering {
+                    self.total_cmp(other)
+                }
+                #[inline]
+                fn hash_field<H: Hasher>(&self, state: &mut H) {
+                    self.to_bits().hash(state)
+                }
+            }
+        )+
+    };
+}
+impl_float![
+    f32,
+    f64,
+    #[cfg(feature = "half")]
+    half::f16,
+    #[cfg(feature = "half")]
+    half::bf16,
+];
+
+#[cfg(feature = "twofloat")]
+impl OrdField for twofloat::TwoFloat {
+    /// Compares the high parts first, and then the low parts.
+    #[inline]
+    fn cmp_field(&self, other: &Self) -> Ordering {
+        self.hi()
+            .total_cmp(&other.hi())
+            .then(self.lo().total_cmp(&other.lo()))
+    }
+    #[inline]
+    fn hash_field<H: Hasher>(&self, state: &mut H) {
+        self.hi().to_bits().hash(state);
+        self.lo().to_bits().hash(state);
+    }
+}
+
+/* fugit */
+
+#[cfg(feature = "fugit")]
+macro_rules! impl_fugit {
+    ($( $type:ident<$prim:ty> ),+) => {
+        $(
+            impl<const NOM: u32, const DENOM: u32> OrdField for fugit::$type<$prim, NOM, DENOM> {
+                #[inline]
+                fn cmp_field(&self, other: &Self) -> Ordering {
+                    self.ticks().cmp(&other.ticks())
+                }
+                #[inline]
+                fn hash_field<H: Hasher>(&self, state: &mut H) {
+                    self.ticks().hash(state)
+                }
+            }
+        )+
+    };
+}
+#[cfg(feature = "fugit")]
+impl_fugit![Duration<u32>, Duration<u64>, Instant<u32>, Instant<u64>];
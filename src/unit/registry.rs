@@ -0,0 +1,268 @@
+// ladata::unit::registry
+//
+//! Registries of custom *data types*, for the `With` variants.
+//!
+//! A registry gives each custom *data type* a stable numeric id and a unique
+//! name, so that the `With` units can be encoded in binary by id, and written
+//! as text by name, and read back from both.
+//
+
+use super::DataType;
+use crate::error::{LadataError as Error, LadataResult as Result};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Common trait for registries of custom *data types*.
+///
+/// Every registered *data type* has a unique id and a unique name.
+///
+/// Implementors only need to provide [`registry_len`][Self::registry_len] and
+/// [`registry_entry`][Self::registry_entry], from which the lookups are provided.
+pub trait DataTypeRegistry {
+    /// The registered *data type*.
+    type Type: DataType + PartialEq;
+
+    /// Returns the number of registered types.
+    fn registry_len(&self) -> usize;
+
+    /// Returns the id, name and *data type* of the entry at `index`.
+    fn registry_entry(&self, index: usize) -> Option<(u32, &str, Self::Type)>;
+
+    /// Returns the name and the *data type* registered with the given `id`.
+    fn registry_lookup_by_id(&self, id: u32) -> Option<(&str, Self::Type)> {
+        (0..self.registry_len())
+            .filter_map(|i| self.registry_entry(i))
+            .find(|(i, _, _)| *i == id)
+            .map(|(_, n, t)| (n, t))
+    }
+
+    /// Returns the id and the *data type* registered with the given `name`.
+    fn registry_lookup_by_name(&self, name: &str) -> Option<(u32, Self::Type)> {
+        (0..self.registry_len())
+            .filter_map(|i| self.registry_entry(i))
+            .find(|(_, n, _)| *n == name)
+            .map(|(i, _, t)| (i, t))
+    }
+
+    /// Returns the id and the name of the given `data_type`.
+    fn registry_lookup_by_type(&self, data_type: &Self::Type) -> Option<(u32, &str)> {
+        (0..self.registry_len())
+            .filter_map(|i| self.registry_entry(i))
+            .find(|(_, _, t)| t == data_type)
+            .map(|(i, n, _)| (i, n))
+    }
+}
+
+/// A registry of custom *data types*, backed by a [`Vec`].
+///
+/// # Examples
+/// ```
+/// use ladata::all::{DataType8bitCopy as T, DataTypeRegistry, TypeRegistry};
+/// # fn main() -> ladata::error::LadataResult<()> {
+///
+/// let mut r = TypeRegistry::new();
+/// r.register(100, "level", T::U8)?;
+/// r.register(101, "flag", T::Bool)?;
+/// assert![r.register(100, "other", T::I8).is_err()];
+///
+/// assert_eq![Some(("flag", T::Bool)), r.registry_lookup_by_id(101)];
+/// assert_eq![Some((100, T::U8)), r.registry_lookup_by_name("level")];
+/// assert_eq![2, r.iter().count()];
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub struct TypeRegistry<T: DataType> {
+    entries: Vec<(u32, String, T)>,
+}
+
+/// A registry of custom *data types*, backed by an array with a fixed capacity.
+///
+/// # Examples
+/// ```
+/// use ladata::all::{DataType8bitCopy as T, DataTypeRegistry, TypeRegistryArray};
+/// # fn main() -> ladata::error::LadataResult<()> {
+///
+/// let mut r = TypeRegistryArray::<T, 2>::new();
+/// r.register(7, "level", T::U8)?;
+/// r.register(8, "flag", T::Bool)?;
+/// assert![r.register(9, "extra", T::I8).is_err()];
+/// assert_eq![Some((8, "flag")), r.registry_lookup_by_type(&T::Bool)];
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TypeRegistryArray<'a, T: DataType, const CAP: usize> {
+    entries: [Option<(u32, &'a str, T)>; CAP],
+    len: usize,
+}
+
+/* TypeRegistry */
+
+#[cfg(feature = "alloc")]
+impl<T: DataType + PartialEq> TypeRegistry<T> {
+    /// Returns a new empty registry.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Registers a *data type* with the given `id` and `name`.
+    ///
+    /// # Errors
+    /// Returns [`KeyAlreadyExists`][Error::KeyAlreadyExists] if the `id`,
+    /// the `name` or the `data_type` are already registered.
+    pub fn register<N: Into<String>>(&mut self, id: u32, name: N, data_type: T) -> Result<()> {
+        let name = name.into();
+        if is_registered(self, id, &name, &data_type) {
+            return Err(Error::KeyAlreadyExists);
+        }
+        self.entries.push((id, name, data_type));
+        Ok(())
+    }
+
+    /// Returns the number of registered types.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no registered types.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the ids, names and *data types*, in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str, T)> {
+        self.entries.iter().map(|(i, n, t)| (*i, n.as_str(), *t))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DataType + PartialEq> Default for TypeRegistry<T> {
+    /// Returns a new empty registry.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DataType + PartialEq> DataTypeRegistry for TypeRegistry<T> {
+    type Type = T;
+
+    #[inline]
+    fn registry_len(&self) -> usize {
+        self.entries.len()
+    }
+    #[inline]
+    fn registry_entry(&self, index: usize) -> Option<(u32, &str, T)> {
+        self.entries
+            .get(index)
+            .map(|(i, n, t)| (*i, n.as_str(), *t))
+    }
+}
+
+/* TypeRegistryArray */
+
+impl<'a, T: DataType, const CAP: usize> TypeRegistryArray<'a, T, CAP> {
+    /// Returns a new empty registry.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: [None; CAP],
+            len: 0,
+        }
+    }
+}
+
+impl<'a, T: DataType + PartialEq, const CAP: usize> TypeRegistryArray<'a, T, CAP> {
+    /// Registers a *data type* with the given `id` and `name`.
+    ///
+    /// # Errors
+    /// Returns [`NotEnoughSpace`][Error::NotEnoughSpace] if the registry is full,
+    /// or [`KeyAlreadyExists`][Error::KeyAlreadyExists] if the `id`,
+    /// the `name` or the `data_type` are already registered.
+    pub fn register(&mut self, id: u32, name: &'a str, data_type: T) -> Result<()> {
+        if self.len == CAP {
+            return Err(Error::NotEnoughSpace(Some(1)));
+        }
+        if is_registered(self, id, name, &data_type) {
+            return Err(Error::KeyAlreadyExists);
+        }
+        self.entries[self.len] = Some((id, name, data_type));
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the number of registered types.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no registered types.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of types that can be registered.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Returns `true` if no more types can be registered.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    /// Returns an iterator over the ids, names and *data types*, in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str, T)> {
+        self.entries[..self.len]
+            .iter()
+            .flatten()
+            .map(|(i, n, t)| (*i, *n, *t))
+    }
+}
+
+impl<'a, T: DataType, const CAP: usize> Default for TypeRegistryArray<'a, T, CAP> {
+    /// Returns a new empty registry.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: DataType + PartialEq, const CAP: usize> DataTypeRegistry
+    for TypeRegistryArray<'a, T, CAP>
+{
+    type Type = T;
+
+    #[inline]
+    fn registry_len(&self) -> usize {
+        self.len
+    }
+    #[inline]
+    fn registry_entry(&self, index: usize) -> Option<(u32, &str, T)> {
+        self.entries[..self.len].get(index).copied().flatten()
+    }
+}
+
+/* helpers */
+
+/// Returns `true` if either the `id`, the `name` or the `data_type` are registered.
+fn is_registered<R: DataTypeRegistry>(
+    registry: &R,
+    id: u32,
+    name: &str,
+    data_type: &R::Type,
+) -> bool {
+    (0..registry.registry_len())
+        .filter_map(|i| registry.registry_entry(i))
+        .any(|(i, n, t)| i == id || n == name || t == *data_type)
+}
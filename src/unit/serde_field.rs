@@ -0,0 +1,248 @@
+// ladata::unit::serde_field
+//
+//! Serde support for the data contained in the unitary types.
+//!
+//! Every variant is (de)serialized through `#[serde(with = "…")]` using the
+//! [`SerdeField`] trait, so that types lacking serde support on their own
+//! (like byte arrays longer than 32) can be supported too.
+//
+
+use core::fmt;
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+#[cfg(feature = "alloc")]
+use {alloc::vec::Vec, core::marker::PhantomData, serde::ser::SerializeSeq};
+
+/// (De)serializes the data contained in a unit variant.
+pub(crate) trait SerdeField: Sized {
+    fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+    fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// Serializes a field, to be used with `#[serde(with = "…")]`.
+#[inline]
+pub(crate) fn serialize<T: SerdeField, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.serialize_field(serializer)
+}
+
+/// Deserializes a field, to be used with `#[serde(with = "…")]`.
+#[inline]
+pub(crate) fn deserialize<'de, T: SerdeField, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::deserialize_field(deserializer)
+}
+
+/* forwarding to the serde implementations */
+
+macro_rules! forward {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl SerdeField for $type {
+                #[inline]
+                fn serialize_field<S: Serializer>(&self, serializer: S)
+                    -> Result<S::Ok, S::Error> {
+                    Serialize::serialize(self, serializer)
+                }
+                #[inline]
+                fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D)
+                    -> Result<Self, D::Error> {
+                    Deserialize::deserialize(deserializer)
+                }
+            }
+        )+
+    };
+}
+forward![
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize,
+    f32, f64, bool, char, core::time::Duration,
+    #[cfg(feature = "std")] std::time::SystemTime,
+    #[cfg(feature = "std")] std::string::String,
+    #[cfg(feature = "half")] half::f16,
+    #[cfg(feature = "half")] half::bf16,
+    #[cfg(feature = "twofloat")] twofloat::TwoFloat,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i32>,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i64>,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i128>,
+    #[cfg(feature = "num-bigint")] num_bigint::BigInt,
+    #[cfg(feature = "rust_decimal")] rust_decimal::Decimal,
+    #[cfg(feature = "time")] time::Date,
+    #[cfg(feature = "time")] time::Time,
+    #[cfg(feature = "time")] time::UtcOffset,
+    #[cfg(feature = "time")] time::Duration,
+    #[cfg(feature = "time")] time::PrimitiveDateTime,
+    #[cfg(feature = "time")] time::OffsetDateTime,
+];
+
+#[cfg(feature = "arraystring")]
+impl<SIZE: arraystring::prelude::Capacity> SerdeField for arraystring::ArrayString<SIZE> {
+    #[inline]
+    fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Serialize::serialize(self, serializer)
+    }
+    #[inline]
+    fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Deserialize::deserialize(deserializer)
+    }
+}
+
+#[cfg(feature = "fugit")]
+macro_rules! forward_fugit {
+    ($( $type:ident<$prim:ty> ),+) => {
+        $(
+            impl<const NOM: u32, const DENOM: u32> SerdeField for fugit::$type<$prim, NOM, DENOM> {
+                #[inline]
+                fn serialize_field<S: Serializer>(&self, serializer: S)
+                    -> Result<S::Ok, S::Error> {
+                    Serialize::serialize(self, serializer)
+                }
+                #[inline]
+                fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D)
+                    -> Result<Self, D::Error> {
+                    Deserialize::deserialize(deserializer)
+                }
+            }
+        )+
+    };
+}
+#[cfg(feature = "fugit")]
+forward_fugit![Duration<u32>, Duration<u64>, Instant<u32>, Instant<u64>];
+
+/* byte arrays of any length */
+
+impl<const N: usize> SerdeField for [u8; N] {
+    #[inline]
+    fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self, serializer)
+    }
+    #[inline]
+    fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(deserializer)
+    }
+}
+
+/// Serializes a byte slice as a tuple, the same way serde serializes arrays.
+pub(crate) fn serialize_bytes<S: Serializer>(
+    bytes: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(bytes.len())?;
+    for byte in bytes {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+/// Deserializes a byte array from a tuple of exactly `N` bytes.
+pub(crate) fn deserialize_bytes<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    struct BytesVisitor<const N: usize>;
+    impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+        type Value = [u8; N];
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "an array of {N} bytes")
+        }
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = [0; N];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            }
+            Ok(bytes)
+        }
+    }
+    deserializer.deserialize_tuple(N, BytesVisitor::<N>)
+}
+
+/* bit arrays */
+
+impl<const BITLEN: usize, const BYTECAP: usize> SerdeField
+    for crate::list::BitArray<(), BITLEN, BYTECAP>
+{
+    #[inline]
+    fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Serialize::serialize(self, serializer)
+    }
+    #[inline]
+    fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Deserialize::deserialize(deserializer)
+    }
+}
+
+/* types without a meaningful serialization */
+
+macro_rules! unserializable {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl SerdeField for $type {
+                fn serialize_field<S: Serializer>(&self, _serializer: S)
+                    -> Result<S::Ok, S::Error> {
+                    Err(serde::ser::Error::custom(concat!["`", stringify!($type), "` can't be serialized"]))
+                }
+                fn deserialize_field<'de, D: Deserializer<'de>>(_deserializer: D)
+                    -> Result<Self, D::Error> {
+                    Err(de::Error::custom(concat!["`", stringify!($type), "` can't be deserialized"]))
+                }
+            }
+        )+
+    };
+}
+unserializable![
+    #[cfg(feature = "std")]
+    std::time::Instant,
+    #[cfg(all(feature = "std", feature = "time"))]
+    #[allow(deprecated)]
+    time::Instant,
+];
+
+/* columns */
+
+#[cfg(feature = "alloc")]
+impl<T: SerdeField> SerdeField for Vec<T> {
+    fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Field<'a, T>(&'a T);
+        impl<T: SerdeField> Serialize for Field<'_, T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize_field(serializer)
+            }
+        }
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self {
+            seq.serialize_element(&Field(element))?;
+        }
+        seq.end()
+    }
+    fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Field<T>(T);
+        impl<'de, T: SerdeField> Deserialize<'de> for Field<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                T::deserialize_field(deserializer).map(Field)
+            }
+        }
+        struct VecVisitor<T>(PhantomData<T>);
+        impl<'de, T: SerdeField> Visitor<'de> for VecVisitor<T> {
+            type Value = Vec<T>;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a sequence")
+            }
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(Field(element)) = seq.next_element()? {
+                    vec.push(element);
+                }
+                Ok(vec)
+            }
+        }
+        deserializer.deserialize_seq(VecVisitor(PhantomData))
+    }
+}
@@ -53,3 +53,1560 @@ fn test_unsafe_sizes_without() {
     assert_eq![64, size_of::<DataRaw512bitCopy>()];
     assert_eq![128, size_of::<DataRaw1024bitCopy>()];
 }
+
+#[cfg(all(feature = "serde", feature = "std"))]
+mod serde {
+    use crate::all::*;
+    use serde::{de::DeserializeOwned, Serialize};
+
+    /// Serializes `value`, deserializes it back and checks both serializations match.
+    fn roundtrip<T: Serialize + DeserializeOwned>(value: T) -> T {
+        let json = serde_json::to_string(&value).unwrap();
+        let back: T = serde_json::from_str(&json).unwrap();
+        assert_eq![json, serde_json::to_string(&back).unwrap()];
+        back
+    }
+
+    #[test]
+    fn roundtrip_all_sizes() {
+        assert![matches![
+            roundtrip(DataUnit8bit::I8(-8)),
+            DataUnit8bit::I8(-8)
+        ]];
+        assert![matches![
+            roundtrip(DataUnit16bit::U16(16)),
+            DataUnit16bit::U16(16)
+        ]];
+        assert![matches![
+            roundtrip(DataUnit32bit::Char('π')),
+            DataUnit32bit::Char('π')
+        ]];
+        assert![matches![roundtrip(DataUnit64bit::F64(6.4)), DataUnit64bit::F64(f) if f == 6.4]];
+        assert![matches![
+            roundtrip(DataUnit128bit::I128(i128::MIN)),
+            DataUnit128bit::I128(i128::MIN)
+        ]];
+        assert![matches![
+            roundtrip(DataUnit256bit::ByteArray32([2; 32])),
+            DataUnit256bit::ByteArray32(a) if a == [2; 32]
+        ]];
+        assert![matches![
+            roundtrip(DataUnit512bit::ByteArray64([5; 64])),
+            DataUnit512bit::ByteArray64(a) if a == [5; 64]
+        ]];
+        assert![matches![
+            roundtrip(DataUnit1024bit::ByteArray128([10; 128])),
+            DataUnit1024bit::ByteArray128(a) if a == [10; 128]
+        ]];
+
+        assert![matches![roundtrip(DataType8bit::Bool), DataType8bit::Bool]];
+        assert![matches![roundtrip(DataType16bit::I16), DataType16bit::I16]];
+        assert![matches![roundtrip(DataType32bit::F32), DataType32bit::F32]];
+        assert![matches![roundtrip(DataType64bit::U64), DataType64bit::U64]];
+        assert![matches![
+            roundtrip(DataType128bit::Duration),
+            DataType128bit::Duration
+        ]];
+        assert![matches![
+            roundtrip(DataType256bit::BitArray256),
+            DataType256bit::BitArray256
+        ]];
+        assert![matches![
+            roundtrip(DataType512bit::None),
+            DataType512bit::None
+        ]];
+        assert![matches![
+            roundtrip(DataType1024bit::ByteArray128),
+            DataType1024bit::ByteArray128
+        ]];
+    }
+
+    #[test]
+    fn roundtrip_copy_and_with() {
+        #[derive(Clone, Copy, Debug, Serialize, serde::Deserialize)]
+        struct Custom(u8);
+        impl DataType for Custom {
+            fn data_align(&self) -> usize {
+                1
+            }
+            fn data_size(&self) -> usize {
+                1
+            }
+            fn is_copy(&self) -> bool {
+                true
+            }
+        }
+        impl DataUnit for Custom {
+            type Type = Custom;
+
+            fn is_copy(&self) -> bool {
+                true
+            }
+            fn unit_type(&self) -> Custom {
+                *self
+            }
+        }
+        impl DataTypeCopy for Custom {}
+        impl DataUnitCopy for Custom {}
+
+        let u = DataUnit64bitCopyWith::<Custom>::With(Custom(7));
+        assert![matches![
+            roundtrip(u),
+            DataUnit64bitCopyWith::With(Custom(7))
+        ]];
+        let u = DataUnit8bitWith::<Custom>::With(Custom(8));
+        assert![matches![roundtrip(u), DataUnit8bitWith::With(Custom(8))]];
+        let t = DataType32bitCopyWith::<Custom>::With(Custom(9));
+        assert![matches![
+            roundtrip(t),
+            DataType32bitCopyWith::With(Custom(9))
+        ]];
+
+        let u = DataUnit32bitCopy::U32(u32::MAX);
+        assert![matches![roundtrip(u), DataUnit32bitCopy::U32(u32::MAX)]];
+    }
+
+    #[test]
+    fn roundtrip_bit_arrays() {
+        let mut ba = DirectBitArray::<1024, 128>::new_zeroed().unwrap();
+        ba.set_bit(1000, true).unwrap();
+        let back = roundtrip(DataUnit1024bit::BitArray1024(ba));
+        assert![matches![back, DataUnit1024bit::BitArray1024(b) if b.get_bit(1000) == Ok(true)]];
+        assert![matches![
+            roundtrip(DataUnit8bit::BitArray8(DirectBitArray8::new_oned().unwrap())),
+            DataUnit8bit::BitArray8(b) if b.is_oned()
+        ]];
+    }
+
+    #[test]
+    fn roundtrip_columns() {
+        let mut c = DataColumn512bit::new(DataType512bit::ByteArray64);
+        c.push(DataUnit512bit::ByteArray64([3; 64])).unwrap();
+        let back = roundtrip(c);
+        assert![matches![back.get(0), Some(DataUnit512bit::ByteArray64(a)) if a == [3; 64]]];
+    }
+
+    #[test]
+    fn instant_fails() {
+        let u = DataUnit128bit::Instant(std::time::Instant::now());
+        assert![serde_json::to_string(&u).is_err()];
+    }
+
+    #[test]
+    #[cfg(feature = "deps_all")]
+    fn roundtrip_dependencies() {
+        use core::str::FromStr;
+
+        let f = half::f16::from_f32(1.5);
+        assert![matches![roundtrip(DataUnit16bit::F16(f)), DataUnit16bit::F16(b) if b == f]];
+
+        let d = time::Date::from_calendar_date(2023, time::Month::August, 29).unwrap();
+        assert![matches![roundtrip(DataUnit32bit::TDate(d)), DataUnit32bit::TDate(b) if b == d]];
+
+        let s = arraystring::ArrayString::try_from_str("ladata").unwrap();
+        assert![matches![
+            roundtrip(DataUnit64bit::ArrayString7(s)), DataUnit64bit::ArrayString7(b) if b == s
+        ]];
+
+        let d = rust_decimal::Decimal::from_str("3.1415").unwrap();
+        assert![
+            matches![roundtrip(DataUnit128bit::Decimal(d)), DataUnit128bit::Decimal(b) if b == d]
+        ];
+
+        let i = num_bigint::BigInt::from(-12345678901234567890_i128);
+        assert![
+            matches![roundtrip(DataUnit256bit::BigInt(i.clone())), DataUnit256bit::BigInt(b) if b == i]
+        ];
+    }
+}
+
+mod encoding {
+    use crate::all::*;
+    use crate::error::LadataError;
+
+    /// Pins the discriminants, which must never change for the encoding to be stable.
+    #[test]
+    fn discriminants_are_stable() {
+        use DataType8bitCopy as T8;
+        assert_eq![0, T8::None.discriminant()];
+        assert_eq![2, T8::U8.discriminant()];
+        assert_eq![3, T8::I8.discriminant()];
+        assert_eq![4, T8::ByteArray1.discriminant()];
+        assert_eq![5, T8::Bool.discriminant()];
+        assert_eq![6, T8::BitArray8.discriminant()];
+
+        use DataType128bitCopy as T128;
+        assert_eq![0, T128::None.discriminant()];
+        assert_eq![2, T128::U8.discriminant()];
+        assert_eq![7, T128::U16.discriminant()];
+        assert_eq![11, T128::U32.discriminant()];
+        assert_eq![16, T128::Char.discriminant()];
+        assert_eq![19, T128::F64.discriminant()];
+        assert_eq![22, T128::U128.discriminant()];
+        assert_eq![26, T128::Duration.discriminant()];
+        assert_eq![27, T128::Usize.discriminant()];
+        assert_eq![28, T128::Isize.discriminant()];
+        #[cfg(feature = "half")]
+        assert_eq![29, T128::F16.discriminant()];
+        #[cfg(feature = "time")]
+        assert_eq![33, T128::TDate.discriminant()];
+        #[cfg(feature = "num-rational")]
+        assert_eq![45, T128::R32.discriminant()];
+        #[cfg(feature = "rust_decimal")]
+        assert_eq![59, T128::Decimal.discriminant()];
+
+        use DataType1024bitCopy as T1024;
+        assert_eq![2, T1024::U8.discriminant()];
+        assert_eq![27, T1024::ByteArray32.discriminant()];
+        assert_eq![32, T1024::BitArray1024.discriminant()];
+        assert_eq![33, T1024::Usize.discriminant()];
+
+        for d in 0..=u8::MAX {
+            if let Some(t) = T1024::from_discriminant(d) {
+                assert_eq![d, t.discriminant()];
+            }
+        }
+        assert![T1024::from_discriminant(1).is_none()]; // With
+    }
+
+    #[test]
+    fn layout() {
+        let mut buf = [0xff; 8];
+        assert_eq![
+            Ok(5),
+            DataUnit32bitCopy::U32(0x0403_0201).encode_into(&mut buf)
+        ];
+        assert_eq![[11, 1, 2, 3, 4, 0xff], buf[..6]];
+
+        assert_eq![Ok(2), DataUnit32bitCopy::Bool(true).encode_into(&mut buf)];
+        assert_eq![[5, 1], buf[..2]];
+
+        // zero-padded up to the data size
+        let mut buf = [0xff; 20];
+        let d = core::time::Duration::new(1, 2);
+        assert_eq![
+            Ok(17),
+            DataUnit128bitCopy::Duration(d).encode_into(&mut buf)
+        ];
+        assert_eq![
+            [26, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0xff],
+            buf[..18]
+        ];
+
+        assert_eq![Ok(1), DataUnit32bitCopy::None.encode_into(&mut buf)];
+        assert_eq![0, buf[0]];
+
+        // pointer-sized integers always take 8 bytes
+        let mut buf = [0xff; 10];
+        let written = DataUnit1024bitCopy::Usize(0x0102).encode_into(&mut buf);
+        assert_eq![Ok(9), written];
+        assert_eq![[2, 1, 0, 0, 0, 0, 0, 0, 0xff], buf[1..]];
+        let written = DataUnit1024bitCopy::Isize(-2).encode_into(&mut buf);
+        assert_eq![Ok(9), written];
+        assert_eq![
+            [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+            buf[1..]
+        ];
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut buf = [0; 136];
+        let units = [
+            DataUnit1024bitCopy::I8(-8),
+            DataUnit1024bitCopy::Char('π'),
+            DataUnit1024bitCopy::F64(-1.5),
+            DataUnit1024bitCopy::I128(i128::MIN),
+            DataUnit1024bitCopy::Usize(usize::MAX),
+            DataUnit1024bitCopy::ByteArray128([7; 128]),
+            DataUnit1024bitCopy::Duration(core::time::Duration::new(3, 999_999_999)),
+            DataUnit1024bitCopy::BitArray16(BitArray::new(true).unwrap()),
+        ];
+        for unit in units {
+            let written = unit.encode_into(&mut buf).unwrap();
+            let (back, read) = DataUnit1024bitCopy::decode(&buf).unwrap();
+            assert_eq![written, read];
+            assert_eq![format!["{unit:?}"], format!["{back:?}"]];
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let mut buf = [0; 4];
+        assert_eq![
+            Err(LadataError::NotEnoughSpace(Some(5))),
+            DataUnit32bitCopy::I32(1).encode_into(&mut buf)
+        ];
+        assert_eq![
+            Err(LadataError::NotEnoughSpace(Some(1))),
+            DataUnit8bitCopy::None.encode_into(&mut [])
+        ];
+        assert_eq![
+            LadataError::NotEnoughSpace(Some(1)),
+            DataUnit8bitCopy::decode(&[]).unwrap_err()
+        ];
+        assert_eq![
+            LadataError::NotEnoughSpace(Some(5)),
+            DataUnit32bitCopy::decode(&[11, 0, 0]).unwrap_err()
+        ];
+        // unknown discriminant, With, invalid bool & char
+        assert_eq![
+            LadataError::InvalidEncoding,
+            DataUnit8bitCopy::decode(&[200, 0]).unwrap_err()
+        ];
+        assert_eq![
+            LadataError::InvalidEncoding,
+            DataUnit8bitCopy::decode(&[1, 0]).unwrap_err()
+        ];
+        assert_eq![
+            LadataError::InvalidEncoding,
+            DataUnit8bitCopy::decode(&[5, 2]).unwrap_err()
+        ];
+        assert_eq![
+            LadataError::InvalidEncoding,
+            DataUnit32bitCopy::decode(&[16, 0, 0xd8, 0, 0]).unwrap_err()
+        ];
+    }
+
+    #[test]
+    #[cfg(feature = "deps_all")]
+    fn roundtrip_dependencies() {
+        let date = time::Date::from_calendar_date(2023, time::Month::July, 14).unwrap();
+        let t = time::Time::from_hms_milli(23, 59, 59, 999).unwrap();
+        let offset = time::UtcOffset::from_hms(-3, -30, 0).unwrap();
+        let mut buf = [0; 136];
+        let units = [
+            DataUnit1024bitCopy::F16(half::f16::from_f32(0.5)),
+            DataUnit1024bitCopy::R64(num_rational::Ratio::new(-3, 4)),
+            DataUnit1024bitCopy::Decimal(rust_decimal::Decimal::new(-31415, 4)),
+            DataUnit1024bitCopy::ArrayString7(
+                arraystring::ArrayString::try_from_str("ladata").unwrap(),
+            ),
+            DataUnit1024bitCopy::FugitDuration32Millis(
+                fugit::Duration::<u32, 1, 1_000>::from_ticks(9),
+            ),
+            DataUnit1024bitCopy::TDate(date),
+            DataUnit1024bitCopy::TTime(t),
+            DataUnit1024bitCopy::TDuration(time::Duration::new(-5, -300)),
+            DataUnit1024bitCopy::TOffsetDateTime(
+                time::PrimitiveDateTime::new(date, t).assume_offset(offset),
+            ),
+            DataUnit1024bitCopy::F128(twofloat::TwoFloat::from(1.25)),
+        ];
+        for unit in units {
+            let written = unit.encode_into(&mut buf).unwrap();
+            let (back, read) = DataUnit1024bitCopy::decode(&buf).unwrap();
+            assert_eq![written, read];
+            assert_eq![format!["{unit:?}"], format!["{back:?}"]];
+        }
+        let now = DataUnit1024bitCopy::Instant(std::time::Instant::now());
+        assert_eq![Err(LadataError::InvalidEncoding), now.encode_into(&mut buf)];
+    }
+}
+
+#[cfg(feature = "unsafe_unit")]
+mod tagged {
+    use crate::all::*;
+    use crate::error::LadataError;
+
+    #[test]
+    fn roundtrip() {
+        let units = [
+            DataUnit64bitCopy::None,
+            DataUnit64bitCopy::With(()),
+            DataUnit64bitCopy::I8(-3),
+            DataUnit64bitCopy::Char('x'),
+            DataUnit64bitCopy::F64(2.5),
+            DataUnit64bitCopy::Usize(7),
+        ];
+        for unit in units {
+            let back = DataUnit64bitCopy::from(Tagged64bit::from(unit));
+            assert_eq![format!["{unit:?}"], format!["{back:?}"]];
+        }
+    }
+
+    #[test]
+    fn get() {
+        let t = Tagged64bit::new(DataUnit64bitCopy::U32(42));
+        assert![matches![t.data_type(), DataType64bitCopy::U32]];
+        assert_eq![Some(42_u32), t.get()];
+        assert_eq![None, t.get::<i32>()];
+        assert_eq![None, t.get::<f64>()];
+        assert_eq![
+            Some(5_usize),
+            Tagged64bit::new(DataUnit64bitCopy::Usize(5)).get()
+        ];
+        assert_eq![None, Tagged8bit::new(DataUnit8bitCopy::None).get::<u8>()];
+    }
+
+    #[test]
+    fn slices() {
+        let tagged = [
+            Tagged32bit::new(DataUnit32bitCopy::Bool(true)),
+            Tagged32bit::new(DataUnit32bitCopy::F32(1.5)),
+            Tagged32bit::new(DataUnit32bitCopy::None),
+        ];
+        let mut raws = [DataRaw32bitCopy { None: () }; 3];
+        let mut tags = [DataType32bitCopy::None; 3];
+        Tagged32bit::split_slice(&tagged, &mut raws, &mut tags).unwrap();
+        assert![matches![tags[1], DataType32bitCopy::F32]];
+
+        let mut joined = [Tagged32bit::new(DataUnit32bitCopy::None); 3];
+        // SAFETY: the raws and tags come from `split_slice`
+        unsafe { Tagged32bit::join_slices_unchecked(&raws, &tags, &mut joined).unwrap() };
+        assert_eq![Some(true), joined[0].get()];
+        assert_eq![Some(1.5_f32), joined[1].get()];
+
+        assert_eq![
+            Err(LadataError::DimensionMismatch),
+            Tagged32bit::split_slice(&tagged, &mut raws[..2], &mut tags)
+        ];
+    }
+}
+
+mod cast {
+    use crate::all::*;
+    use crate::error::LadataError;
+
+    macro_rules! cast {
+        ($unit:expr, $to:expr, $mode:ident) => {
+            $unit.cast($to, CastMode::$mode).map(|u| format!["{u:?}"])
+        };
+    }
+
+    #[test]
+    fn integers() {
+        use DataType64bit as T;
+        let ok = |s: &str| Ok(s.into());
+        // across sizes
+        assert_eq![ok("I64(-3)"), cast![DataUnit8bit::I8(-3), T::I64, Lossless]];
+        assert_eq![
+            ok("U8(200)"),
+            cast![DataUnit64bit::U64(200), DataType8bit::U8, Checked]
+        ];
+        assert_eq![
+            ok("I128(-1)"),
+            cast![DataUnit8bitCopy::I8(-1), DataType128bitCopy::I128, Checked]
+        ];
+
+        // lossless depends on the types, not on the values
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit16bit::U16(1), T::U8, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit8bit::I8(1), T::U64, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit8bit::U8(1), T::I8, Lossless]
+        ];
+        assert_eq![
+            ok("I16(255)"),
+            cast![DataUnit8bit::U8(255), T::I16, Lossless]
+        ];
+
+        assert_eq![
+            Err(LadataError::Overflow),
+            cast![DataUnit16bit::U16(256), T::U8, Checked]
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            cast![DataUnit8bit::U8(128), T::I8, Checked]
+        ];
+        assert_eq![
+            Err(LadataError::Underflow),
+            cast![DataUnit8bit::I8(-1), T::U32, Checked]
+        ];
+        assert_eq![
+            Err(LadataError::Underflow),
+            cast![DataUnit16bit::I16(-129), T::I8, Checked]
+        ];
+
+        assert_eq![
+            ok("U8(255)"),
+            cast![DataUnit16bit::U16(256), T::U8, Saturating]
+        ];
+        assert_eq![
+            ok("U32(0)"),
+            cast![DataUnit8bit::I8(-1), T::U32, Saturating]
+        ];
+        assert_eq![
+            ok("I8(-128)"),
+            cast![DataUnit16bit::I16(-300), T::I8, Saturating]
+        ];
+        assert_eq![
+            ok("I8(127)"),
+            cast![DataUnit64bit::U64(u64::MAX), T::I8, Saturating]
+        ];
+
+        assert_eq![ok("U8(0)"), cast![DataUnit16bit::U16(256), T::U8, Wrapping]];
+        assert_eq![
+            ok("U32(4294967295)"),
+            cast![DataUnit8bit::I8(-1), T::U32, Wrapping]
+        ];
+        assert_eq![
+            ok("I8(-128)"),
+            cast![DataUnit8bit::U8(128), T::I8, Wrapping]
+        ];
+        assert_eq![
+            ok("I8(44)"),
+            cast![DataUnit16bit::I16(300), T::I8, Wrapping]
+        ];
+    }
+
+    #[test]
+    fn floats() {
+        use DataType64bit as T;
+        let ok = |s: &str| Ok(s.into());
+        assert_eq![
+            ok("F64(1.5)"),
+            cast![DataUnit32bit::F32(1.5), T::F64, Lossless]
+        ];
+        assert_eq![
+            ok("F64(65535.0)"),
+            cast![DataUnit16bit::U16(u16::MAX), T::F64, Lossless]
+        ];
+        assert_eq![
+            ok("F32(-32768.0)"),
+            cast![DataUnit16bit::I16(i16::MIN), T::F32, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit64bit::F64(1.5), T::F32, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit32bit::U32(1), T::F32, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit32bit::F32(1.0), T::I64, Lossless]
+        ];
+
+        // float to integer truncates towards zero
+        assert_eq![
+            ok("I8(-2)"),
+            cast![DataUnit32bit::F32(-2.9), T::I8, Checked]
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            cast![DataUnit32bit::F32(300.0), T::U8, Checked]
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            cast![DataUnit32bit::F32(f32::NAN), T::U8, Checked]
+        ];
+        assert_eq![
+            Err(LadataError::Underflow),
+            cast![DataUnit64bit::F64(-1e39), T::I64, Checked]
+        ];
+        assert_eq![
+            ok("U8(255)"),
+            cast![DataUnit32bit::F32(300.0), T::U8, Saturating]
+        ];
+        assert_eq![
+            ok("U8(0)"),
+            cast![DataUnit32bit::F32(f32::NAN), T::U8, Saturating]
+        ];
+        // wrapping floats saturate like `as`
+        assert_eq![
+            ok("U8(255)"),
+            cast![DataUnit32bit::F32(300.0), T::U8, Wrapping]
+        ];
+        assert_eq![
+            ok("U8(0)"),
+            cast![DataUnit32bit::F32(-1.5), T::U8, Wrapping]
+        ];
+        assert_eq![
+            ok("I8(-128)"),
+            cast![DataUnit64bit::F64(-1e10), T::I8, Wrapping]
+        ];
+        assert_eq![
+            ok("I16(0)"),
+            cast![DataUnit32bit::F32(f32::NAN), T::I16, Wrapping]
+        ];
+        assert_eq![
+            ok("ByteArray2([255, 255])"),
+            cast![DataUnit32bit::F32(1e6), T::ByteArray2, Wrapping]
+        ];
+
+        // out of range floats
+        assert_eq![
+            Err(LadataError::Overflow),
+            cast![DataUnit64bit::F64(1e300), T::F32, Checked]
+        ];
+        assert_eq![
+            Err(LadataError::Underflow),
+            cast![DataUnit64bit::F64(-1e300), T::F32, Checked]
+        ];
+        assert_eq![
+            ok("F32(-3.4028235e38)"),
+            cast![DataUnit64bit::F64(-1e300), T::F32, Saturating]
+        ];
+        assert_eq![
+            ok("F32(inf)"),
+            cast![DataUnit64bit::F64(1e300), T::F32, Wrapping]
+        ];
+        assert_eq![
+            ok("F32(inf)"),
+            cast![DataUnit64bit::F64(f64::INFINITY), T::F32, Checked]
+        ];
+    }
+
+    #[test]
+    fn bool_and_char() {
+        use DataType32bit as T;
+        let ok = |s: &str| Ok(s.into());
+        assert_eq![
+            ok("U8(1)"),
+            cast![DataUnit8bit::Bool(true), T::U8, Lossless]
+        ];
+        assert_eq![
+            ok("F32(1.0)"),
+            cast![DataUnit8bit::Bool(true), T::F32, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit8bit::U8(1), T::Bool, Lossless]
+        ];
+        assert_eq![
+            ok("Bool(true)"),
+            cast![DataUnit8bit::U8(1), T::Bool, Checked]
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            cast![DataUnit8bit::U8(2), T::Bool, Checked]
+        ];
+        assert_eq![
+            ok("Bool(true)"),
+            cast![DataUnit8bit::U8(2), T::Bool, Saturating]
+        ];
+        assert_eq![
+            ok("Bool(false)"),
+            cast![DataUnit8bit::U8(2), T::Bool, Wrapping]
+        ];
+
+        assert_eq![
+            ok("U32(97)"),
+            cast![DataUnit32bit::Char('a'), T::U32, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit32bit::Char('a'), T::U16, Lossless]
+        ];
+        assert_eq![
+            ok("U16(97)"),
+            cast![DataUnit32bit::Char('a'), T::U16, Checked]
+        ];
+        assert_eq![
+            ok("Char('a')"),
+            cast![DataUnit8bit::U8(97), T::Char, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit16bit::U16(97), T::Char, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            cast![DataUnit32bit::U32(0xD800), T::Char, Checked]
+        ];
+        assert_eq![
+            Err(LadataError::Underflow),
+            cast![DataUnit8bit::I8(-1), T::Char, Checked]
+        ];
+        assert_eq![
+            ok("Char('\\u{10ffff}')"),
+            cast![DataUnit32bit::U32(u32::MAX), T::Char, Saturating]
+        ];
+        assert_eq![
+            ok("Char('�')"),
+            cast![DataUnit32bit::U32(0xD800), T::Char, Wrapping]
+        ];
+        assert_eq![
+            ok("Char('a')"),
+            cast![DataUnit32bit::U32(0x110061), T::Char, Wrapping]
+        ];
+    }
+
+    #[test]
+    fn arrays() {
+        use DataType64bit as T;
+        let ok = |s: &str| Ok(s.into());
+        // byte arrays are little-endian unsigned integers
+        assert_eq![
+            ok("U16(513)"),
+            cast![DataUnit16bit::ByteArray2([1, 2]), T::U16, Lossless]
+        ];
+        assert_eq![
+            ok("ByteArray4([1, 2, 0, 0])"),
+            cast![DataUnit16bit::U16(513), T::ByteArray4, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit16bit::I16(1), T::ByteArray2, Lossless]
+        ];
+        assert_eq![
+            Err(LadataError::Underflow),
+            cast![DataUnit16bit::I16(-1), T::ByteArray2, Checked]
+        ];
+        assert_eq![
+            ok("ByteArray2([255, 255])"),
+            cast![DataUnit16bit::I16(-1), T::ByteArray2, Wrapping]
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            cast![DataUnit16bit::ByteArray2([1, 2]), T::ByteArray1, Checked]
+        ];
+        assert_eq![
+            ok("ByteArray1([255])"),
+            cast![DataUnit16bit::ByteArray2([1, 2]), T::ByteArray1, Saturating]
+        ];
+        assert_eq![
+            ok("ByteArray1([1])"),
+            cast![DataUnit16bit::ByteArray2([1, 2]), T::ByteArray1, Wrapping]
+        ];
+        assert_eq![
+            ok("ByteArray1([1])"),
+            cast![DataUnit16bit::ByteArray2([1, 0]), T::ByteArray1, Checked]
+        ];
+
+        // wider than an u128
+        let mut bytes = [0; 32];
+        bytes[20] = 1;
+        let big = DataUnit256bit::ByteArray32(bytes);
+        assert_eq![Err(LadataError::Overflow), cast![big, T::U64, Checked]];
+        assert_eq![
+            ok("U64(18446744073709551615)"),
+            cast![big, T::U64, Saturating]
+        ];
+        assert_eq![ok("U64(0)"), cast![big, T::U64, Wrapping]];
+        assert_eq![ok("F64(1.461501637330903e48)"), cast![big, T::F64, Checked]];
+        assert_eq![
+            ok(
+                "ByteArray32([255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, \
+            255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, \
+            255, 255, 255])"
+            ),
+            cast![DataUnit8bit::I8(-1), DataType256bit::ByteArray32, Wrapping]
+        ];
+
+        // bit arrays
+        let bits = DataUnit64bit::U8(0b101)
+            .cast(T::BitArray8, CastMode::Lossless)
+            .unwrap();
+        if let DataUnit64bit::BitArray8(b) = bits {
+            assert_eq![[0b101], b.as_byte_slice()];
+        } else {
+            panic!["{bits:?}"];
+        }
+        assert_eq![ok("U16(5)"), cast![bits, T::U16, Lossless]];
+        assert_eq![ok("ByteArray1([5])"), cast![bits, T::ByteArray1, Lossless]];
+    }
+
+    #[test]
+    fn uncastable() {
+        use core::time::Duration;
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit8bit::None, DataType64bit::U8, Wrapping]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![
+                DataUnit128bit::Duration(Duration::ZERO),
+                DataType64bit::U64,
+                Wrapping
+            ]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit64bit::U64(0), DataType128bit::Duration, Wrapping]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit64bit::U64(0), DataType64bit::None, Wrapping]
+        ];
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn half() {
+        use DataType32bit as T;
+        let ok = |s: &str| Ok(s.into());
+        assert_eq![
+            ok("F32(1.5)"),
+            cast![
+                DataUnit16bit::F16(half::f16::from_f32(1.5)),
+                T::F32,
+                Lossless
+            ]
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            cast![DataUnit16bit::BF16(half::bf16::ONE), T::F16, Lossless]
+        ];
+        assert_eq![
+            ok("F16(2048.0)"),
+            cast![DataUnit8bit::U8(0), T::F16, Lossless].and(cast![
+                DataUnit16bit::U16(2048),
+                T::F16,
+                Checked
+            ])
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            cast![DataUnit32bit::U32(70000), T::F16, Checked]
+        ];
+        assert_eq![
+            ok("F16(65504.0)"),
+            cast![DataUnit32bit::U32(70000), T::F16, Saturating]
+        ];
+    }
+}
+
+mod text {
+    use crate::all::*;
+    use crate::error::LadataError;
+
+    /// Parses `text` as `t`, and checks it's displayed back the same.
+    fn roundtrip(t: DataType1024bit, text: &str) {
+        let unit = t
+            .parse(text)
+            .unwrap_or_else(|e| panic!["{t:?} {text:?}: {e}"]);
+        assert_eq![text, unit.to_string()];
+    }
+
+    #[test]
+    fn parse() {
+        use DataType64bit as T;
+        assert![matches![T::U8.parse("255"), Ok(DataUnit64bit::U8(255))]];
+        assert![matches![T::I64.parse("-9"), Ok(DataUnit64bit::I64(-9))]];
+        assert![matches![T::F32.parse("1e3"), Ok(DataUnit64bit::F32(f)) if f == 1000.0]];
+        assert![matches![
+            T::Bool.parse("false"),
+            Ok(DataUnit64bit::Bool(false))
+        ]];
+        assert![matches![
+            T::ByteArray2.parse("0aFF"),
+            Ok(DataUnit64bit::ByteArray2([10, 255]))
+        ]];
+        assert![matches![T::None.parse(""), Ok(DataUnit64bit::None)]];
+        assert![matches![
+            DataType64bitCopy::Char.parse("€"),
+            Ok(DataUnit64bitCopy::Char('€'))
+        ]];
+
+        let bits = T::BitArray16.parse("1_0000_0001").unwrap();
+        assert_eq!["00000001_00000001", bits.to_string()];
+    }
+
+    #[test]
+    fn errors() {
+        use DataType64bit as T;
+        for (t, text) in [
+            (T::U8, "256"),
+            (T::U8, ""),
+            (T::Bool, "1"),
+            (T::Char, "ab"),
+            (T::Char, ""),
+            (T::ByteArray2, "0a"),
+            (T::ByteArray1, "0g"),
+            (T::BitArray8, "2"),
+            (T::BitArray8, "101010101"),
+            (T::BitArray8, ""),
+            (T::None, "x"),
+        ] {
+            assert_eq![LadataError::InvalidText, t.parse(text).unwrap_err()];
+        }
+    }
+
+    #[test]
+    fn roundtrip_all() {
+        use DataType1024bit as T;
+        roundtrip(T::None, "");
+        roundtrip(T::U128, "340282366920938463463374607431768211455");
+        roundtrip(T::I16, "-32768");
+        roundtrip(T::F64, "0.1");
+        roundtrip(T::F64, "-inf");
+        roundtrip(T::F32, "NaN");
+        roundtrip(T::Bool, "true");
+        roundtrip(T::Char, "\n");
+        roundtrip(T::ByteArray4, "00ff10a0");
+        roundtrip(T::BitArray8, "10000001");
+        roundtrip(T::Duration, "0");
+        roundtrip(T::Duration, "3.000000001");
+        roundtrip(T::Duration, "3.5");
+        #[cfg(feature = "std")]
+        roundtrip(T::SystemTime, "-1.25");
+        assert_eq![
+            LadataError::InvalidText,
+            T::Duration.parse("-1").unwrap_err()
+        ];
+    }
+
+    #[test]
+    #[cfg(feature = "deps_all")]
+    fn roundtrip_dependencies() {
+        use DataType1024bit as T;
+        roundtrip(T::F16, "1.5");
+        roundtrip(T::BF16, "-2");
+        roundtrip(T::F128, "1 + 0.00000000000000001");
+        roundtrip(T::F128, "1 - 0.00000000000000001");
+        roundtrip(T::R32, "-3/4");
+        roundtrip(T::R64, "7");
+        roundtrip(T::Decimal, "-1.2500");
+        roundtrip(
+            T::BigInt,
+            "-123456789012345678901234567890123456789012345678901234567890",
+        );
+        roundtrip(T::ArrayString31, "ñandú");
+        roundtrip(T::FugitInstant64Millis, "1693310709123");
+        roundtrip(T::TDate, "2023-08-29");
+        roundtrip(T::TDate, "-0044-03-15");
+        roundtrip(T::TTime, "09:05:00.5");
+        roundtrip(T::TTime, "23:59:59");
+        roundtrip(T::TUtcOffset, "-02:30");
+        roundtrip(T::TUtcOffset, "+01:00:01");
+        roundtrip(T::TDuration, "-1.000000001");
+        roundtrip(T::TDateTime, "2023-08-29T00:00:00");
+        roundtrip(T::TOffsetDateTime, "2023-08-29T13:05:09.123+02:00");
+        roundtrip(T::TOffsetDateTime, "2023-08-29T13:05:09-02:00");
+        assert_eq![
+            LadataError::InvalidText,
+            T::TDate.parse("2023-02-30").unwrap_err()
+        ];
+    }
+}
+
+mod order {
+    use crate::all::*;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn eq() {
+        assert_eq![DataUnit32bitCopy::I8(3), DataUnit32bitCopy::I8(3)];
+        assert_ne![DataUnit32bitCopy::I8(3), DataUnit32bitCopy::U8(3)];
+        assert_eq![DataUnit32bit::F32(f32::NAN), DataUnit32bit::F32(f32::NAN)];
+        assert_ne![DataUnit32bit::F32(0.0), DataUnit32bit::F32(-0.0)];
+        assert_eq![DataUnit8bit::None, DataUnit8bit::None];
+    }
+
+    #[test]
+    fn ord() {
+        use DataUnit64bitCopy as U;
+        // by discriminant first
+        assert![U::None < U::U8(0)];
+        assert![U::U8(200) < U::I8(-100)];
+        assert![U::I8(100) < U::Bool(false)];
+        // then by value
+        assert![U::I8(-100) < U::I8(1)];
+        assert![U::F64(f64::NEG_INFINITY) < U::F64(-0.0)];
+        assert![U::F64(-0.0) < U::F64(0.0)];
+        assert![U::F64(f64::INFINITY) < U::F64(f64::NAN)];
+
+        let mut units = [U::I8(2), U::U8(5), U::F32(0.5), U::I8(-2), U::U8(1)];
+        units.sort();
+        assert_eq![
+            [U::U8(1), U::U8(5), U::I8(-2), U::I8(2), U::F32(0.5)],
+            units
+        ];
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn collections() {
+        use std::collections::{BTreeMap, HashSet};
+        use DataUnit256bit as U;
+
+        let mut set = HashSet::new();
+        assert![set.insert(U::F64(f64::NAN))];
+        assert![!set.insert(U::F64(f64::NAN))];
+        assert![set.insert(U::F32(f32::NAN))];
+        assert![set.insert(U::String("a".into()))];
+        assert![!set.insert(U::String("a".into()))];
+        assert_eq![3, set.len()];
+
+        let mut map = BTreeMap::new();
+        map.insert(U::String("b".into()), 1);
+        map.insert(U::U128(7), 2);
+        map.insert(U::String("a".into()), 3);
+        let values: Vec<_> = map.into_values().collect();
+        assert_eq![vec![2, 3, 1], values];
+    }
+
+    #[test]
+    fn numeric() {
+        use DataUnit64bit as U;
+        assert![U::I8(3).numeric_eq(&U::U64(3))];
+        assert![U::F32(3.0).numeric_eq(&U::U64(3))];
+        assert![!U::F32(3.5).numeric_eq(&U::U64(3))];
+        assert_eq![Some(Ordering::Greater), U::F32(3.5).numeric_cmp(&U::U64(3))];
+        assert_eq![Some(Ordering::Less), U::F32(-3.5).numeric_cmp(&U::I64(-3))];
+        assert_eq![
+            Some(Ordering::Less),
+            U::I64(-1).numeric_cmp(&U::U64(u64::MAX))
+        ];
+        assert_eq![
+            Some(Ordering::Less),
+            U::U64(u64::MAX).numeric_cmp(&U::F32(f32::INFINITY))
+        ];
+        assert_eq![
+            Some(Ordering::Less),
+            U::I64(i64::MAX).numeric_cmp(&U::F64(i64::MAX as f64))
+        ];
+        assert_eq![None, U::F64(f64::NAN).numeric_cmp(&U::F64(f64::NAN))];
+        assert_eq![None, U::Char('3').numeric_cmp(&U::U8(3))];
+    }
+}
+
+mod arith {
+    use crate::all::*;
+    use crate::error::LadataError;
+
+    #[test]
+    fn same_type() {
+        use DataUnit32bitCopy as U;
+        assert_eq![Ok(U::I8(-128)), U::I8(-100).checked_sub(&U::I8(28))];
+        assert_eq![
+            Err(LadataError::Underflow),
+            U::I8(-100).checked_sub(&U::I8(29))
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            U::I8(-128).checked_div(&U::I8(-1))
+        ];
+        assert_eq![Ok(U::I8(0)), U::I8(-128).checked_rem(&U::I8(-1))];
+        assert_eq![
+            Err(LadataError::Underflow),
+            U::I16(200).checked_mul(&U::I16(-200))
+        ];
+        assert_eq![
+            Err(LadataError::Underflow),
+            U::U32(2).checked_sub(&U::U32(3))
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            U::U32(u32::MAX).checked_mul(&U::U32(2))
+        ];
+        assert_eq![Ok(U::U16(3)), U::U16(7).checked_div(&U::U16(2))];
+        assert_eq![Ok(U::I32(-1)), U::I32(-7).checked_rem(&U::I32(2))];
+        assert_eq![
+            Err(LadataError::DivisionByZero),
+            U::U8(1).checked_rem(&U::U8(0))
+        ];
+
+        assert_eq![Ok(U::F32(-0.5)), U::F32(1.0).checked_div(&U::F32(-2.0))];
+        assert_eq![
+            Err(LadataError::DivisionByZero),
+            U::F32(1.0).checked_div(&U::F32(-0.0))
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            U::F32(f32::MAX).checked_add(&U::F32(f32::MAX))
+        ];
+        assert_eq![
+            Ok(U::F32(f32::INFINITY)),
+            U::F32(f32::INFINITY).checked_add(&U::F32(1.0))
+        ];
+    }
+
+    #[test]
+    fn neg() {
+        use DataUnit128bitCopy as U;
+        assert_eq![Ok(U::I8(-127)), U::I8(127).checked_neg()];
+        assert_eq![Err(LadataError::Overflow), U::I8(-128).checked_neg()];
+        assert_eq![Err(LadataError::Overflow), U::I128(i128::MIN).checked_neg()];
+        assert_eq![Ok(U::U16(0)), U::U16(0).checked_neg()];
+        assert_eq![Err(LadataError::Underflow), U::U16(1).checked_neg()];
+        assert_eq![Ok(U::F64(-1.5)), U::F64(1.5).checked_neg()];
+        assert_eq![Err(LadataError::TypeMismatch), U::Char('a').checked_neg()];
+    }
+
+    #[test]
+    fn promotion() {
+        use DataUnit128bitCopy as U;
+        // integers
+        assert_eq![Ok(U::U32(70_000)), U::U8(1).checked_add(&U::U32(69_999))];
+        assert_eq![Ok(U::I16(-200)), U::U8(200).checked_mul(&U::I8(-1))];
+        assert_eq![Ok(U::I32(-1)), U::U16(1).checked_sub(&U::I8(2))];
+        assert_eq![Ok(U::I128(-1)), U::U64(1).checked_sub(&U::I8(2))];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            U::U128(1).checked_sub(&U::I8(2))
+        ];
+        #[cfg(target_pointer_width = "64")]
+        assert_eq![Ok(U::U64(3)), U::Usize(1).checked_add(&U::U8(2))];
+        assert_eq![Ok(U::Usize(3)), U::Usize(1).checked_add(&U::Usize(2))];
+
+        // integers with floats
+        assert_eq![Ok(U::F32(2.5)), U::U8(2).checked_add(&U::F32(0.5))];
+        assert_eq![Ok(U::F64(2.5)), U::I32(2).checked_add(&U::F32(0.5))];
+        assert_eq![Ok(U::F64(2.5)), U::U128(2).checked_add(&U::F64(0.5))];
+        assert_eq![Ok(U::F64(1.5)), U::F32(1.0).checked_add(&U::F64(0.5))];
+
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            U::Bool(true).checked_add(&U::U8(1))
+        ];
+
+        // the promoted type is not available at this size
+        use DataUnit8bit as U8;
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            U8::U8(1).checked_add(&U8::I8(1))
+        ];
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn half() {
+        use half::{bf16, f16};
+        use DataUnit64bitCopy as U;
+        let (h, b) = (f16::from_f32(1.5), bf16::from_f32(2.0));
+        assert_eq![
+            Ok(U::F16(f16::from_f32(3.0))),
+            U::F16(h).checked_add(&U::F16(h))
+        ];
+        assert_eq![Ok(U::F32(3.5)), U::F16(h).checked_add(&U::BF16(b))];
+        assert_eq![
+            Ok(U::BF16(bf16::from_f32(3.0))),
+            U::BF16(b).checked_add(&U::U8(1))
+        ];
+        assert_eq![Ok(U::F32(258.0)), U::BF16(b).checked_add(&U::U16(256))];
+        assert_eq![
+            Err(LadataError::Overflow),
+            U::F16(f16::MAX).checked_mul(&U::F16(h))
+        ];
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "twofloat"))]
+    fn twofloat() {
+        use twofloat::TwoFloat;
+        use DataUnit128bitCopy as U;
+        let t = TwoFloat::from(1.5);
+        assert_eq![
+            Ok(U::F128(TwoFloat::from(3.0))),
+            U::F128(t).checked_add(&U::F128(t))
+        ];
+        assert_eq![
+            Ok(U::F128(TwoFloat::from(3.5))),
+            U::F128(t).checked_add(&U::I8(2))
+        ];
+        assert_eq![
+            Ok(U::F128(TwoFloat::from(2.0))),
+            U::F32(0.5).checked_add(&U::F128(t))
+        ];
+    }
+
+    #[test]
+    #[cfg(feature = "rust_decimal")]
+    fn decimal() {
+        use rust_decimal::Decimal;
+        use DataUnit128bitCopy as U;
+        let d = Decimal::new(15, 1);
+        assert_eq![
+            Ok(U::Decimal(Decimal::new(35, 1))),
+            U::Decimal(d).checked_add(&U::I8(2))
+        ];
+        assert_eq![
+            Ok(U::Decimal(Decimal::new(3, 0))),
+            U::U16(2).checked_mul(&U::Decimal(d))
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            U::Decimal(d).checked_add(&U::F32(1.0))
+        ];
+        assert_eq![
+            Err(LadataError::DivisionByZero),
+            U::Decimal(d).checked_div(&U::Decimal(Decimal::ZERO))
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            U::Decimal(Decimal::MAX).checked_add(&U::U8(1))
+        ];
+        assert_eq![
+            Err(LadataError::Overflow),
+            U::U128(u128::MAX).checked_add(&U::Decimal(d))
+        ];
+    }
+}
+
+mod link {
+    use crate::all::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Custom(u8);
+    impl DataType for Custom {
+        fn data_align(&self) -> usize {
+            1
+        }
+        fn data_size(&self) -> usize {
+            1
+        }
+        fn is_copy(&self) -> bool {
+            true
+        }
+    }
+    impl DataUnit for Custom {
+        type Type = Custom;
+
+        fn is_copy(&self) -> bool {
+            true
+        }
+        fn unit_type(&self) -> Custom {
+            Custom(self.0 * 2)
+        }
+    }
+    impl DataTypeCopy for Custom {}
+    impl DataUnitCopy for Custom {}
+
+    #[test]
+    fn data_type() {
+        use {DataType64bitCopyWith as T, DataUnit64bitCopyWith as U};
+        assert_eq![T::<Custom>::F64, U::<Custom>::F64(1.5).data_type()];
+        assert_eq![T::<Custom>::None, U::<Custom>::None.data_type()];
+        assert_eq![T::With(Custom(6)), U::With(Custom(3)).data_type()];
+        assert_eq![T::With(Custom(6)), U::With(Custom(3)).unit_type()];
+
+        let units = [DataUnit32bit::Char('a'), DataUnit32bit::ByteArray4([1; 4])];
+        for unit in &units {
+            assert![unit.data_type().accepts(unit)];
+        }
+    }
+
+    #[test]
+    fn default_unit() {
+        use {DataType64bit as T, DataUnit64bit as U};
+        assert![matches![T::I64.default_unit(), U::I64(0)]];
+        assert![matches![T::F32.default_unit(), U::F32(f) if f == 0.0]];
+        assert![matches![T::Char.default_unit(), U::Char('\0')]];
+        assert![matches![T::ByteArray8.default_unit(), U::ByteArray8(a) if a == [0; 8]]];
+        assert![matches![T::With(()).default_unit(), U::None]];
+        assert_eq![
+            "00000000",
+            DataType8bitCopy::BitArray8.default_unit().to_string()
+        ];
+
+        // the default unit is always accepted by its type
+        for t in [T::U8, T::Bool, T::U64, T::Usize, T::BitArray64] {
+            assert![t.accepts(&t.default_unit())];
+            assert_eq![t, t.default_unit().data_type()];
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn default_unit_std() {
+        use {DataType1024bit as T, DataUnit1024bit as U};
+        assert![matches![T::String.default_unit(), U::String(s) if s.is_empty()]];
+        assert![matches![T::Duration.default_unit(), U::Duration(d) if d.is_zero()]];
+        assert![matches![
+            T::SystemTime.default_unit(),
+            U::SystemTime(t) if t == std::time::UNIX_EPOCH
+        ]];
+        assert![matches![T::Instant.default_unit(), U::None]];
+    }
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn default_unit_time() {
+        use time::OffsetDateTime;
+        use {DataType128bitCopy as T, DataUnit128bitCopy as U};
+        let epoch = OffsetDateTime::UNIX_EPOCH;
+        assert![matches![T::TDate.default_unit(), U::TDate(d) if d == epoch.date()]];
+        assert![matches![T::TOffsetDateTime.default_unit(), U::TOffsetDateTime(d) if d == epoch]];
+        assert![matches![T::TDuration.default_unit(), U::TDuration(d) if d.is_zero()]];
+    }
+}
+
+mod resize {
+    use crate::all::*;
+    use crate::error::LadataError;
+
+    #[test]
+    fn units() {
+        let u = DataUnit8bitCopy::I8(-5);
+        let big = DataUnit1024bitCopy::from(u);
+        assert_eq![DataUnit1024bitCopy::I8(-5), big];
+        assert_eq![Ok(u), DataUnit8bitCopy::try_from(big)];
+        assert_eq![
+            DataUnit64bitCopy::Char('z'),
+            DataUnit32bitCopy::Char('z').into()
+        ];
+
+        // the variant doesn't exist at the smaller size
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            DataUnit16bitCopy::try_from(DataUnit32bitCopy::U32(1))
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            DataUnit64bitCopy::try_from(DataUnit128bitCopy::ByteArray16([0; 16]))
+        ];
+
+        // the custom data is moved too
+        let with = DataUnit16bitCopyWith::With(DataUnit8bitCopy::U8(3));
+        assert_eq![
+            DataUnit128bitCopyWith::With(DataUnit8bitCopy::U8(3)),
+            DataUnit128bitCopyWith::from(with)
+        ];
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn noncopy_units() {
+        let s = DataUnit256bit::String("moved".into());
+        let big = DataUnit1024bit::from(s);
+        assert_eq![DataUnit1024bit::String("moved".into()), big];
+        assert_eq![
+            Ok(DataUnit256bit::String("moved".into())),
+            DataUnit256bit::try_from(big)
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            DataUnit64bit::try_from(DataUnit256bit::String("".into()))
+        ];
+    }
+
+    #[test]
+    fn types() {
+        assert_eq![DataType512bitCopy::Bool, DataType8bitCopy::Bool.into()];
+        assert_eq![
+            DataType64bit::Usize,
+            DataType64bit::try_from(DataType1024bit::Usize).unwrap()
+        ];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            DataType8bit::try_from(DataType16bit::U16)
+        ];
+        assert_eq![
+            Ok(DataType8bitWith::With(DataType8bit::U8)),
+            DataType8bitWith::try_from(DataType64bitWith::With(DataType8bit::U8))
+        ];
+
+        // the promoted type and unit still agree
+        let u = DataUnit16bitCopy::U16(9);
+        let t = DataType128bitCopy::from(u.data_type());
+        assert![t.accepts(&DataUnit128bitCopy::from(u))];
+    }
+
+    #[test]
+    #[cfg(feature = "unsafe_unit")]
+    fn raw_and_tagged() {
+        let raw = DataRaw8bitCopy { I8: -7 };
+        let big = DataRaw256bitCopy::from(raw);
+        assert_eq![-7, unsafe { big.I8 }];
+        let raw = DataRaw32bitCopy { Char: 'ñ' };
+        assert_eq!['ñ', unsafe { DataRaw64bitCopy::from(raw).Char }];
+
+        let tagged = Tagged16bit::new(DataUnit16bitCopy::I16(-300));
+        let big = Tagged128bit::from(tagged);
+        assert_eq![Some(-300_i16), big.get()];
+        assert_eq![Some(-300_i16), Tagged16bit::try_from(big).unwrap().get()];
+        assert_eq![
+            Err(LadataError::TypeMismatch),
+            Tagged8bit::try_from(big).map(|_| ())
+        ];
+    }
+}
+
+mod registry {
+    use crate::all::*;
+    use crate::error::{LadataError, LadataResult};
+    use core::fmt;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Sensor {
+        Temp(i16),
+        Flag(bool),
+    }
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum SensorType {
+        Temp,
+        Flag,
+    }
+    impl DataType for SensorType {
+        fn data_align(&self) -> usize {
+            2
+        }
+        fn data_size(&self) -> usize {
+            2
+        }
+        fn is_copy(&self) -> bool {
+            true
+        }
+    }
+    impl DataUnit for Sensor {
+        type Type = SensorType;
+
+        fn is_copy(&self) -> bool {
+            true
+        }
+        fn unit_type(&self) -> SensorType {
+            match self {
+                Sensor::Temp(_) => SensorType::Temp,
+                Sensor::Flag(_) => SensorType::Flag,
+            }
+        }
+    }
+    impl DataTypeCopy for SensorType {}
+    impl DataUnitCopy for Sensor {}
+    impl DataUnitPayload for Sensor {
+        fn encode_payload(&self, bytes: &mut [u8]) -> LadataResult<usize> {
+            match self {
+                Sensor::Temp(t) if bytes.len() >= 2 => {
+                    bytes[..2].copy_from_slice(&t.to_le_bytes());
+                    Ok(2)
+                }
+                Sensor::Flag(b) if !bytes.is_empty() => {
+                    bytes[0] = *b as u8;
+                    Ok(1)
+                }
+                _ => Err(LadataError::NotEnoughSpace(None)),
+            }
+        }
+        fn decode_payload(data_type: SensorType, bytes: &[u8]) -> LadataResult<(Self, usize)> {
+            match data_type {
+                SensorType::Temp if bytes.len() >= 2 => {
+                    Ok((Sensor::Temp(i16::from_le_bytes([bytes[0], bytes[1]])), 2))
+                }
+                SensorType::Flag if !bytes.is_empty() => Ok((Sensor::Flag(bytes[0] != 0), 1)),
+                _ => Err(LadataError::InvalidEncoding),
+            }
+        }
+        fn fmt_payload(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Sensor::Temp(t) => write!(f, "{t}"),
+                Sensor::Flag(b) => write!(f, "{b}"),
+            }
+        }
+        fn parse_payload(data_type: SensorType, text: &str) -> LadataResult<Self> {
+            match data_type {
+                SensorType::Temp => text.parse().map(Sensor::Temp).ok(),
+                SensorType::Flag => text.parse().map(Sensor::Flag).ok(),
+            }
+            .ok_or(LadataError::InvalidText)
+        }
+    }
+
+    fn registry() -> TypeRegistryArray<'static, SensorType, 2> {
+        let mut r = TypeRegistryArray::new();
+        r.register(10, "temp", SensorType::Temp).unwrap();
+        r.register(11, "flag", SensorType::Flag).unwrap();
+        r
+    }
+
+    #[test]
+    fn lookup() {
+        let r = registry();
+        assert![r.is_full()];
+        assert_eq![
+            Err(LadataError::NotEnoughSpace(Some(1))),
+            r.clone().register(12, "x", SensorType::Temp)
+        ];
+        assert_eq![
+            Some(("flag", SensorType::Flag)),
+            r.registry_lookup_by_id(11)
+        ];
+        assert_eq![
+            Some((10, SensorType::Temp)),
+            r.registry_lookup_by_name("temp")
+        ];
+        assert_eq![
+            Some((11, "flag")),
+            r.registry_lookup_by_type(&SensorType::Flag)
+        ];
+        assert_eq![None, r.registry_lookup_by_id(12)];
+        assert![r.iter().map(|(i, _, _)| i).eq([10, 11])];
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn duplicates() {
+        let mut r = TypeRegistry::new();
+        r.register(1, "temp", SensorType::Temp).unwrap();
+        let err = Err(LadataError::KeyAlreadyExists);
+        assert_eq![err, r.register(1, "flag", SensorType::Flag)];
+        assert_eq![err, r.register(2, "temp", SensorType::Flag)];
+        assert_eq![err, r.register(2, "flag", SensorType::Temp)];
+        assert_eq![Ok(()), r.register(2, "flag", SensorType::Flag)];
+        assert_eq![2, r.len()];
+    }
+
+    #[test]
+    fn encoding() {
+        let r = registry();
+        let mut buf = [0; 16];
+        let u = DataUnit32bitCopyWith::With(Sensor::Temp(-40));
+        let n = u.encode_into_with(&r, &mut buf).unwrap();
+        assert_eq![7, n];
+        assert_eq![[10, 0, 0, 0], buf[1..5]];
+        assert_eq![Ok((u, n)), DataUnit32bitCopyWith::decode_with(&r, &buf)];
+
+        // other variants are encoded as usual
+        let u = DataUnit32bitCopyWith::<Sensor>::U16(300);
+        let n = u.encode_into_with(&r, &mut buf).unwrap();
+        assert_eq![Ok((u, n)), DataUnit32bitCopyWith::decode_with(&r, &buf)];
+
+        // unknown types and ids
+        let empty = TypeRegistryArray::<SensorType, 1>::new();
+        let u = DataUnit32bitCopyWith::With(Sensor::Flag(true));
+        assert_eq![
+            Err(LadataError::KeyNotFound),
+            u.encode_into_with(&empty, &mut buf)
+        ];
+        u.encode_into_with(&r, &mut buf).unwrap();
+        assert_eq![
+            Err(LadataError::KeyNotFound),
+            DataUnit32bitCopyWith::<Sensor>::decode_with(&empty, &buf)
+        ];
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn text() {
+        let r = registry();
+        let u = DataUnit16bitCopyWith::With(Sensor::Flag(true));
+        let s = u.display_with(&r).to_string();
+        assert_eq!["flag:true", s];
+        assert_eq![Ok(u), u.data_type().parse_with(&r, &s)];
+
+        let u = DataUnit16bitWith::With(Sensor::Temp(21));
+        let s = u.display_with(&r).to_string();
+        assert_eq!["temp:21", s];
+        assert![matches![
+            u.data_type().parse_with(&r, &s),
+            Ok(DataUnit16bitWith::With(Sensor::Temp(21)))
+        ]];
+
+        // other variants are written as usual
+        let u = DataUnit16bitCopyWith::<Sensor>::I8(-3);
+        let s = u.display_with(&r).to_string();
+        assert_eq![u.to_string(), s];
+        assert_eq![Ok(u), u.data_type().parse_with(&r, &s)];
+
+        let t = DataType16bitCopyWith::With(SensorType::Temp);
+        assert_eq![
+            Err(LadataError::KeyNotFound),
+            t.parse_with::<Sensor, _>(&r, "heat:21")
+        ];
+        assert_eq![
+            Err(LadataError::InvalidText),
+            t.parse_with::<Sensor, _>(&r, "21")
+        ];
+
+        // an unregistered data type is written as a placeholder
+        let mut r = TypeRegistryArray::<SensorType, 1>::new();
+        r.register(10, "temp", SensorType::Temp).unwrap();
+        let u = DataUnit16bitCopyWith::With(Sensor::Flag(true));
+        let s = u.display_with(&r).to_string();
+        assert_eq!["?", s];
+        assert_eq![
+            Err(LadataError::InvalidText),
+            u.data_type().parse_with::<Sensor, _>(&r, &s)
+        ];
+    }
+}
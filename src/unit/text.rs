@@ -0,0 +1,458 @@
+// ladata::unit::text
+//
+//! Canonical textual representation of the data contained in the units.
+//!
+//! Every variant is formatted and parsed through the [`TextField`] trait,
+//! so that the text written by `Display` can always be read back by `parse`,
+//! without allocating for the `Copy` variants.
+//!
+//! - integers, floats and `bool` use their standard representation.
+//! - a `char` is the character itself.
+//! - byte arrays are written as two lowercase hex digits per byte, in order.
+//! - bit arrays are written as binary digits, the most significant bit first,
+//!   with the bytes separated by `_`, which are ignored when parsing.
+//! - durations are written as seconds, with an optional fraction:
+//!   `1.5`, and system times as the signed duration since the unix epoch.
+//! - `time` types use their ISO 8601 form: `2023-08-29`, `13:05:09.5`,
+//!   `+02:00`, `2023-08-29T13:05:09.5`, `2023-08-29T13:05:09.5+02:00`.
+//! - custom `With` units are written through a `DataTypeRegistry`, as the
+//!   name of their *data type*, followed by `:` and their own text.
+//
+
+use crate::error::{LadataError as Error, LadataResult as Result};
+use core::{fmt, str::FromStr};
+
+/// Formats and parses the data contained in a unit variant.
+pub(crate) trait TextField: Sized {
+    fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result;
+    fn parse_field(text: &str) -> Result<Self>;
+}
+
+/// Displays the output of a formatting closure.
+pub(crate) struct DisplayFn<F: Fn(&mut fmt::Formatter) -> fmt::Result>(pub F);
+
+impl<F: Fn(&mut fmt::Formatter) -> fmt::Result> fmt::Display for DisplayFn<F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Parses `text` with the `FromStr` implementation of `T`.
+#[inline]
+fn from_str<T: FromStr>(text: &str) -> Result<T> {
+    text.parse().map_err(|_| Error::InvalidText)
+}
+
+/* forwarding to the standard representation */
+
+macro_rules! forward {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl TextField for $type {
+                #[inline]
+                fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+                #[inline]
+                fn parse_field(text: &str) -> Result<Self> {
+                    from_str(text)
+                }
+            }
+        )+
+    };
+}
+forward![
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64, bool,
+    #[cfg(feature = "half")] half::f16,
+    #[cfg(feature = "half")] half::bf16,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i32>,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i64>,
+    #[cfg(feature = "num-rational")] num_rational::Ratio<i128>,
+    #[cfg(feature = "num-bigint")] num_bigint::BigInt,
+    #[cfg(feature = "rust_decimal")] rust_decimal::Decimal,
+    #[cfg(feature = "std")] std::string::String,
+];
+
+impl TextField for char {
+    #[inline]
+    fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+    fn parse_field(text: &str) -> Result<Self> {
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(Error::InvalidText),
+        }
+    }
+}
+
+/* byte arrays & bit arrays */
+
+impl<const N: usize> TextField for [u8; N] {
+    fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+    fn parse_field(text: &str) -> Result<Self> {
+        let text = text.as_bytes();
+        if text.len() != N * 2 {
+            return Err(Error::InvalidText);
+        }
+        let mut bytes = [0; N];
+        for (byte, digits) in bytes.iter_mut().zip(text.chunks_exact(2)) {
+            *byte = (hex_digit(digits[0])? << 4) | hex_digit(digits[1])?;
+        }
+        Ok(bytes)
+    }
+}
+
+/// Returns the value of an ASCII hexadecimal digit.
+fn hex_digit(digit: u8) -> Result<u8> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(Error::InvalidText),
+    }
+}
+
+impl<const BITLEN: usize, const BYTECAP: usize> TextField
+    for crate::list::BitArray<(), BITLEN, BYTECAP>
+{
+    #[inline]
+    fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+    /// Accepts up to `BITLEN` binary digits, the most significant first.
+    fn parse_field(text: &str) -> Result<Self> {
+        let mut bits = Self::new_zeroed_unchecked();
+        let mut index = 0;
+        for digit in text.bytes().rev().filter(|b| *b != b'_') {
+            if index == BITLEN {
+                return Err(Error::InvalidText);
+            }
+            match digit {
+                b'0' => (),
+                b'1' => bits.set_bit_unchecked(index, true),
+                _ => return Err(Error::InvalidText),
+            }
+            index += 1;
+        }
+        if index == 0 {
+            Err(Error::InvalidText)
+        } else {
+            Ok(bits)
+        }
+    }
+}
+
+/* durations */
+
+/// Writes `secs` and `nanos` as seconds with an optional fraction.
+fn fmt_seconds(f: &mut fmt::Formatter, negative: bool, secs: u64, nanos: u32) -> fmt::Result {
+    let sign = if negative { "-" } else { "" };
+    if nanos == 0 {
+        write!(f, "{sign}{secs}")
+    } else {
+        let (mut nanos, mut width) = (nanos, 9);
+        while nanos % 10 == 0 {
+            nanos /= 10;
+            width -= 1;
+        }
+        write!(f, "{sign}{secs}.{nanos:0width$}")
+    }
+}
+
+/// Parses seconds with an optional fraction, returning the sign, seconds and nanoseconds.
+fn parse_seconds(text: &str) -> Result<(bool, u64, u32)> {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (secs, fraction) = text.split_once('.').unwrap_or((text, ""));
+    Ok((negative, parse_digits(secs)?, parse_fraction(fraction)?))
+}
+
+/// Parses a non-empty sequence of ASCII digits.
+fn parse_digits<T: FromStr>(digits: &str) -> Result<T> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        Err(Error::InvalidText)
+    } else {
+        from_str(digits)
+    }
+}
+
+/// Parses up to 9 decimal fraction digits as nanoseconds.
+fn parse_fraction(digits: &str) -> Result<u32> {
+    if digits.is_empty() {
+        return Ok(0);
+    } else if digits.len() > 9 {
+        return Err(Error::InvalidText);
+    }
+    Ok(parse_digits::<u32>(digits)? * 10_u32.pow(9 - digits.len() as u32))
+}
+
+impl TextField for core::time::Duration {
+    #[inline]
+    fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_seconds(f, false, self.as_secs(), self.subsec_nanos())
+    }
+    fn parse_field(text: &str) -> Result<Self> {
+        match parse_seconds(text)? {
+            (false, secs, nanos) => Ok(Self::new(secs, nanos)),
+            _ => Err(Error::InvalidText),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TextField for std::time::SystemTime {
+    fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.duration_since(Self::UNIX_EPOCH) {
+            Ok(d) => fmt_seconds(f, false, d.as_secs(), d.subsec_nanos()),
+            Err(e) => {
+                let d = e.duration();
+                fmt_seconds(f, true, d.as_secs(), d.subsec_nanos())
+            }
+        }
+    }
+    fn parse_field(text: &str) -> Result<Self> {
+        let (negative, secs, nanos) = parse_seconds(text)?;
+        let d = core::time::Duration::new(secs, nanos);
+        if negative {
+            Self::UNIX_EPOCH.checked_sub(d)
+        } else {
+            Self::UNIX_EPOCH.checked_add(d)
+        }
+        .ok_or(Error::InvalidText)
+    }
+}
+
+/* dependencies */
+
+#[cfg(feature = "twofloat")]
+impl TextField for twofloat::TwoFloat {
+    /// Written as the high and low parts: `1 + 0.000000000000000001`.
+    #[inline]
+    fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+    fn parse_field(text: &str) -> Result<Self> {
+        let (hi, lo) = match text.split_once(" + ") {
+            Some((hi, lo)) => (from_str::<f64>(hi)?, from_str::<f64>(lo)?),
+            None => {
+                let (hi, lo) = text.split_once(" - ").ok_or(Error::InvalidText)?;
+                (from_str::<f64>(hi)?, -from_str::<f64>(lo)?)
+            }
+        };
+        Self::try_from((hi, lo)).map_err(|_| Error::InvalidText)
+    }
+}
+
+#[cfg(feature = "arraystring")]
+impl<SIZE: arraystring::prelude::Capacity> TextField for arraystring::ArrayString<SIZE> {
+    #[inline]
+    fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+    #[inline]
+    fn parse_field(text: &str) -> Result<Self> {
+        Self::try_from_str(text).map_err(|_| Error::InvalidText)
+    }
+}
+
+#[cfg(feature = "fugit")]
+macro_rules! impl_fugit {
+    ($( $type:ident<$prim:ty> ),+) => {
+        $(
+            /// Written as the number of ticks.
+            impl<const NOM: u32, const DENOM: u32> TextField for fugit::$type<$prim, NOM, DENOM> {
+                #[inline]
+                fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::Display::fmt(&self.ticks(), f)
+                }
+                #[inline]
+                fn parse_field(text: &str) -> Result<Self> {
+                    parse_digits(text).map(Self::from_ticks)
+                }
+            }
+        )+
+    };
+}
+#[cfg(feature = "fugit")]
+impl_fugit![Duration<u32>, Duration<u64>, Instant<u32>, Instant<u64>];
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::{fmt, fmt_seconds, parse_digits, parse_fraction, parse_seconds, TextField};
+    use crate::error::{LadataError as Error, LadataResult as Result};
+    use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    /// Splits `text` at the first `separator`, or returns an error.
+    fn split(text: &str, separator: char) -> Result<(&str, &str)> {
+        text.split_once(separator).ok_or(Error::InvalidText)
+    }
+
+    /// Parses exactly 2 ASCII digits.
+    fn two_digits(digits: &str) -> Result<u8> {
+        if digits.len() == 2 {
+            parse_digits(digits)
+        } else {
+            Err(Error::InvalidText)
+        }
+    }
+
+    impl TextField for Date {
+        fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let (year, month, day) = (self.year(), self.month() as u8, self.day());
+            if (0..=9999).contains(&year) {
+                write!(f, "{year:04}-{month:02}-{day:02}")
+            } else {
+                write!(f, "{year:+05}-{month:02}-{day:02}")
+            }
+        }
+        fn parse_field(text: &str) -> Result<Self> {
+            let (negative, rest) = match text.as_bytes().first() {
+                Some(b'-') => (true, &text[1..]),
+                Some(b'+') => (false, &text[1..]),
+                _ => (false, text),
+            };
+            let (year, rest) = split(rest, '-')?;
+            let (month, day) = split(rest, '-')?;
+            let year: i32 = parse_digits(year)?;
+            let month = Month::try_from(two_digits(month)?).map_err(|_| Error::InvalidText)?;
+            let year = if negative { -year } else { year };
+            Date::from_calendar_date(year, month, two_digits(day)?).map_err(|_| Error::InvalidText)
+        }
+    }
+
+    impl TextField for Time {
+        fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let (h, m, s, nanos) = self.as_hms_nano();
+            write!(f, "{h:02}:{m:02}:")?;
+            if s < 10 {
+                write!(f, "0")?;
+            }
+            fmt_seconds(f, false, s as u64, nanos)
+        }
+        fn parse_field(text: &str) -> Result<Self> {
+            let (h, rest) = split(text, ':')?;
+            let (m, rest) = split(rest, ':')?;
+            let (s, fraction) = rest.split_once('.').unwrap_or((rest, ""));
+            Time::from_hms_nano(
+                two_digits(h)?,
+                two_digits(m)?,
+                two_digits(s)?,
+                parse_fraction(fraction)?,
+            )
+            .map_err(|_| Error::InvalidText)
+        }
+    }
+
+    impl TextField for UtcOffset {
+        fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let sign = if self.is_negative() { '-' } else { '+' };
+            let (h, m, s) = self.as_hms();
+            let (h, m, s) = (h.unsigned_abs(), m.unsigned_abs(), s.unsigned_abs());
+            write!(f, "{sign}{h:02}:{m:02}")?;
+            if s != 0 {
+                write!(f, ":{s:02}")?;
+            }
+            Ok(())
+        }
+        fn parse_field(text: &str) -> Result<Self> {
+            let sign = match text.as_bytes().first() {
+                Some(b'+') => 1,
+                Some(b'-') => -1,
+                _ => return Err(Error::InvalidText),
+            };
+            let (h, rest) = split(&text[1..], ':')?;
+            let (m, s) = rest.split_once(':').unwrap_or((rest, "00"));
+            let [h, m, s] =
+                [two_digits(h)?, two_digits(m)?, two_digits(s)?].map(|n| n as i8 * sign);
+            UtcOffset::from_hms(h, m, s).map_err(|_| Error::InvalidText)
+        }
+    }
+
+    impl TextField for Duration {
+        fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let negative = self.is_negative();
+            let secs = self.whole_seconds().unsigned_abs();
+            let nanos = self.subsec_nanoseconds().unsigned_abs();
+            fmt_seconds(f, negative, secs, nanos)
+        }
+        fn parse_field(text: &str) -> Result<Self> {
+            let (negative, secs, nanos) = parse_seconds(text)?;
+            let (secs, nanos) = if negative {
+                (-(secs as i128), -(nanos as i32))
+            } else {
+                (secs as i128, nanos as i32)
+            };
+            let secs = i64::try_from(secs).map_err(|_| Error::InvalidText)?;
+            Ok(Duration::new(secs, nanos))
+        }
+    }
+
+    impl TextField for PrimitiveDateTime {
+        fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            self.date().fmt_field(f)?;
+            write!(f, "T")?;
+            self.time().fmt_field(f)
+        }
+        fn parse_field(text: &str) -> Result<Self> {
+            let (date, time) = split(text, 'T')?;
+            Ok(PrimitiveDateTime::new(
+                Date::parse_field(date)?,
+                Time::parse_field(time)?,
+            ))
+        }
+    }
+
+    impl TextField for OffsetDateTime {
+        fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            PrimitiveDateTime::new(self.date(), self.time()).fmt_field(f)?;
+            self.offset().fmt_field(f)
+        }
+        fn parse_field(text: &str) -> Result<Self> {
+            let at = text
+                .rfind(['+', '-'])
+                .filter(|at| text[..*at].contains('T'))
+                .ok_or(Error::InvalidText)?;
+            let datetime = PrimitiveDateTime::parse_field(&text[..at])?;
+            Ok(datetime.assume_offset(UtcOffset::parse_field(&text[at..])?))
+        }
+    }
+}
+
+/* types without a textual representation */
+
+macro_rules! unparseable {
+    ($( $(#[$attr:meta])* $type:ty ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl TextField for $type {
+                /// Written with its `Debug` representation.
+                #[inline]
+                fn fmt_field(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::Debug::fmt(self, f)
+                }
+                #[inline]
+                fn parse_field(_text: &str) -> Result<Self> {
+                    Err(Error::InvalidText)
+                }
+            }
+        )+
+    };
+}
+unparseable![
+    #[cfg(feature = "std")]
+    std::time::Instant,
+    #[cfg(all(feature = "std", feature = "time"))]
+    #[allow(deprecated)]
+    time::Instant,
+];
@@ -4,12 +4,21 @@
 //
 // - DataType
 // - DataTypeCopy
+// - DataTypeCast
+// - DataTypeAccepts
 // - DataUnit
 // - DataUnitCopy
+// - DataUnitPayload
 // - DataRaw
+// - DataColumn
 
+use super::cast::{CastMode, CastValue};
+use crate::error::LadataResult;
 use core::fmt::Debug;
 
+#[cfg(feature = "alloc")]
+use {crate::error::LadataResult as Result, alloc::vec::Vec};
+
 /// Common trait for *data types*.
 ///
 /// Allows extending `DataType*`**`With`** versions with custom *types*.
@@ -44,6 +53,41 @@ pub trait DataType: Copy + Debug {
 /// - [`DataUnitCopy`]
 pub trait DataTypeCopy: DataType {}
 
+/// Common trait for *data types* that can be the target of a cast.
+///
+/// It's implemented for the `DataType*` and `DataType*Copy` enums, and it
+/// allows the `cast` method of any `DataUnit*` to target units of any size.
+///
+/// # See also
+/// - [`CastMode`]
+/// - [`DataType`]
+pub trait DataTypeCast: DataType {
+    /// The *data unit* resulting from the cast.
+    type Unit: DataUnit;
+
+    /// Casts the size-agnostic `value` into a unit of the current type.
+    #[doc(hidden)]
+    fn cast_value(&self, value: &CastValue, mode: CastMode) -> LadataResult<Self::Unit>;
+}
+
+/// Common trait for *data types* that can validate *data units*.
+///
+/// It's implemented for the `DataType*` and `DataType*Copy` enums,
+/// pairing each one with the `DataUnit*` of the same size.
+///
+/// # See also
+/// - [`DataType`]
+/// - [`DataUnit`]
+pub trait DataTypeAccepts: DataType {
+    /// The *data unit* validated by the type.
+    type Unit: DataUnit;
+
+    /// Returns `true` if the given `unit` is of the current type.
+    ///
+    /// Any `With` unit is accepted by any `With` type.
+    fn accepts(&self, unit: &Self::Unit) -> bool;
+}
+
 /// Common trait for *data units*.
 ///
 /// Allows extending `DataUnit*`**`With`** versions.
@@ -53,8 +97,17 @@ pub trait DataTypeCopy: DataType {}
 /// - [`DataTypeCopy`]
 /// - [`DataType`]
 pub trait DataUnit: Debug {
+    /// The *data type* of the unit.
+    type Type: DataType;
+
     /// Whether the data type in the current variant is [`Copy`].
     fn is_copy(&self) -> bool;
+
+    /// Returns the *data type* of the current variant.
+    ///
+    /// The `With` variant of the `DataUnit*` enums delegates to this method,
+    /// so that custom units can report their own *data type*.
+    fn unit_type(&self) -> Self::Type;
 }
 
 /// Common (marker) trait for `Copy` *data units*.
@@ -72,6 +125,29 @@ pub trait DataUnit: Debug {
 /// - [`DataTypeCopy`]
 pub trait DataUnitCopy: DataUnit + Copy {}
 
+/// Common trait for custom *data units* that can be encoded and parsed.
+///
+/// Allows the `With` variants of the `DataUnit*` enums to round-trip through
+/// their binary and textual representations, identifying their *data type*
+/// with a [`DataTypeRegistry`][super::DataTypeRegistry].
+///
+/// # See also
+/// - [`DataUnit`]
+pub trait DataUnitPayload: DataUnit + Sized {
+    /// Encodes the data of the unit into `bytes`, returning the number of bytes written.
+    fn encode_payload(&self, bytes: &mut [u8]) -> LadataResult<usize>;
+
+    /// Decodes the data of a unit of the given `data_type` from the start of `bytes`,
+    /// returning it with the number of bytes read.
+    fn decode_payload(data_type: Self::Type, bytes: &[u8]) -> LadataResult<(Self, usize)>;
+
+    /// Writes the data of the unit as text.
+    fn fmt_payload(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result;
+
+    /// Parses the data of a unit of the given `data_type` from `text`.
+    fn parse_payload(data_type: Self::Type, text: &str) -> LadataResult<Self>;
+}
+
 /// Common trait for *unsafe data units*.
 ///
 /// # Safety
@@ -89,3 +165,47 @@ pub unsafe trait DataRaw {}
 #[cfg(feature = "unsafe_unit")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "not(safe)")))]
 pub unsafe trait DataRawCopy: DataRaw + Copy {}
+
+/// Common trait for *data columns*.
+///
+/// A column stores a sequence of *data units* sharing the same *data type*,
+/// which is stored only once for the whole column, instead of once per unit.
+///
+/// See also:
+/// - [`DataUnit`]
+/// - [`DataType`]
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub trait DataColumn: Debug {
+    /// The *data type* of the column.
+    type Type: DataType;
+    /// The *data unit* accepted and returned by the column.
+    type Unit: DataUnit;
+
+    /// Returns a new empty column of the given `data_type`.
+    fn column_new(data_type: Self::Type) -> Self;
+
+    /// Returns the *data type* shared by all the units in the column.
+    fn column_type(&self) -> Self::Type;
+
+    /// Returns the number of units in the column.
+    fn column_len(&self) -> usize;
+
+    /// Returns `true` if the given `unit` can be stored in the column.
+    fn column_accepts(&self, unit: &Self::Unit) -> bool;
+
+    /// Appends a `unit` at the end of the column.
+    fn column_push(&mut self, unit: Self::Unit) -> Result<()>;
+
+    /// Removes the last unit from the column and returns it.
+    fn column_pop(&mut self) -> Option<Self::Unit>;
+
+    /// Returns a copy of the unit at `index`.
+    fn column_get(&self, index: usize) -> Option<Self::Unit>;
+
+    /// Replaces the unit at `index`, returning the old one.
+    fn column_set(&mut self, index: usize, unit: Self::Unit) -> Result<Self::Unit>;
+
+    /// Returns all the units of the column, in order.
+    fn column_into_units(self) -> Vec<Self::Unit>;
+}